@@ -0,0 +1,256 @@
+use serde::Serialize;
+
+use casper_types::{account::AccountHash, crypto, PublicKey};
+
+use crate::{
+    rpcs::{common::BlockIdentifier, AccountIdentifier},
+    types::{Account, Deploy, DeployHash},
+    Error, HttpTransport, JsonRpcId, Transport, Verbosity,
+};
+
+/// A signer of a [`Deploy`], whether its approvals are valid, and the weight of its key in the
+/// deploy's account.
+#[derive(Clone, PartialEq, Eq, Serialize, Debug)]
+pub struct SignerWeight {
+    /// The public key of the signer.
+    pub signer: PublicKey,
+    /// The weight of the signer's key in the account, or `None` if the key is not associated with
+    /// the account.
+    pub weight: Option<u8>,
+    /// Whether every approval by the signer is a valid signature of the deploy hash.
+    pub valid: bool,
+}
+
+/// A comparison of the approvals of a [`Deploy`] against the deployment threshold of the account
+/// under which it would be executed.
+#[derive(Clone, PartialEq, Eq, Serialize, Debug)]
+pub struct ApprovalsCheck {
+    /// The hash of the deploy.
+    pub deploy_hash: DeployHash,
+    /// The account hash of the deploy's account.
+    pub account_hash: AccountHash,
+    /// The weight of each distinct signer of the deploy.
+    pub signers: Vec<SignerWeight>,
+    /// The total weight of the valid signers' keys which are associated with the account.
+    pub total_weight: u32,
+    /// The account's deployment threshold.
+    pub deployment_threshold: u8,
+    /// Whether the approvals are sufficient for the deploy to be executed.
+    ///
+    /// This requires the deploy to have at least one approval, every approval to be valid, every
+    /// signer's key to be associated with the account, and the total weight to meet the
+    /// deployment threshold.
+    pub can_execute: bool,
+}
+
+impl ApprovalsCheck {
+    /// Compares the approvals of `deploy` against the associated keys and deployment threshold of
+    /// `account`, which should be the deploy's account.
+    pub fn new(deploy: &Deploy, account: &Account) -> Self {
+        let mut signers: Vec<SignerWeight> = vec![];
+        for approval in deploy.approvals() {
+            let valid =
+                crypto::verify(deploy.id().inner(), approval.signature(), approval.signer())
+                    .is_ok();
+            if let Some(signer_weight) = signers
+                .iter_mut()
+                .find(|signer_weight| signer_weight.signer == *approval.signer())
+            {
+                signer_weight.valid &= valid;
+                continue;
+            }
+            let account_hash = approval.signer().to_account_hash();
+            let weight = account
+                .associated_keys()
+                .find(|associated_key| *associated_key.account_hash() == account_hash)
+                .map(|associated_key| associated_key.weight());
+            signers.push(SignerWeight {
+                signer: approval.signer().clone(),
+                weight,
+                valid,
+            });
+        }
+
+        let total_weight = signers
+            .iter()
+            .filter(|signer_weight| signer_weight.valid)
+            .filter_map(|signer_weight| signer_weight.weight)
+            .map(u32::from)
+            .sum();
+        let deployment_threshold = account.action_thresholds().deployment();
+        let can_execute = !signers.is_empty()
+            && signers
+                .iter()
+                .all(|signer_weight| signer_weight.valid && signer_weight.weight.is_some())
+            && total_weight >= u32::from(deployment_threshold);
+
+        ApprovalsCheck {
+            deploy_hash: *deploy.id(),
+            account_hash: *account.account_hash(),
+            signers,
+            total_weight,
+            deployment_threshold,
+            can_execute,
+        }
+    }
+}
+
+/// Checks whether the approvals of the given [`Deploy`] are sufficient for it to be executed.
+///
+/// Sends a JSON-RPC `state_get_account_info` request to the specified node to retrieve the
+/// deploy's account, then compares the approvals against it as per [`ApprovalsCheck::new`].
+///
+/// For details of the parameters, see [the module docs](crate#common-parameters).
+pub async fn check_approvals(
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_block_identifier: Option<BlockIdentifier>,
+    deploy: &Deploy,
+) -> Result<ApprovalsCheck, Error> {
+    check_approvals_with_transport(
+        &HttpTransport::default(),
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_identifier,
+        deploy,
+    )
+    .await
+}
+
+/// Same as [`check_approvals`], but sends the request via the given `transport`.
+pub async fn check_approvals_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_block_identifier: Option<BlockIdentifier>,
+    deploy: &Deploy,
+) -> Result<ApprovalsCheck, Error> {
+    let account_identifier = AccountIdentifier::PublicKey(deploy.header().account().clone());
+    let account = crate::get_account_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_identifier,
+        account_identifier,
+    )
+    .await?
+    .result
+    .account;
+    Ok(ApprovalsCheck::new(deploy, &account))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use casper_types::{SecretKey, Signature, URef};
+
+    use super::*;
+    use crate::{types::DeployBuilder, Signer, TransferTarget};
+
+    /// A signer which signs with one key but claims the signature is by another.
+    struct ForgingSigner {
+        secret_key: SecretKey,
+        claimed_signer: PublicKey,
+    }
+
+    impl Signer for ForgingSigner {
+        fn public_key(&self) -> Option<PublicKey> {
+            Some(self.claimed_signer.clone())
+        }
+
+        fn sign(&self, deploy_hash: &DeployHash) -> Result<(PublicKey, Signature), Error> {
+            let (_, signature) = Signer::sign(&self.secret_key, deploy_hash)?;
+            Ok((self.claimed_signer.clone(), signature))
+        }
+    }
+
+    fn secret_key(byte: u8) -> SecretKey {
+        SecretKey::ed25519_from_bytes([byte; 32]).unwrap()
+    }
+
+    fn account(associated_keys: &[(&SecretKey, u8)], deployment_threshold: u8) -> Account {
+        let associated_keys: Vec<_> = associated_keys
+            .iter()
+            .map(|(secret_key, weight)| {
+                json!({
+                    "account_hash": PublicKey::from(*secret_key).to_account_hash(),
+                    "weight": weight
+                })
+            })
+            .collect();
+        serde_json::from_value(json!({
+            "account_hash": PublicKey::from(&secret_key(1)).to_account_hash(),
+            "named_keys": [],
+            "main_purse": URef::default(),
+            "associated_keys": associated_keys,
+            "action_thresholds": { "deployment": deployment_threshold, "key_management": 3 }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn should_check_approvals_against_deployment_threshold() {
+        let (key_1, key_2, key_3) = (secret_key(1), secret_key(2), secret_key(3));
+        let mut deploy = DeployBuilder::new_transfer(
+            "casper-test",
+            2_500_000_000_u64,
+            None,
+            TransferTarget::AccountHash(AccountHash::default()),
+            None,
+        )
+        .with_standard_payment(100_000_000_u64)
+        .with_secret_key(&key_1)
+        .build()
+        .unwrap();
+        deploy.sign(&key_2).unwrap();
+        deploy.sign(&key_2).unwrap();
+
+        let check = ApprovalsCheck::new(&deploy, &account(&[(&key_1, 1), (&key_2, 1)], 2));
+        assert_eq!(check.signers.len(), 2);
+        assert_eq!(check.total_weight, 2);
+        assert!(check.can_execute);
+
+        let check = ApprovalsCheck::new(&deploy, &account(&[(&key_1, 1), (&key_2, 1)], 3));
+        assert!(!check.can_execute);
+
+        deploy.sign(&key_3).unwrap();
+        let check = ApprovalsCheck::new(&deploy, &account(&[(&key_1, 1), (&key_2, 1)], 2));
+        assert_eq!(check.signers[2].weight, None);
+        assert_eq!(check.total_weight, 2);
+        assert!(!check.can_execute);
+    }
+
+    #[test]
+    fn should_not_count_weight_of_forged_approvals() {
+        let (key_1, key_2, key_3) = (secret_key(1), secret_key(2), secret_key(3));
+        let mut deploy = DeployBuilder::new_transfer(
+            "casper-test",
+            2_500_000_000_u64,
+            None,
+            TransferTarget::AccountHash(AccountHash::default()),
+            None,
+        )
+        .with_standard_payment(100_000_000_u64)
+        .with_secret_key(&key_1)
+        .build()
+        .unwrap();
+        deploy
+            .sign(&ForgingSigner {
+                secret_key: key_3,
+                claimed_signer: PublicKey::from(&key_2),
+            })
+            .unwrap();
+
+        let check = ApprovalsCheck::new(&deploy, &account(&[(&key_1, 1), (&key_2, 1)], 2));
+        assert!(check.signers[0].valid);
+        assert!(!check.signers[1].valid);
+        assert_eq!(check.signers[1].weight, Some(1));
+        assert_eq!(check.total_weight, 1);
+        assert!(!check.can_execute);
+    }
+}
@@ -0,0 +1,512 @@
+//! A batch of JSON-RPC requests sent to a node in a single round trip.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Debug, Formatter},
+};
+
+use itertools::Itertools;
+use jsonrpc_lite::JsonRpc;
+use serde::{de::DeserializeOwned, Serialize};
+use tracing::Instrument;
+
+use casper_hashing::Digest;
+#[cfg(doc)]
+use casper_types::Transfer;
+use casper_types::{Key, URef};
+
+#[cfg(doc)]
+use crate::types::{Account, Block, StoredValue};
+use crate::{
+    json_rpc::{self, BatchRequest},
+    rpcs::{
+        common::{BlockIdentifier, GlobalStateIdentifier},
+        results::{
+            GetAccountResult, GetAuctionInfoResult, GetBalanceResult, GetBlockResult,
+            GetBlockTransfersResult, GetDeployResult, GetDictionaryItemResult, GetEraSummaryResult,
+            GetStateRootHashResult, QueryBalanceResult, QueryGlobalStateResult,
+        },
+        v1_6_0::{
+            get_account::{GetAccountParams, GET_ACCOUNT_METHOD},
+            get_auction_info::{GetAuctionInfoParams, GET_AUCTION_INFO_METHOD},
+            get_balance::{GetBalanceParams, GET_BALANCE_METHOD},
+            get_block::{GetBlockParams, GET_BLOCK_METHOD},
+            get_block_transfers::{GetBlockTransfersParams, GET_BLOCK_TRANSFERS_METHOD},
+            get_deploy::{GetDeployParams, GET_DEPLOY_METHOD},
+            get_dictionary_item::{GetDictionaryItemParams, GET_DICTIONARY_ITEM_METHOD},
+            get_era_summary::{GetEraSummaryParams, GET_ERA_SUMMARY_METHOD},
+            get_state_root_hash::{GetStateRootHashParams, GET_STATE_ROOT_HASH_METHOD},
+            query_balance::{QueryBalanceParams, QUERY_BALANCE_METHOD},
+            query_global_state::{QueryGlobalStateParams, QUERY_GLOBAL_STATE_METHOD},
+        },
+        AccountIdentifier, DictionaryItemIdentifier, PurseIdentifier,
+    },
+    types::DeployHash,
+    validation::{self, ValidateResponseError},
+    Error, HttpTransport, JsonRpcId, SuccessResponse, Transport, Verbosity,
+};
+
+type Validator<R> = Box<dyn Fn(&R) -> Result<(), ValidateResponseError> + Send + Sync>;
+
+/// A batch of JSON-RPC requests to be sent to a node in a single round trip.
+///
+/// Each method queues a request and returns a [`BatchHandle`], which is used to take the typed
+/// response to that request from the [`BatchResponse`] once the batch has been sent.  Responses are
+/// correlated with their requests by JSON-RPC ID, so every request in a batch must have a distinct
+/// ID.
+///
+/// For details of the parameters of each method, see the free function of the same name in
+/// [the crate root](crate).
+#[derive(Default, Debug)]
+pub struct Batch {
+    requests: Vec<BatchRequest>,
+}
+
+impl Batch {
+    /// Returns a new, empty `Batch`.
+    pub fn new() -> Self {
+        Batch::default()
+    }
+
+    /// Returns the number of requests in the batch.
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    /// Returns `true` if the batch contains no requests.
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+
+    /// Queues a `info_get_deploy` request, to retrieve a [`Deploy`](crate::types::Deploy) from
+    /// the network.
+    pub fn get_deploy(
+        &mut self,
+        rpc_id: JsonRpcId,
+        deploy_hash: DeployHash,
+        finalized_approvals: bool,
+    ) -> BatchHandle<GetDeployResult> {
+        let params = GetDeployParams::new(deploy_hash, finalized_approvals);
+        self.push(rpc_id, GET_DEPLOY_METHOD, Some(params))
+    }
+
+    /// Queues a `chain_get_block` request, to retrieve a [`Block`] from the network.
+    pub fn get_block(
+        &mut self,
+        rpc_id: JsonRpcId,
+        maybe_block_identifier: Option<BlockIdentifier>,
+    ) -> BatchHandle<GetBlockResult> {
+        let params = maybe_block_identifier.map(GetBlockParams::new);
+        let mut handle = self.push(rpc_id, GET_BLOCK_METHOD, params);
+        handle.validator = Some(Box::new(move |result: &GetBlockResult| {
+            validation::validate_get_block_result(maybe_block_identifier, result)
+        }));
+        handle
+    }
+
+    /// Queues a `chain_get_block_transfers` request, to retrieve all [`Transfer`] items for a
+    /// given [`Block`].
+    pub fn get_block_transfers(
+        &mut self,
+        rpc_id: JsonRpcId,
+        maybe_block_identifier: Option<BlockIdentifier>,
+    ) -> BatchHandle<GetBlockTransfersResult> {
+        let params = maybe_block_identifier.map(GetBlockTransfersParams::new);
+        self.push(rpc_id, GET_BLOCK_TRANSFERS_METHOD, params)
+    }
+
+    /// Queues a `chain_get_state_root_hash` request, to retrieve a state root hash at a given
+    /// [`Block`].
+    pub fn get_state_root_hash(
+        &mut self,
+        rpc_id: JsonRpcId,
+        maybe_block_identifier: Option<BlockIdentifier>,
+    ) -> BatchHandle<GetStateRootHashResult> {
+        let params = maybe_block_identifier.map(GetStateRootHashParams::new);
+        self.push(rpc_id, GET_STATE_ROOT_HASH_METHOD, params)
+    }
+
+    /// Queues a `chain_get_era_summary` request, to retrieve era information at a given
+    /// [`Block`].
+    pub fn get_era_summary(
+        &mut self,
+        rpc_id: JsonRpcId,
+        maybe_block_identifier: Option<BlockIdentifier>,
+    ) -> BatchHandle<GetEraSummaryResult> {
+        let params = maybe_block_identifier.map(GetEraSummaryParams::new);
+        self.push(rpc_id, GET_ERA_SUMMARY_METHOD, params)
+    }
+
+    /// Queues a `query_global_state` request, to retrieve a [`StoredValue`] from global state.
+    pub fn query_global_state(
+        &mut self,
+        rpc_id: JsonRpcId,
+        global_state_identifier: Option<GlobalStateIdentifier>,
+        key: Key,
+        path: Vec<String>,
+    ) -> BatchHandle<QueryGlobalStateResult> {
+        let params = QueryGlobalStateParams::new(global_state_identifier, key, path);
+        self.push(rpc_id, QUERY_GLOBAL_STATE_METHOD, Some(params))
+    }
+
+    /// Queues a `query_balance` request, to retrieve a purse's balance from global state.
+    pub fn query_balance(
+        &mut self,
+        rpc_id: JsonRpcId,
+        maybe_global_state_identifier: Option<GlobalStateIdentifier>,
+        purse_identifier: PurseIdentifier,
+    ) -> BatchHandle<QueryBalanceResult> {
+        let params = QueryBalanceParams::new(maybe_global_state_identifier, purse_identifier);
+        self.push(rpc_id, QUERY_BALANCE_METHOD, Some(params))
+    }
+
+    /// Queues a `state_get_dictionary_item` request, to retrieve a [`StoredValue`] from a
+    /// dictionary.
+    pub fn get_dictionary_item(
+        &mut self,
+        rpc_id: JsonRpcId,
+        state_root_hash: Digest,
+        dictionary_item_identifier: DictionaryItemIdentifier,
+    ) -> BatchHandle<GetDictionaryItemResult> {
+        let params = GetDictionaryItemParams::new(state_root_hash, dictionary_item_identifier);
+        self.push(rpc_id, GET_DICTIONARY_ITEM_METHOD, Some(params))
+    }
+
+    /// Queues a `state_get_balance` request, to retrieve a purse's balance.
+    pub fn get_balance(
+        &mut self,
+        rpc_id: JsonRpcId,
+        state_root_hash: Digest,
+        purse: URef,
+    ) -> BatchHandle<GetBalanceResult> {
+        let params = GetBalanceParams::new(state_root_hash, purse);
+        self.push(rpc_id, GET_BALANCE_METHOD, Some(params))
+    }
+
+    /// Queues a `state_get_account_info` request, to retrieve an [`Account`].
+    pub fn get_account(
+        &mut self,
+        rpc_id: JsonRpcId,
+        maybe_block_identifier: Option<BlockIdentifier>,
+        account_identifier: AccountIdentifier,
+    ) -> BatchHandle<GetAccountResult> {
+        let params = GetAccountParams::new(account_identifier, maybe_block_identifier);
+        self.push(rpc_id, GET_ACCOUNT_METHOD, Some(params))
+    }
+
+    /// Queues a `state_get_auction_info` request, to retrieve the bids and validators at a given
+    /// [`Block`].
+    pub fn get_auction_info(
+        &mut self,
+        rpc_id: JsonRpcId,
+        maybe_block_identifier: Option<BlockIdentifier>,
+    ) -> BatchHandle<GetAuctionInfoResult> {
+        let params = maybe_block_identifier.map(GetAuctionInfoParams::new);
+        self.push(rpc_id, GET_AUCTION_INFO_METHOD, params)
+    }
+
+    /// Sends the batch to the specified node, using the default [`HttpTransport`].
+    ///
+    /// For details of the parameters, see [the module docs](crate#common-parameters).
+    pub async fn send(
+        self,
+        node_address: &str,
+        verbosity: Verbosity,
+    ) -> Result<BatchResponse, Error> {
+        self.send_with_transport(&HttpTransport::default(), node_address, verbosity)
+            .await
+    }
+
+    /// Same as [`send`](Self::send), but sends the batch via the given `transport`.
+    ///
+    /// Returns an error without sending anything if two requests in the batch have the same
+    /// JSON-RPC ID.  An empty batch is not sent, and yields an empty `BatchResponse`.
+    pub async fn send_with_transport(
+        self,
+        transport: &dyn Transport,
+        node_address: &str,
+        verbosity: Verbosity,
+    ) -> Result<BatchResponse, Error> {
+        let mut rpc_ids = HashSet::new();
+        for batch_request in &self.requests {
+            if !rpc_ids.insert(&batch_request.rpc_id) {
+                return Err(Error::DuplicateBatchRpcId {
+                    rpc_id: batch_request.rpc_id.clone(),
+                });
+            }
+        }
+        if self.requests.is_empty() {
+            return Ok(BatchResponse::default());
+        }
+
+        let rpc_ids = self
+            .requests
+            .iter()
+            .map(|batch_request| &batch_request.rpc_id)
+            .join(",");
+        let span = json_rpc::trace::rpc_span(json_rpc::BATCH_RPC_METHOD, &rpc_ids, node_address);
+        async move {
+            let requests: Vec<&JsonRpc> = self
+                .requests
+                .iter()
+                .map(|batch_request| &batch_request.request)
+                .collect();
+            json_rpc::trace::payload("sending batch", &requests, verbosity);
+
+            let url = json_rpc::rpc_url(node_address);
+            let timer = json_rpc::trace::Timer::start();
+            let responses = transport
+                .send_batch(&url, &self.requests)
+                .await
+                .map_err(|error| {
+                    json_rpc::trace::failure(&error, &timer);
+                    error
+                })?;
+            json_rpc::trace::outcome(json_rpc::trace::SUCCESS_STATUS, &timer);
+            json_rpc::trace::payload("received batch", &responses, verbosity);
+
+            Ok(BatchResponse::new(responses))
+        }
+        .instrument(span)
+        .await
+    }
+
+    fn push<P: Serialize, R>(
+        &mut self,
+        rpc_id: JsonRpcId,
+        rpc_method: &'static str,
+        maybe_params: Option<P>,
+    ) -> BatchHandle<R> {
+        let request = json_rpc::build_request(&rpc_id, rpc_method, maybe_params);
+        self.requests.push(BatchRequest {
+            rpc_id: rpc_id.clone(),
+            rpc_method,
+            request,
+        });
+        BatchHandle {
+            rpc_id,
+            rpc_method,
+            validator: None,
+        }
+    }
+}
+
+/// A handle to a single request queued in a [`Batch`], used to take the typed response to that
+/// request from the [`BatchResponse`].
+pub struct BatchHandle<R> {
+    rpc_id: JsonRpcId,
+    rpc_method: &'static str,
+    validator: Option<Validator<R>>,
+}
+
+impl<R> BatchHandle<R> {
+    /// Returns the JSON-RPC ID of the request.
+    pub fn rpc_id(&self) -> &JsonRpcId {
+        &self.rpc_id
+    }
+
+    /// Returns the JSON-RPC method of the request.
+    pub fn rpc_method(&self) -> &'static str {
+        self.rpc_method
+    }
+}
+
+impl<R> Debug for BatchHandle<R> {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("BatchHandle")
+            .field("rpc_id", &self.rpc_id)
+            .field("rpc_method", &self.rpc_method)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The node's responses to a [`Batch`], keyed by JSON-RPC ID.
+#[derive(Default, Debug)]
+pub struct BatchResponse {
+    responses: HashMap<JsonRpcId, JsonRpc>,
+    /// The error returned by the node if it rejected the batch as a whole.
+    batch_error: Option<jsonrpc_lite::Error>,
+}
+
+impl BatchResponse {
+    fn new(responses: Vec<JsonRpc>) -> Self {
+        let mut batch_response = BatchResponse::default();
+        for response in responses {
+            match response.get_id().and_then(to_rpc_id) {
+                Some(rpc_id) => {
+                    let _ = batch_response.responses.insert(rpc_id, response);
+                }
+                None => batch_response.batch_error = response.get_error().cloned(),
+            }
+        }
+        batch_response
+    }
+
+    /// Removes and returns the response to the request identified by `handle`.
+    ///
+    /// Each entry is returned as a `SuccessResponse` or an `Error` independently of the others, so
+    /// a failure of one request in the batch does not affect the rest.
+    pub fn take<R: DeserializeOwned>(
+        &mut self,
+        handle: BatchHandle<R>,
+    ) -> Result<SuccessResponse<R>, Error> {
+        let response = match self.responses.remove(&handle.rpc_id) {
+            Some(response) => response,
+            None => {
+                return Err(match &self.batch_error {
+                    Some(error) => Error::ResponseIsRpcError {
+                        rpc_id: handle.rpc_id,
+                        rpc_method: handle.rpc_method,
+                        error: error.clone(),
+                    },
+                    None => Error::MissingBatchResponse {
+                        rpc_id: handle.rpc_id,
+                        rpc_method: handle.rpc_method,
+                    },
+                })
+            }
+        };
+        let success_response: SuccessResponse<R> =
+            json_rpc::parse_response(&handle.rpc_id, handle.rpc_method, response)?;
+        if let Some(validator) = &handle.validator {
+            validator(&success_response.result)?;
+        }
+        Ok(success_response)
+    }
+
+    /// Returns the number of responses which have not yet been taken.
+    pub fn len(&self) -> usize {
+        self.responses.len()
+    }
+
+    /// Returns `true` if all responses have been taken.
+    pub fn is_empty(&self) -> bool {
+        self.responses.is_empty()
+    }
+}
+
+fn to_rpc_id(id: jsonrpc_lite::Id) -> Option<JsonRpcId> {
+    match id {
+        jsonrpc_lite::Id::Num(number) => Some(JsonRpcId::Number(number)),
+        jsonrpc_lite::Id::Str(string) => Some(JsonRpcId::String(string)),
+        jsonrpc_lite::Id::None(_) => None,
+    }
+}
+
+#[cfg(all(test, feature = "tokio"))]
+mod tests {
+    use async_trait::async_trait;
+    use serde_json::json;
+
+    use casper_types::AccessRights;
+
+    use super::*;
+
+    /// A transport which answers each request individually with its own method name, unless the
+    /// method is `state_get_balance`, which yields a JSON-RPC error.
+    #[derive(Debug)]
+    struct EchoTransport;
+
+    #[async_trait]
+    impl Transport for EchoTransport {
+        async fn send(
+            &self,
+            _url: &str,
+            rpc_id: &JsonRpcId,
+            rpc_method: &'static str,
+            _request: &JsonRpc,
+        ) -> Result<JsonRpc, Error> {
+            if rpc_method == GET_BALANCE_METHOD {
+                return Ok(JsonRpc::error(
+                    rpc_id,
+                    jsonrpc_lite::Error::invalid_params(),
+                ));
+            }
+            Ok(JsonRpc::success(
+                rpc_id,
+                &json!({ "api_version": "1.5.0", "state_root_hash": null }),
+            ))
+        }
+
+        async fn send_batch(
+            &self,
+            url: &str,
+            requests: &[BatchRequest],
+        ) -> Result<Vec<JsonRpc>, Error> {
+            let mut responses = vec![];
+            for batch_request in requests.iter().rev() {
+                responses.push(
+                    self.send(
+                        url,
+                        &batch_request.rpc_id,
+                        batch_request.rpc_method,
+                        &batch_request.request,
+                    )
+                    .await?,
+                );
+            }
+            Ok(responses)
+        }
+    }
+
+    #[tokio::test]
+    async fn should_correlate_responses_by_rpc_id() {
+        let mut batch = Batch::new();
+        let first = batch.get_state_root_hash(JsonRpcId::from(1), None);
+        let second = batch.get_balance(
+            JsonRpcId::from("two".to_string()),
+            Digest::from([0; Digest::LENGTH]),
+            URef::new([0; 32], AccessRights::READ),
+        );
+        let third = batch.get_state_root_hash(JsonRpcId::from(3), None);
+        assert_eq!(batch.len(), 3);
+
+        let mut response = batch
+            .send_with_transport(&EchoTransport, "http://localhost:7777", Verbosity::Low)
+            .await
+            .expect("should send batch");
+        assert_eq!(response.len(), 3);
+
+        assert_eq!(response.take(third).unwrap().id, JsonRpcId::from(3));
+        assert!(matches!(
+            response.take(second),
+            Err(Error::ResponseIsRpcError {
+                rpc_method: GET_BALANCE_METHOD,
+                ..
+            })
+        ));
+        assert_eq!(response.take(first).unwrap().id, JsonRpcId::from(1));
+        assert!(response.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_report_missing_response() {
+        let mut batch = Batch::new();
+        let handle = batch.get_state_root_hash(JsonRpcId::from(1), None);
+        let mut response = batch
+            .send_with_transport(&EchoTransport, "http://localhost:7777", Verbosity::Low)
+            .await
+            .unwrap();
+        let _ = response.take(handle).unwrap();
+
+        let mut other_batch = Batch::new();
+        let duplicate_handle = other_batch.get_state_root_hash(JsonRpcId::from(1), None);
+        assert!(matches!(
+            response.take(duplicate_handle),
+            Err(Error::MissingBatchResponse { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_reject_duplicate_rpc_ids() {
+        let mut batch = Batch::new();
+        let _ = batch.get_state_root_hash(JsonRpcId::from(1), None);
+        let _ = batch.get_era_summary(JsonRpcId::from(1), None);
+        let error = batch
+            .send_with_transport(&EchoTransport, "http://localhost:7777", Verbosity::Low)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::DuplicateBatchRpcId { .. }));
+    }
+}
@@ -0,0 +1,778 @@
+//! A blocking API, mirroring the asynchronous RPC functions in the root of the library.
+//!
+//! Each function drives the asynchronous function of the same name to completion on a
+//! single-threaded `tokio` runtime shared by all calls, so the behavior, including validation of
+//! the node's response, is identical.  The functions in [`blocking::cli`](cli) similarly mirror
+//! those in [`crate::cli`].
+//!
+//! These functions must not be called from within an asynchronous runtime, as doing so will panic.
+//!
+//! For details of the parameters, see [the crate docs](crate#common-parameters).
+
+pub mod cli;
+
+use std::future::Future;
+
+use once_cell::sync::OnceCell;
+use tokio::runtime::{Builder, Runtime};
+
+use casper_hashing::Digest;
+use casper_types::{Key, URef};
+
+use crate::{
+    rpcs::{
+        common::{BlockIdentifier, GlobalStateIdentifier},
+        results::{
+            GetAccountResult, GetAuctionInfoResult, GetBalanceResult, GetBlockResult,
+            GetBlockTransfersResult, GetChainspecResult, GetDeployResult, GetDictionaryItemResult,
+            GetEraInfoResult, GetEraSummaryResult, GetNodeStatusResult, GetPeersResult,
+            GetStateRootHashResult, GetValidatorChangesResult, ListRpcsResult, PutDeployResult,
+            QueryBalanceResult, QueryGlobalStateResult, SpeculativeExecResult,
+        },
+        AccountIdentifier, DictionaryItemIdentifier, PurseIdentifier,
+    },
+    types::{Deploy, DeployHash, TimeDiff},
+    ApprovalsCheck, DeployOutcome, Error, JsonRpcId, SuccessResponse, Transport, Verbosity,
+};
+
+/// The runtime on which all blocking calls are driven, shared so that pooled connections remain
+/// usable across calls.
+static RUNTIME: OnceCell<Runtime> = OnceCell::new();
+
+/// Runs `future` to completion on the shared runtime.
+fn block_on<T, E: From<Error>>(future: impl Future<Output = Result<T, E>>) -> Result<T, E> {
+    let runtime = RUNTIME.get_or_try_init(|| {
+        Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|error| Error::FailedToStartRuntime { error })
+    })?;
+    runtime.block_on(future)
+}
+
+/// Blocking version of [`crate::put_deploy`].
+pub fn put_deploy(
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    deploy: Deploy,
+) -> Result<SuccessResponse<PutDeployResult>, Error> {
+    block_on(crate::put_deploy(rpc_id, node_address, verbosity, deploy))
+}
+
+/// Blocking version of [`crate::put_deploy_with_transport`].
+pub fn put_deploy_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    deploy: Deploy,
+) -> Result<SuccessResponse<PutDeployResult>, Error> {
+    block_on(crate::put_deploy_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+        deploy,
+    ))
+}
+
+/// Blocking version of [`crate::speculative_exec`].
+pub fn speculative_exec(
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    block_identifier: Option<BlockIdentifier>,
+    verbosity: Verbosity,
+    deploy: Deploy,
+) -> Result<SuccessResponse<SpeculativeExecResult>, Error> {
+    block_on(crate::speculative_exec(
+        rpc_id,
+        node_address,
+        block_identifier,
+        verbosity,
+        deploy,
+    ))
+}
+
+/// Blocking version of [`crate::speculative_exec_with_transport`].
+pub fn speculative_exec_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    block_identifier: Option<BlockIdentifier>,
+    verbosity: Verbosity,
+    deploy: Deploy,
+) -> Result<SuccessResponse<SpeculativeExecResult>, Error> {
+    block_on(crate::speculative_exec_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        block_identifier,
+        verbosity,
+        deploy,
+    ))
+}
+
+/// Blocking version of [`crate::get_deploy`].
+pub fn get_deploy(
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    deploy_hash: DeployHash,
+    finalized_approvals: bool,
+) -> Result<SuccessResponse<GetDeployResult>, Error> {
+    block_on(crate::get_deploy(
+        rpc_id,
+        node_address,
+        verbosity,
+        deploy_hash,
+        finalized_approvals,
+    ))
+}
+
+/// Blocking version of [`crate::get_deploy_with_transport`].
+pub fn get_deploy_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    deploy_hash: DeployHash,
+    finalized_approvals: bool,
+) -> Result<SuccessResponse<GetDeployResult>, Error> {
+    block_on(crate::get_deploy_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+        deploy_hash,
+        finalized_approvals,
+    ))
+}
+
+/// Blocking version of [`crate::check_approvals`].
+pub fn check_approvals(
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_block_identifier: Option<BlockIdentifier>,
+    deploy: &Deploy,
+) -> Result<ApprovalsCheck, Error> {
+    block_on(crate::check_approvals(
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_identifier,
+        deploy,
+    ))
+}
+
+/// Blocking version of [`crate::check_approvals_with_transport`].
+pub fn check_approvals_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_block_identifier: Option<BlockIdentifier>,
+    deploy: &Deploy,
+) -> Result<ApprovalsCheck, Error> {
+    block_on(crate::check_approvals_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_identifier,
+        deploy,
+    ))
+}
+
+/// Blocking version of [`crate::wait_for_deploy`].
+pub fn wait_for_deploy(
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    deploy_hash: DeployHash,
+    maybe_timeout: Option<TimeDiff>,
+) -> Result<DeployOutcome, Error> {
+    block_on(crate::wait_for_deploy(
+        rpc_id,
+        node_address,
+        verbosity,
+        deploy_hash,
+        maybe_timeout,
+    ))
+}
+
+/// Blocking version of [`crate::wait_for_deploy_with_transport`].
+pub fn wait_for_deploy_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    deploy_hash: DeployHash,
+    maybe_timeout: Option<TimeDiff>,
+) -> Result<DeployOutcome, Error> {
+    block_on(crate::wait_for_deploy_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+        deploy_hash,
+        maybe_timeout,
+    ))
+}
+
+/// Blocking version of [`crate::get_block`].
+pub fn get_block(
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_block_identifier: Option<BlockIdentifier>,
+) -> Result<SuccessResponse<GetBlockResult>, Error> {
+    block_on(crate::get_block(
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_identifier,
+    ))
+}
+
+/// Blocking version of [`crate::get_block_with_transport`].
+pub fn get_block_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_block_identifier: Option<BlockIdentifier>,
+) -> Result<SuccessResponse<GetBlockResult>, Error> {
+    block_on(crate::get_block_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_identifier,
+    ))
+}
+
+/// Blocking version of [`crate::get_block_transfers`].
+pub fn get_block_transfers(
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_block_identifier: Option<BlockIdentifier>,
+) -> Result<SuccessResponse<GetBlockTransfersResult>, Error> {
+    block_on(crate::get_block_transfers(
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_identifier,
+    ))
+}
+
+/// Blocking version of [`crate::get_block_transfers_with_transport`].
+pub fn get_block_transfers_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_block_identifier: Option<BlockIdentifier>,
+) -> Result<SuccessResponse<GetBlockTransfersResult>, Error> {
+    block_on(crate::get_block_transfers_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_identifier,
+    ))
+}
+
+/// Blocking version of [`crate::get_state_root_hash`].
+pub fn get_state_root_hash(
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_block_identifier: Option<BlockIdentifier>,
+) -> Result<SuccessResponse<GetStateRootHashResult>, Error> {
+    block_on(crate::get_state_root_hash(
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_identifier,
+    ))
+}
+
+/// Blocking version of [`crate::get_state_root_hash_with_transport`].
+pub fn get_state_root_hash_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_block_identifier: Option<BlockIdentifier>,
+) -> Result<SuccessResponse<GetStateRootHashResult>, Error> {
+    block_on(crate::get_state_root_hash_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_identifier,
+    ))
+}
+
+/// Blocking version of [`crate::get_era_summary`].
+pub fn get_era_summary(
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_block_identifier: Option<BlockIdentifier>,
+) -> Result<SuccessResponse<GetEraSummaryResult>, Error> {
+    block_on(crate::get_era_summary(
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_identifier,
+    ))
+}
+
+/// Blocking version of [`crate::get_era_summary_with_transport`].
+pub fn get_era_summary_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_block_identifier: Option<BlockIdentifier>,
+) -> Result<SuccessResponse<GetEraSummaryResult>, Error> {
+    block_on(crate::get_era_summary_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_identifier,
+    ))
+}
+
+/// Blocking version of [`crate::query_global_state`].
+pub fn query_global_state(
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    global_state_identifier: Option<GlobalStateIdentifier>,
+    key: Key,
+    path: Vec<String>,
+) -> Result<SuccessResponse<QueryGlobalStateResult>, Error> {
+    block_on(crate::query_global_state(
+        rpc_id,
+        node_address,
+        verbosity,
+        global_state_identifier,
+        key,
+        path,
+    ))
+}
+
+/// Blocking version of [`crate::query_global_state_with_transport`].
+pub fn query_global_state_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    global_state_identifier: Option<GlobalStateIdentifier>,
+    key: Key,
+    path: Vec<String>,
+) -> Result<SuccessResponse<QueryGlobalStateResult>, Error> {
+    block_on(crate::query_global_state_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+        global_state_identifier,
+        key,
+        path,
+    ))
+}
+
+/// Blocking version of [`crate::query_balance`].
+pub fn query_balance(
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_global_state_identifier: Option<GlobalStateIdentifier>,
+    purse_identifier: PurseIdentifier,
+) -> Result<SuccessResponse<QueryBalanceResult>, Error> {
+    block_on(crate::query_balance(
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_global_state_identifier,
+        purse_identifier,
+    ))
+}
+
+/// Blocking version of [`crate::query_balance_with_transport`].
+pub fn query_balance_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_global_state_identifier: Option<GlobalStateIdentifier>,
+    purse_identifier: PurseIdentifier,
+) -> Result<SuccessResponse<QueryBalanceResult>, Error> {
+    block_on(crate::query_balance_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_global_state_identifier,
+        purse_identifier,
+    ))
+}
+
+/// Blocking version of [`crate::get_dictionary_item`].
+pub fn get_dictionary_item(
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    state_root_hash: Digest,
+    dictionary_item_identifier: DictionaryItemIdentifier,
+) -> Result<SuccessResponse<GetDictionaryItemResult>, Error> {
+    block_on(crate::get_dictionary_item(
+        rpc_id,
+        node_address,
+        verbosity,
+        state_root_hash,
+        dictionary_item_identifier,
+    ))
+}
+
+/// Blocking version of [`crate::get_dictionary_item_with_transport`].
+pub fn get_dictionary_item_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    state_root_hash: Digest,
+    dictionary_item_identifier: DictionaryItemIdentifier,
+) -> Result<SuccessResponse<GetDictionaryItemResult>, Error> {
+    block_on(crate::get_dictionary_item_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+        state_root_hash,
+        dictionary_item_identifier,
+    ))
+}
+
+/// Blocking version of [`crate::get_balance`].
+pub fn get_balance(
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    state_root_hash: Digest,
+    purse: URef,
+) -> Result<SuccessResponse<GetBalanceResult>, Error> {
+    block_on(crate::get_balance(
+        rpc_id,
+        node_address,
+        verbosity,
+        state_root_hash,
+        purse,
+    ))
+}
+
+/// Blocking version of [`crate::get_balance_with_transport`].
+pub fn get_balance_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    state_root_hash: Digest,
+    purse: URef,
+) -> Result<SuccessResponse<GetBalanceResult>, Error> {
+    block_on(crate::get_balance_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+        state_root_hash,
+        purse,
+    ))
+}
+
+/// Blocking version of [`crate::get_account`].
+pub fn get_account(
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_block_identifier: Option<BlockIdentifier>,
+    account_identifier: AccountIdentifier,
+) -> Result<SuccessResponse<GetAccountResult>, Error> {
+    block_on(crate::get_account(
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_identifier,
+        account_identifier,
+    ))
+}
+
+/// Blocking version of [`crate::get_account_with_transport`].
+pub fn get_account_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_block_identifier: Option<BlockIdentifier>,
+    account_identifier: AccountIdentifier,
+) -> Result<SuccessResponse<GetAccountResult>, Error> {
+    block_on(crate::get_account_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_identifier,
+        account_identifier,
+    ))
+}
+
+/// Blocking version of [`crate::get_auction_info`].
+pub fn get_auction_info(
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_block_identifier: Option<BlockIdentifier>,
+) -> Result<SuccessResponse<GetAuctionInfoResult>, Error> {
+    block_on(crate::get_auction_info(
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_identifier,
+    ))
+}
+
+/// Blocking version of [`crate::get_auction_info_with_transport`].
+pub fn get_auction_info_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_block_identifier: Option<BlockIdentifier>,
+) -> Result<SuccessResponse<GetAuctionInfoResult>, Error> {
+    block_on(crate::get_auction_info_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_identifier,
+    ))
+}
+
+/// Blocking version of [`crate::get_validator_changes`].
+pub fn get_validator_changes(
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+) -> Result<SuccessResponse<GetValidatorChangesResult>, Error> {
+    block_on(crate::get_validator_changes(
+        rpc_id,
+        node_address,
+        verbosity,
+    ))
+}
+
+/// Blocking version of [`crate::get_validator_changes_with_transport`].
+pub fn get_validator_changes_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+) -> Result<SuccessResponse<GetValidatorChangesResult>, Error> {
+    block_on(crate::get_validator_changes_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+    ))
+}
+
+/// Blocking version of [`crate::get_peers`].
+pub fn get_peers(
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+) -> Result<SuccessResponse<GetPeersResult>, Error> {
+    block_on(crate::get_peers(rpc_id, node_address, verbosity))
+}
+
+/// Blocking version of [`crate::get_peers_with_transport`].
+pub fn get_peers_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+) -> Result<SuccessResponse<GetPeersResult>, Error> {
+    block_on(crate::get_peers_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+    ))
+}
+
+/// Blocking version of [`crate::get_node_status`].
+pub fn get_node_status(
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+) -> Result<SuccessResponse<GetNodeStatusResult>, Error> {
+    block_on(crate::get_node_status(rpc_id, node_address, verbosity))
+}
+
+/// Blocking version of [`crate::get_node_status_with_transport`].
+pub fn get_node_status_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+) -> Result<SuccessResponse<GetNodeStatusResult>, Error> {
+    block_on(crate::get_node_status_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+    ))
+}
+
+/// Blocking version of [`crate::get_chainspec`].
+pub fn get_chainspec(
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+) -> Result<SuccessResponse<GetChainspecResult>, Error> {
+    block_on(crate::get_chainspec(rpc_id, node_address, verbosity))
+}
+
+/// Blocking version of [`crate::get_chainspec_with_transport`].
+pub fn get_chainspec_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+) -> Result<SuccessResponse<GetChainspecResult>, Error> {
+    block_on(crate::get_chainspec_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+    ))
+}
+
+/// Blocking version of [`crate::list_rpcs`].
+pub fn list_rpcs(
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+) -> Result<SuccessResponse<ListRpcsResult>, Error> {
+    block_on(crate::list_rpcs(rpc_id, node_address, verbosity))
+}
+
+/// Blocking version of [`crate::list_rpcs_with_transport`].
+pub fn list_rpcs_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+) -> Result<SuccessResponse<ListRpcsResult>, Error> {
+    block_on(crate::list_rpcs_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+    ))
+}
+
+/// Blocking version of [`crate::get_era_info`].
+#[deprecated(
+    since = "2.0.0",
+    note = "prefer 'get_era_summary' as it doesn't require a switch block"
+)]
+pub fn get_era_info(
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_block_identifier: Option<BlockIdentifier>,
+) -> Result<SuccessResponse<GetEraInfoResult>, Error> {
+    #[allow(deprecated)]
+    block_on(crate::get_era_info(
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_identifier,
+    ))
+}
+
+/// Blocking version of [`crate::get_era_info_with_transport`].
+#[deprecated(
+    since = "2.0.0",
+    note = "prefer 'get_era_summary' as it doesn't require a switch block"
+)]
+pub fn get_era_info_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_block_identifier: Option<BlockIdentifier>,
+) -> Result<SuccessResponse<GetEraInfoResult>, Error> {
+    #[allow(deprecated)]
+    block_on(crate::get_era_info_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_identifier,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use jsonrpc_lite::JsonRpc;
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct InMemoryTransport;
+
+    #[async_trait]
+    impl Transport for InMemoryTransport {
+        async fn send(
+            &self,
+            _url: &str,
+            rpc_id: &JsonRpcId,
+            _rpc_method: &'static str,
+            _request: &JsonRpc,
+        ) -> Result<JsonRpc, Error> {
+            Ok(JsonRpc::success(
+                rpc_id,
+                &json!({ "api_version": "1.5.0", "peers": [] }),
+            ))
+        }
+    }
+
+    #[test]
+    fn should_send_request_synchronously() {
+        let response = get_peers_with_transport(
+            &InMemoryTransport,
+            JsonRpcId::from(1),
+            "http://localhost:7777",
+            Verbosity::Low,
+        )
+        .expect("should succeed");
+        assert_eq!(response.id, JsonRpcId::from(1));
+        assert!(response.result.peers.is_empty());
+
+        let response =
+            cli::get_peers_with_transport(&InMemoryTransport, "2", "http://localhost:7777", 0)
+                .expect("should succeed");
+        assert_eq!(response.id, JsonRpcId::from(2));
+    }
+}
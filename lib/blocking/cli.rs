@@ -0,0 +1,925 @@
+//! A blocking API, mirroring the asynchronous RPC functions in [`crate::cli`].
+//!
+//! For details of the parameters, see [the `cli` module docs](crate::cli#common-parameters).
+
+#[cfg(feature = "std-fs-io")]
+use crate::ApprovalsCheck;
+use crate::{
+    cli::{CliError, DeployStrParams, DictionaryItemStrParams, PaymentStrParams, SessionStrParams},
+    rpcs::results::{
+        GetAccountResult, GetAuctionInfoResult, GetBalanceResult, GetBlockResult,
+        GetBlockTransfersResult, GetChainspecResult, GetDeployResult, GetDictionaryItemResult,
+        GetEraInfoResult, GetEraSummaryResult, GetNodeStatusResult, GetPeersResult,
+        GetStateRootHashResult, GetValidatorChangesResult, ListRpcsResult, PutDeployResult,
+        QueryBalanceResult, QueryGlobalStateResult, SpeculativeExecResult,
+    },
+    DeployOutcome, SuccessResponse, Transport,
+};
+
+use super::block_on;
+
+/// Blocking version of [`crate::cli::put_deploy`].
+pub fn put_deploy(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    deploy_params: DeployStrParams<'_>,
+    session_params: SessionStrParams<'_>,
+    payment_params: PaymentStrParams<'_>,
+) -> Result<SuccessResponse<PutDeployResult>, CliError> {
+    block_on(crate::cli::put_deploy(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        deploy_params,
+        session_params,
+        payment_params,
+    ))
+}
+
+/// Blocking version of [`crate::cli::put_deploy_with_transport`].
+pub fn put_deploy_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    deploy_params: DeployStrParams<'_>,
+    session_params: SessionStrParams<'_>,
+    payment_params: PaymentStrParams<'_>,
+) -> Result<SuccessResponse<PutDeployResult>, CliError> {
+    block_on(crate::cli::put_deploy_with_transport(
+        transport,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        deploy_params,
+        session_params,
+        payment_params,
+    ))
+}
+
+/// Blocking version of [`crate::cli::speculative_put_deploy`].
+pub fn speculative_put_deploy(
+    maybe_block_id: &str,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    deploy_params: DeployStrParams<'_>,
+    session_params: SessionStrParams<'_>,
+    payment_params: PaymentStrParams<'_>,
+) -> Result<SuccessResponse<SpeculativeExecResult>, CliError> {
+    block_on(crate::cli::speculative_put_deploy(
+        maybe_block_id,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        deploy_params,
+        session_params,
+        payment_params,
+    ))
+}
+
+/// Blocking version of [`crate::cli::speculative_put_deploy_with_transport`].
+#[allow(clippy::too_many_arguments)]
+pub fn speculative_put_deploy_with_transport(
+    transport: &dyn Transport,
+    maybe_block_id: &str,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    deploy_params: DeployStrParams<'_>,
+    session_params: SessionStrParams<'_>,
+    payment_params: PaymentStrParams<'_>,
+) -> Result<SuccessResponse<SpeculativeExecResult>, CliError> {
+    block_on(crate::cli::speculative_put_deploy_with_transport(
+        transport,
+        maybe_block_id,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        deploy_params,
+        session_params,
+        payment_params,
+    ))
+}
+
+/// Blocking version of [`crate::cli::check_approvals`].
+#[cfg(feature = "std-fs-io")]
+pub fn check_approvals(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
+    input_path: &str,
+) -> Result<ApprovalsCheck, CliError> {
+    block_on(crate::cli::check_approvals(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+        input_path,
+    ))
+}
+
+/// Blocking version of [`crate::cli::check_approvals_with_transport`].
+#[cfg(feature = "std-fs-io")]
+pub fn check_approvals_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
+    input_path: &str,
+) -> Result<ApprovalsCheck, CliError> {
+    block_on(crate::cli::check_approvals_with_transport(
+        transport,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+        input_path,
+    ))
+}
+
+/// Blocking version of [`crate::cli::send_deploy_file`].
+#[cfg(feature = "std-fs-io")]
+pub fn send_deploy_file(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    input_path: &str,
+) -> Result<SuccessResponse<PutDeployResult>, CliError> {
+    block_on(crate::cli::send_deploy_file(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        input_path,
+    ))
+}
+
+/// Blocking version of [`crate::cli::send_deploy_file_with_transport`].
+#[cfg(feature = "std-fs-io")]
+pub fn send_deploy_file_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    input_path: &str,
+) -> Result<SuccessResponse<PutDeployResult>, CliError> {
+    block_on(crate::cli::send_deploy_file_with_transport(
+        transport,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        input_path,
+    ))
+}
+
+/// Blocking version of [`crate::cli::speculative_send_deploy_file`].
+#[cfg(feature = "std-fs-io")]
+pub fn speculative_send_deploy_file(
+    maybe_block_id: &str,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    input_path: &str,
+) -> Result<SuccessResponse<SpeculativeExecResult>, CliError> {
+    block_on(crate::cli::speculative_send_deploy_file(
+        maybe_block_id,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        input_path,
+    ))
+}
+
+/// Blocking version of [`crate::cli::speculative_send_deploy_file_with_transport`].
+#[cfg(feature = "std-fs-io")]
+pub fn speculative_send_deploy_file_with_transport(
+    transport: &dyn Transport,
+    maybe_block_id: &str,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    input_path: &str,
+) -> Result<SuccessResponse<SpeculativeExecResult>, CliError> {
+    block_on(crate::cli::speculative_send_deploy_file_with_transport(
+        transport,
+        maybe_block_id,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        input_path,
+    ))
+}
+
+/// Blocking version of [`crate::cli::transfer`].
+#[allow(clippy::too_many_arguments)]
+pub fn transfer(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    amount: &str,
+    target_account: &str,
+    transfer_id: &str,
+    deploy_params: DeployStrParams<'_>,
+    payment_params: PaymentStrParams<'_>,
+) -> Result<SuccessResponse<PutDeployResult>, CliError> {
+    block_on(crate::cli::transfer(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        amount,
+        target_account,
+        transfer_id,
+        deploy_params,
+        payment_params,
+    ))
+}
+
+/// Blocking version of [`crate::cli::transfer_with_transport`].
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    amount: &str,
+    target_account: &str,
+    transfer_id: &str,
+    deploy_params: DeployStrParams<'_>,
+    payment_params: PaymentStrParams<'_>,
+) -> Result<SuccessResponse<PutDeployResult>, CliError> {
+    block_on(crate::cli::transfer_with_transport(
+        transport,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        amount,
+        target_account,
+        transfer_id,
+        deploy_params,
+        payment_params,
+    ))
+}
+
+/// Blocking version of [`crate::cli::speculative_transfer`].
+#[allow(clippy::too_many_arguments)]
+pub fn speculative_transfer(
+    maybe_block_id: &str,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    amount: &str,
+    target_account: &str,
+    transfer_id: &str,
+    deploy_params: DeployStrParams<'_>,
+    payment_params: PaymentStrParams<'_>,
+) -> Result<SuccessResponse<SpeculativeExecResult>, CliError> {
+    block_on(crate::cli::speculative_transfer(
+        maybe_block_id,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        amount,
+        target_account,
+        transfer_id,
+        deploy_params,
+        payment_params,
+    ))
+}
+
+/// Blocking version of [`crate::cli::speculative_transfer_with_transport`].
+#[allow(clippy::too_many_arguments)]
+pub fn speculative_transfer_with_transport(
+    transport: &dyn Transport,
+    maybe_block_id: &str,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    amount: &str,
+    target_account: &str,
+    transfer_id: &str,
+    deploy_params: DeployStrParams<'_>,
+    payment_params: PaymentStrParams<'_>,
+) -> Result<SuccessResponse<SpeculativeExecResult>, CliError> {
+    block_on(crate::cli::speculative_transfer_with_transport(
+        transport,
+        maybe_block_id,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        amount,
+        target_account,
+        transfer_id,
+        deploy_params,
+        payment_params,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_deploy`].
+pub fn get_deploy(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    deploy_hash: &str,
+    finalized_approvals: bool,
+) -> Result<SuccessResponse<GetDeployResult>, CliError> {
+    block_on(crate::cli::get_deploy(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        deploy_hash,
+        finalized_approvals,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_deploy_with_transport`].
+pub fn get_deploy_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    deploy_hash: &str,
+    finalized_approvals: bool,
+) -> Result<SuccessResponse<GetDeployResult>, CliError> {
+    block_on(crate::cli::get_deploy_with_transport(
+        transport,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        deploy_hash,
+        finalized_approvals,
+    ))
+}
+
+/// Blocking version of [`crate::cli::wait_for_deploy`].
+pub fn wait_for_deploy(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    deploy_hash: &str,
+    maybe_timeout: &str,
+) -> Result<DeployOutcome, CliError> {
+    block_on(crate::cli::wait_for_deploy(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        deploy_hash,
+        maybe_timeout,
+    ))
+}
+
+/// Blocking version of [`crate::cli::wait_for_deploy_with_transport`].
+pub fn wait_for_deploy_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    deploy_hash: &str,
+    maybe_timeout: &str,
+) -> Result<DeployOutcome, CliError> {
+    block_on(crate::cli::wait_for_deploy_with_transport(
+        transport,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        deploy_hash,
+        maybe_timeout,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_block`].
+pub fn get_block(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
+) -> Result<SuccessResponse<GetBlockResult>, CliError> {
+    block_on(crate::cli::get_block(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_block_with_transport`].
+pub fn get_block_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
+) -> Result<SuccessResponse<GetBlockResult>, CliError> {
+    block_on(crate::cli::get_block_with_transport(
+        transport,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_block_transfers`].
+pub fn get_block_transfers(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
+) -> Result<SuccessResponse<GetBlockTransfersResult>, CliError> {
+    block_on(crate::cli::get_block_transfers(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_block_transfers_with_transport`].
+pub fn get_block_transfers_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
+) -> Result<SuccessResponse<GetBlockTransfersResult>, CliError> {
+    block_on(crate::cli::get_block_transfers_with_transport(
+        transport,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_state_root_hash`].
+pub fn get_state_root_hash(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
+) -> Result<SuccessResponse<GetStateRootHashResult>, CliError> {
+    block_on(crate::cli::get_state_root_hash(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_state_root_hash_with_transport`].
+pub fn get_state_root_hash_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
+) -> Result<SuccessResponse<GetStateRootHashResult>, CliError> {
+    block_on(crate::cli::get_state_root_hash_with_transport(
+        transport,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_era_summary`].
+pub fn get_era_summary(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
+) -> Result<SuccessResponse<GetEraSummaryResult>, CliError> {
+    block_on(crate::cli::get_era_summary(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_era_summary_with_transport`].
+pub fn get_era_summary_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
+) -> Result<SuccessResponse<GetEraSummaryResult>, CliError> {
+    block_on(crate::cli::get_era_summary_with_transport(
+        transport,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+    ))
+}
+
+/// Blocking version of [`crate::cli::query_global_state`].
+pub fn query_global_state(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
+    maybe_state_root_hash: &str,
+    key: &str,
+    path: &str,
+) -> Result<SuccessResponse<QueryGlobalStateResult>, CliError> {
+    block_on(crate::cli::query_global_state(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+        maybe_state_root_hash,
+        key,
+        path,
+    ))
+}
+
+/// Blocking version of [`crate::cli::query_global_state_with_transport`].
+#[allow(clippy::too_many_arguments)]
+pub fn query_global_state_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
+    maybe_state_root_hash: &str,
+    key: &str,
+    path: &str,
+) -> Result<SuccessResponse<QueryGlobalStateResult>, CliError> {
+    block_on(crate::cli::query_global_state_with_transport(
+        transport,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+        maybe_state_root_hash,
+        key,
+        path,
+    ))
+}
+
+/// Blocking version of [`crate::cli::query_balance`].
+pub fn query_balance(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
+    maybe_state_root_hash: &str,
+    purse_id: &str,
+) -> Result<SuccessResponse<QueryBalanceResult>, CliError> {
+    block_on(crate::cli::query_balance(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+        maybe_state_root_hash,
+        purse_id,
+    ))
+}
+
+/// Blocking version of [`crate::cli::query_balance_with_transport`].
+pub fn query_balance_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
+    maybe_state_root_hash: &str,
+    purse_id: &str,
+) -> Result<SuccessResponse<QueryBalanceResult>, CliError> {
+    block_on(crate::cli::query_balance_with_transport(
+        transport,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+        maybe_state_root_hash,
+        purse_id,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_dictionary_item`].
+pub fn get_dictionary_item(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    state_root_hash: &str,
+    dictionary_item_str_params: DictionaryItemStrParams<'_>,
+) -> Result<SuccessResponse<GetDictionaryItemResult>, CliError> {
+    block_on(crate::cli::get_dictionary_item(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        state_root_hash,
+        dictionary_item_str_params,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_dictionary_item_with_transport`].
+pub fn get_dictionary_item_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    state_root_hash: &str,
+    dictionary_item_str_params: DictionaryItemStrParams<'_>,
+) -> Result<SuccessResponse<GetDictionaryItemResult>, CliError> {
+    block_on(crate::cli::get_dictionary_item_with_transport(
+        transport,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        state_root_hash,
+        dictionary_item_str_params,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_balance`].
+pub fn get_balance(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    state_root_hash: &str,
+    purse: &str,
+) -> Result<SuccessResponse<GetBalanceResult>, CliError> {
+    block_on(crate::cli::get_balance(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        state_root_hash,
+        purse,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_balance_with_transport`].
+pub fn get_balance_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    state_root_hash: &str,
+    purse: &str,
+) -> Result<SuccessResponse<GetBalanceResult>, CliError> {
+    block_on(crate::cli::get_balance_with_transport(
+        transport,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        state_root_hash,
+        purse,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_account`].
+pub fn get_account(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
+    account_identifier: &str,
+) -> Result<SuccessResponse<GetAccountResult>, CliError> {
+    block_on(crate::cli::get_account(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+        account_identifier,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_account_with_transport`].
+pub fn get_account_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
+    account_identifier: &str,
+) -> Result<SuccessResponse<GetAccountResult>, CliError> {
+    block_on(crate::cli::get_account_with_transport(
+        transport,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+        account_identifier,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_auction_info`].
+pub fn get_auction_info(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
+) -> Result<SuccessResponse<GetAuctionInfoResult>, CliError> {
+    block_on(crate::cli::get_auction_info(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_auction_info_with_transport`].
+pub fn get_auction_info_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
+) -> Result<SuccessResponse<GetAuctionInfoResult>, CliError> {
+    block_on(crate::cli::get_auction_info_with_transport(
+        transport,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_validator_changes`].
+pub fn get_validator_changes(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+) -> Result<SuccessResponse<GetValidatorChangesResult>, CliError> {
+    block_on(crate::cli::get_validator_changes(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_validator_changes_with_transport`].
+pub fn get_validator_changes_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+) -> Result<SuccessResponse<GetValidatorChangesResult>, CliError> {
+    block_on(crate::cli::get_validator_changes_with_transport(
+        transport,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_peers`].
+pub fn get_peers(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+) -> Result<SuccessResponse<GetPeersResult>, CliError> {
+    block_on(crate::cli::get_peers(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_peers_with_transport`].
+pub fn get_peers_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+) -> Result<SuccessResponse<GetPeersResult>, CliError> {
+    block_on(crate::cli::get_peers_with_transport(
+        transport,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_node_status`].
+pub fn get_node_status(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+) -> Result<SuccessResponse<GetNodeStatusResult>, CliError> {
+    block_on(crate::cli::get_node_status(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_node_status_with_transport`].
+pub fn get_node_status_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+) -> Result<SuccessResponse<GetNodeStatusResult>, CliError> {
+    block_on(crate::cli::get_node_status_with_transport(
+        transport,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_chainspec`].
+pub fn get_chainspec(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+) -> Result<SuccessResponse<GetChainspecResult>, CliError> {
+    block_on(crate::cli::get_chainspec(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_chainspec_with_transport`].
+pub fn get_chainspec_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+) -> Result<SuccessResponse<GetChainspecResult>, CliError> {
+    block_on(crate::cli::get_chainspec_with_transport(
+        transport,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+    ))
+}
+
+/// Blocking version of [`crate::cli::list_rpcs`].
+pub fn list_rpcs(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+) -> Result<SuccessResponse<ListRpcsResult>, CliError> {
+    block_on(crate::cli::list_rpcs(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+    ))
+}
+
+/// Blocking version of [`crate::cli::list_rpcs_with_transport`].
+pub fn list_rpcs_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+) -> Result<SuccessResponse<ListRpcsResult>, CliError> {
+    block_on(crate::cli::list_rpcs_with_transport(
+        transport,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_era_info`].
+#[deprecated(
+    since = "2.0.0",
+    note = "prefer 'get_era_summary' as it doesn't require a switch block"
+)]
+pub fn get_era_info(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
+) -> Result<SuccessResponse<GetEraInfoResult>, CliError> {
+    #[allow(deprecated)]
+    block_on(crate::cli::get_era_info(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+    ))
+}
+
+/// Blocking version of [`crate::cli::get_era_info_with_transport`].
+#[deprecated(
+    since = "2.0.0",
+    note = "prefer 'get_era_summary' as it doesn't require a switch block"
+)]
+pub fn get_era_info_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
+) -> Result<SuccessResponse<GetEraInfoResult>, CliError> {
+    #[allow(deprecated)]
+    block_on(crate::cli::get_era_info_with_transport(
+        transport,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+    ))
+}
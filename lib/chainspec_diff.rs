@@ -0,0 +1,187 @@
+use std::str;
+
+use serde::Serialize;
+
+use crate::{Error, HttpTransport, JsonRpcId, Transport, Verbosity};
+
+/// A setting whose value differs between two chainspecs.
+#[derive(Clone, PartialEq, Eq, Serialize, Debug)]
+pub struct ChainspecDifference {
+    /// The dotted path of the setting, e.g. "deploys.max_ttl".
+    pub key: String,
+    /// The value of the setting in the first chainspec as TOML, or `None` if absent.
+    pub value: Option<String>,
+    /// The value of the setting in the other chainspec as TOML, or `None` if absent.
+    pub other_value: Option<String>,
+}
+
+/// A comparison of two chainspecs, setting by setting.
+#[derive(Clone, PartialEq, Eq, Serialize, Debug)]
+pub struct ChainspecDiff {
+    /// The settings which differ, ordered by key.
+    pub differences: Vec<ChainspecDifference>,
+}
+
+impl ChainspecDiff {
+    /// Compares `chainspec_bytes` against `other_chainspec_bytes`, the contents of two
+    /// chainspec.toml files.
+    ///
+    /// Tables are compared key by key, whereas arrays are compared as a whole.
+    pub fn new(chainspec_bytes: &[u8], other_chainspec_bytes: &[u8]) -> Result<Self, Error> {
+        let mut differences = vec![];
+        diff_tables(
+            "",
+            &parse_table(chainspec_bytes)?,
+            &parse_table(other_chainspec_bytes)?,
+            &mut differences,
+        );
+        Ok(ChainspecDiff { differences })
+    }
+
+    /// Returns `true` if the chainspecs have no differences.
+    pub fn is_empty(&self) -> bool {
+        self.differences.is_empty()
+    }
+}
+
+fn parse_table(chainspec_bytes: &[u8]) -> Result<toml::Table, Error> {
+    let chainspec = str::from_utf8(chainspec_bytes)
+        .map_err(|error| Error::InvalidChainspec(error.to_string()))?;
+    toml::from_str(chainspec).map_err(|error| Error::InvalidChainspec(error.to_string()))
+}
+
+fn diff_tables(
+    prefix: &str,
+    table: &toml::Table,
+    other_table: &toml::Table,
+    differences: &mut Vec<ChainspecDifference>,
+) {
+    let mut keys: Vec<&String> = table.keys().chain(other_table.keys()).collect();
+    keys.sort();
+    keys.dedup();
+    for key in keys {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        match (table.get(key), other_table.get(key)) {
+            (Some(toml::Value::Table(table)), Some(toml::Value::Table(other_table))) => {
+                diff_tables(&path, table, other_table, differences)
+            }
+            (maybe_value, maybe_other_value) if maybe_value != maybe_other_value => {
+                differences.push(ChainspecDifference {
+                    key: path,
+                    value: maybe_value.map(toml::Value::to_string),
+                    other_value: maybe_other_value.map(toml::Value::to_string),
+                });
+            }
+            _ => (),
+        }
+    }
+}
+
+/// Compares the chainspecs of two nodes.
+///
+/// Sends a JSON-RPC `info_get_chainspec` request to each of the specified nodes, then compares the
+/// chainspecs as per [`ChainspecDiff::new`].
+///
+/// For details of the parameters, see [the module docs](crate#common-parameters).
+pub async fn diff_chainspecs(
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    other_node_address: &str,
+    verbosity: Verbosity,
+) -> Result<ChainspecDiff, Error> {
+    diff_chainspecs_with_transport(
+        &HttpTransport::default(),
+        rpc_id,
+        node_address,
+        other_node_address,
+        verbosity,
+    )
+    .await
+}
+
+/// Same as [`diff_chainspecs`], but sends the requests via the given `transport`.
+pub async fn diff_chainspecs_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    other_node_address: &str,
+    verbosity: Verbosity,
+) -> Result<ChainspecDiff, Error> {
+    let chainspec_bytes =
+        crate::get_chainspec_with_transport(transport, rpc_id.clone(), node_address, verbosity)
+            .await?
+            .result
+            .chainspec_bytes
+            .chainspec_bytes;
+    let other_chainspec_bytes =
+        crate::get_chainspec_with_transport(transport, rpc_id, other_node_address, verbosity)
+            .await?
+            .result
+            .chainspec_bytes
+            .chainspec_bytes;
+    ChainspecDiff::new(&chainspec_bytes, &other_chainspec_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_diff_chainspecs() {
+        let chainspec = br#"
+[network]
+name = 'casper-test'
+
+[deploys]
+max_ttl = '18 hours'
+max_dependencies = 10
+
+[wasm.storage_costs]
+gas_per_byte = 630_000
+"#;
+        let other_chainspec = br#"
+[network]
+name = 'casper-test'
+
+[deploys]
+max_ttl = '1 day'
+max_dependencies = 10
+
+[wasm.storage_costs]
+gas_per_byte = 1_117_587
+
+[upcoming_feature]
+enabled = true
+"#;
+        assert!(ChainspecDiff::new(chainspec, chainspec).unwrap().is_empty());
+
+        let diff = ChainspecDiff::new(chainspec, other_chainspec).unwrap();
+        let keys: Vec<_> = diff
+            .differences
+            .iter()
+            .map(|difference| difference.key.as_str())
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                "deploys.max_ttl",
+                "upcoming_feature",
+                "wasm.storage_costs.gas_per_byte"
+            ]
+        );
+        assert_eq!(
+            diff.differences[0],
+            ChainspecDifference {
+                key: "deploys.max_ttl".to_string(),
+                value: Some("\"18 hours\"".to_string()),
+                other_value: Some("\"1 day\"".to_string()),
+            }
+        );
+        assert!(diff.differences[1].value.is_none());
+        assert!(diff.differences[1].other_value.is_some());
+    }
+}
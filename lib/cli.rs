@@ -12,17 +12,18 @@
 //!   If it can be parsed as an `i64` it will be used as a JSON integer. If empty, a random `i64`
 //!   will be assigned.  Otherwise the provided string will be used verbatim.
 //! * `node_address` - The hostname or IP and port of the server, e.g. `http://127.0.0.1:7777`.
-//! * `verbosity_level` - When `1`, the JSON-RPC request will be printed to `stdout` with long
-//!   string fields (e.g. hex-formatted raw Wasm bytes) shortened to a string indicating the char
-//!   count of the field.  When `verbosity_level` is greater than `1`, the request will be printed
-//!   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the request
-//!   will not be printed to `stdout`.
+//! * `verbosity_level` - The level of detail of the JSON-RPC request and response emitted as
+//!   debug-level `tracing` events (see [the crate docs](crate#tracing)).  When `verbosity_level`
+//!   is `0` or `1`, long string fields (e.g. hex-formatted raw Wasm bytes) are shortened to a
+//!   string indicating the char count of the field.  When `verbosity_level` is greater than `1`,
+//!   there is no abbreviation of long fields.
 //! * `maybe_block_id` - Must be a hex-encoded, 32-byte hash digest or a `u64` representing the
 //!   [`Block`] height or empty.  If empty, the latest `Block` known on the server will be used.
 
 /// Functions for creating Deploys.
 pub mod deploy;
 mod deploy_str_params;
+mod deploy_template;
 mod dictionary_item_str_params;
 mod error;
 mod json_args;
@@ -41,6 +42,12 @@ use casper_types::URef;
 #[cfg(doc)]
 use casper_types::{account::AccountHash, Key};
 
+#[cfg(feature = "tokio")]
+use crate::DeployOutcome;
+#[cfg(feature = "std-fs-io")]
+use crate::{
+    rpcs::results::ChainspecRawBytes, ApprovalsCheck, DeployInspection, Signer, WasmInspection,
+};
 use crate::{
     rpcs::{
         results::{
@@ -53,15 +60,17 @@ use crate::{
         DictionaryItemIdentifier,
     },
     types::Deploy,
-    SuccessResponse,
+    ChainspecDiff, HttpTransport, SuccessResponse, Transport,
 };
 #[cfg(doc)]
-use crate::{Account, Block, Error, StoredValue, Transfer};
+use crate::{types::DeployBuilder, Account, Block, Error, StoredValue, Transfer};
 #[cfg(doc)]
 use casper_types::PublicKey;
 pub use deploy_str_params::DeployStrParams;
+pub use deploy_template::DeployTemplate;
 pub use dictionary_item_str_params::DictionaryItemStrParams;
 pub use error::CliError;
+pub(crate) use json_args::cl_value_to_json;
 pub use json_args::{
     help as json_args_help, Error as JsonArgsError, ErrorDetails as JsonArgsErrorDetails, JsonArg,
 };
@@ -74,6 +83,19 @@ pub use simple_args::{help as simple_args_help, insert_arg};
 
 /// Creates a [`Deploy`] and sends it to the network for execution.
 ///
+/// If the payment amount of `payment_params` is `auto`, `auto:<MARGIN>%` or
+/// `auto:<MARGIN>%:<TRIAL_AMOUNT>`, the deploy is first speculatively executed on the node, and its
+/// payment amount set to the resulting cost plus the margin (10% by default) as per
+/// [`DeployBuilder::build_with_auto_payment`].  The trial deploy uses a standard payment of the
+/// trial amount (100 CSPR by default), which caps the gas it can use and which the account's
+/// balance must cover.
+///
+/// If the chain name of `deploy_params` is empty or its `discover_limits` is true, the network's
+/// deploy limits are discovered from the node as per
+/// [`discover_network_limits`](crate::discover_network_limits), and the deploy is checked against
+/// those limits before being signed.  An empty chain name is replaced by the node's, while a given
+/// one must match the node's.
+///
 /// For details of the parameters, see [the module docs](crate::cli#common-parameters) or the docs
 /// of the individual parameter types.
 pub async fn put_deploy(
@@ -83,18 +105,53 @@ pub async fn put_deploy(
     deploy_params: DeployStrParams<'_>,
     session_params: SessionStrParams<'_>,
     payment_params: PaymentStrParams<'_>,
+) -> Result<SuccessResponse<PutDeployResult>, CliError> {
+    put_deploy_with_transport(
+        &HttpTransport::default(),
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        deploy_params,
+        session_params,
+        payment_params,
+    )
+    .await
+}
+
+/// Same as [`put_deploy`], but sends the request via the given `transport`.
+pub async fn put_deploy_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    deploy_params: DeployStrParams<'_>,
+    session_params: SessionStrParams<'_>,
+    payment_params: PaymentStrParams<'_>,
 ) -> Result<SuccessResponse<PutDeployResult>, CliError> {
     let rpc_id = parse::rpc_id(maybe_rpc_id);
     let verbosity = parse::verbosity(verbosity_level);
-    let deploy =
-        deploy::with_payment_and_session(deploy_params, payment_params, session_params, false)?;
-    crate::put_deploy(rpc_id, node_address, verbosity, deploy)
+    let maybe_auto_payment = parse::auto_payment(payment_params.payment_amount)?;
+    let deploy = deploy::with_payment_and_session_for_node(
+        transport,
+        rpc_id.clone(),
+        node_address,
+        verbosity,
+        maybe_auto_payment,
+        deploy_params,
+        payment_params,
+        session_params,
+    )
+    .await?;
+    crate::put_deploy_with_transport(transport, rpc_id, node_address, verbosity, deploy)
         .await
         .map_err(CliError::from)
 }
 
 /// Creates a [`Deploy`] and sends it to the specified node for speculative execution.
 ///
+/// As for [`put_deploy`], the chain name and network limits are discovered from the node if the
+/// chain name of `deploy_params` is empty or its `discover_limits` is true.
+///
 /// For details of the parameters, see [the module docs](crate::cli#common-parameters) or the docs
 /// of the individual parameter types.
 pub async fn speculative_put_deploy(
@@ -105,15 +162,56 @@ pub async fn speculative_put_deploy(
     deploy_params: DeployStrParams<'_>,
     session_params: SessionStrParams<'_>,
     payment_params: PaymentStrParams<'_>,
+) -> Result<SuccessResponse<SpeculativeExecResult>, CliError> {
+    speculative_put_deploy_with_transport(
+        &HttpTransport::default(),
+        maybe_block_id,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        deploy_params,
+        session_params,
+        payment_params,
+    )
+    .await
+}
+
+/// Same as [`speculative_put_deploy`], but sends the request via the given `transport`.
+#[allow(clippy::too_many_arguments)]
+pub async fn speculative_put_deploy_with_transport(
+    transport: &dyn Transport,
+    maybe_block_id: &str,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    deploy_params: DeployStrParams<'_>,
+    session_params: SessionStrParams<'_>,
+    payment_params: PaymentStrParams<'_>,
 ) -> Result<SuccessResponse<SpeculativeExecResult>, CliError> {
     let rpc_id = parse::rpc_id(maybe_rpc_id);
     let verbosity = parse::verbosity(verbosity_level);
-    let deploy =
-        deploy::with_payment_and_session(deploy_params, payment_params, session_params, false)?;
+    let deploy = deploy::with_payment_and_session_for_node(
+        transport,
+        rpc_id.clone(),
+        node_address,
+        verbosity,
+        None,
+        deploy_params,
+        payment_params,
+        session_params,
+    )
+    .await?;
     let speculative_exec = parse::block_identifier(maybe_block_id)?;
-    crate::speculative_exec(rpc_id, node_address, speculative_exec, verbosity, deploy)
-        .await
-        .map_err(CliError::from)
+    crate::speculative_exec_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        speculative_exec,
+        verbosity,
+        deploy,
+    )
+    .await
+    .map_err(CliError::from)
 }
 /// Returns a [`Deploy`] and outputs it to a file or stdout if the `std-fs-io` feature is enabled.
 ///
@@ -121,13 +219,15 @@ pub async fn speculative_put_deploy(
 /// and then sent to the network for execution using [`send_deploy_file`].  Alternatively, the
 /// returned `Deploy` can be signed via the [`Deploy::sign`] method.
 ///
-/// If the `std-fs-io` feature is NOT enabled, `maybe_output_path` and `force` are ignored.
-/// Otherwise, `maybe_output_path` specifies the output file path, or if empty, will print it to
-/// `stdout`.  If `force` is true, and a file exists at `maybe_output_path`, it will be
-/// overwritten.  If `force` is false and a file exists at `maybe_output_path`,
-/// [`Error::FileAlreadyExists`] is returned and the file will not be written.
+/// If the `std-fs-io` feature is NOT enabled, `maybe_output_path`, `format` and `force` are
+/// ignored.  Otherwise, `maybe_output_path` specifies the output file path, or if empty, will print
+/// it to `stdout`.  `format` is "json" or "binary", or if empty, defaults to "json".  If `force` is
+/// true, and a file exists at `maybe_output_path`, it will be overwritten.  If `force` is false and
+/// a file exists at `maybe_output_path`, [`Error::FileAlreadyExists`] is returned and the file will
+/// not be written.
 pub fn make_deploy(
     #[allow(unused_variables)] maybe_output_path: &str,
+    #[allow(unused_variables)] format: &str,
     deploy_params: DeployStrParams<'_>,
     session_params: SessionStrParams<'_>,
     payment_params: PaymentStrParams<'_>,
@@ -137,8 +237,9 @@ pub fn make_deploy(
         deploy::with_payment_and_session(deploy_params, payment_params, session_params, true)?;
     #[cfg(feature = "std-fs-io")]
     {
+        let format = parse::deploy_format(format)?;
         let output = parse::output_kind(maybe_output_path, force);
-        crate::output_deploy(output, &deploy).map_err(CliError::from)?;
+        crate::output_deploy(output, format, &deploy).map_err(CliError::from)?;
     }
     Ok(deploy)
 }
@@ -146,20 +247,284 @@ pub fn make_deploy(
 /// Reads a previously-saved [`Deploy`] from a file, cryptographically signs it, and outputs it to a
 /// file or stdout.
 ///
+/// `maybe_output_path` specifies the output file path, or if empty, will print it to `stdout`.
+/// `format` is "json" or "binary", or if empty, defaults to "json".  If `force` is true, and a file
+/// exists at `maybe_output_path`, it will be overwritten.  If `force` is false and a file exists at
+/// `maybe_output_path`, [`Error::FileAlreadyExists`] is returned and the file will not be written.
+#[cfg(feature = "std-fs-io")]
+pub fn sign_deploy_file(
+    input_path: &str,
+    secret_key_path: &str,
+    maybe_output_path: &str,
+    format: &str,
+    force: bool,
+) -> Result<(), CliError> {
+    let secret_key = parse::secret_key_from_file(secret_key_path)?;
+    let format = parse::deploy_format(format)?;
+    let output = parse::output_kind(maybe_output_path, force);
+    crate::sign_deploy_file(input_path, &secret_key, format, output).map_err(CliError::from)
+}
+
+/// Reads a previously-saved [`Deploy`] from a file, signs it using an external process, and
+/// outputs it to a file or stdout.
+///
+/// `signer_command` is split into the program and its args as a POSIX shell would, so quotes can be
+/// used for words containing whitespace.  For details of how the process is used, see
+/// [`ExternalSigner`](crate::ExternalSigner).
+///
+/// `maybe_output_path` specifies the output file path, or if empty, will print it to `stdout`.
+/// `format` is "json" or "binary", or if empty, defaults to "json".  If `force` is true, and a file
+/// exists at `maybe_output_path`, it will be overwritten.  If `force` is false and a file exists at
+/// `maybe_output_path`, [`Error::FileAlreadyExists`] is returned and the file will not be written.
+#[cfg(feature = "std-fs-io")]
+pub fn sign_deploy_file_with_signer_command(
+    input_path: &str,
+    signer_command: &str,
+    maybe_output_path: &str,
+    format: &str,
+    force: bool,
+) -> Result<(), CliError> {
+    let signer =
+        parse::external_signer(signer_command)?.ok_or_else(|| CliError::InvalidArgument {
+            context: "sign_deploy_file_with_signer_command",
+            error: "signer command must not be empty".to_string(),
+        })?;
+    let format = parse::deploy_format(format)?;
+    let output = parse::output_kind(maybe_output_path, force);
+    crate::sign_deploy_file(input_path, &signer, format, output).map_err(CliError::from)
+}
+
+/// Reads a previously-saved [`Deploy`] from a file, re-stamps it with a new timestamp, re-signs it,
+/// and outputs it to a file or stdout.
+///
+/// `timestamp` is the new timestamp, or if empty, the current time.  `ttl`, `chain_name` and
+/// `gas_price` are the new values of those fields, or if empty, the deploy's existing values are
+/// kept.  The deploy's approvals are dropped, and it is signed using the secret key in each file of
+/// `secret_key_paths` and, if `signer_command` is not empty, an external process, as per
+/// [`sign_deploy_file_with_signer_command`].
+///
+/// `maybe_output_path` specifies the output file path, or if empty, will print it to `stdout`.
+/// `format` is "json" or "binary", or if empty, defaults to "json".  If `force` is true, and a file
+/// exists at `maybe_output_path`, it will be overwritten.  If `force` is false and a file exists at
+/// `maybe_output_path`, [`Error::FileAlreadyExists`] is returned and the file will not be written.
+#[cfg(feature = "std-fs-io")]
+#[allow(clippy::too_many_arguments)]
+pub fn restamp_deploy_file(
+    input_path: &str,
+    timestamp: &str,
+    ttl: &str,
+    chain_name: &str,
+    gas_price: &str,
+    secret_key_paths: &[&str],
+    signer_command: &str,
+    maybe_output_path: &str,
+    format: &str,
+    force: bool,
+) -> Result<(), CliError> {
+    let timestamp = parse::timestamp(timestamp)?;
+    let maybe_ttl = if ttl.is_empty() {
+        None
+    } else {
+        Some(parse::ttl(ttl)?)
+    };
+    let maybe_chain_name = (!chain_name.is_empty()).then(|| chain_name.to_string());
+    let maybe_gas_price = if gas_price.is_empty() {
+        None
+    } else {
+        Some(parse::gas_price(gas_price)?)
+    };
+
+    let mut signers: Vec<Box<dyn Signer>> = vec![];
+    for secret_key_path in secret_key_paths {
+        signers.push(Box::new(parse::secret_key_from_file(secret_key_path)?));
+    }
+    if let Some(external_signer) = parse::external_signer(signer_command)? {
+        signers.push(Box::new(external_signer));
+    }
+    let signers: Vec<&dyn Signer> = signers.iter().map(Box::as_ref).collect();
+
+    let format = parse::deploy_format(format)?;
+    let output = parse::output_kind(maybe_output_path, force);
+    crate::restamp_deploy_file(
+        input_path,
+        timestamp,
+        maybe_ttl,
+        maybe_chain_name,
+        maybe_gas_price,
+        &signers,
+        format,
+        output,
+    )
+    .map_err(CliError::from)
+}
+
+/// Reads a previously-saved [`Deploy`] from a file and returns a human-readable view of it, with
+/// its runtime args decoded from their `CLValue`s.
+///
+/// For details of the view, see [`DeployInspection`].
+#[cfg(feature = "std-fs-io")]
+pub fn inspect_deploy_file(input_path: &str) -> Result<DeployInspection, CliError> {
+    let deploy = crate::read_deploy_file(input_path)?;
+    Ok(DeployInspection::new(&deploy))
+}
+
+/// Reads a previously-saved [`Deploy`] from a file and verifies that it is internally consistent
+/// and valid for the given chain.
+///
+/// `max_ttl` is the maximum permitted time-to-live of the deploy, e.g. '1day'.  For details of the
+/// checks performed, see [`Deploy::verify`].
+#[cfg(feature = "std-fs-io")]
+pub fn verify_deploy_file(
+    input_path: &str,
+    chain_name: &str,
+    max_ttl: &str,
+) -> Result<(), CliError> {
+    let max_ttl = parse::ttl(max_ttl)?;
+    let deploy = crate::read_deploy_file(input_path)?;
+    deploy.verify(chain_name, max_ttl).map_err(CliError::from)
+}
+
+/// Reads several previously-saved copies of the same [`Deploy`] from files, merges their approvals
+/// into a single deploy, and outputs it to a file or stdout.
+///
+/// Returns [`Error::MismatchedDeploys`] if the deploys do not all have the same hash.
+///
+/// `maybe_output_path` specifies the output file path, or if empty, will print it to `stdout`.
+/// `format` is "json" or "binary", or if empty, defaults to "json".  If `force` is true, and a file
+/// exists at `maybe_output_path`, it will be overwritten.  If `force` is false and a file exists at
+/// `maybe_output_path`, [`Error::FileAlreadyExists`] is returned and the file will not be written.
+#[cfg(feature = "std-fs-io")]
+pub fn merge_deploy_files(
+    input_paths: &[&str],
+    maybe_output_path: &str,
+    format: &str,
+    force: bool,
+) -> Result<(), CliError> {
+    let format = parse::deploy_format(format)?;
+    let output = parse::output_kind(maybe_output_path, force);
+    crate::merge_deploy_files(input_paths, format, output).map_err(CliError::from)
+}
+
+/// Reads a Wasm module from a file and returns a summary of its imports, exports, custom sections
+/// and memory.
+///
+/// For details of the summary, see [`WasmInspection`].  To check whether the module would be
+/// accepted by a network, see [`crate::validate_wasm`].
+#[cfg(feature = "std-fs-io")]
+pub fn inspect_wasm_file(input_path: &str) -> Result<WasmInspection, CliError> {
+    crate::inspect_wasm_file(input_path).map_err(CliError::from)
+}
+
+/// Reads a Wasm module from a file, removes its debug info, and outputs it to a file or stdout.
+///
+/// The custom sections removed are those named "name", "sourceMappingURL", "external_debug_info"
+/// or starting with ".debug".
+///
+/// `maybe_output_path` specifies the output file path, or if empty, will write the module to
+/// `stdout`.  If `force` is true, and a file exists at `maybe_output_path`, it will be overwritten.
+/// If `force` is false and a file exists at `maybe_output_path`, [`Error::FileAlreadyExists`] is
+/// returned and the file will not be written.
+#[cfg(feature = "std-fs-io")]
+pub fn strip_wasm_debug_sections_file(
+    input_path: &str,
+    maybe_output_path: &str,
+    force: bool,
+) -> Result<(), CliError> {
+    let output = parse::output_kind(maybe_output_path, force);
+    crate::strip_wasm_debug_sections_file(input_path, output).map_err(CliError::from)
+}
+
+/// Reads a previously-saved [`Deploy`] from a file and checks whether its approvals are sufficient
+/// for it to be executed, by comparing them against the associated keys and deployment threshold
+/// of the deploy's account.
+///
+/// For details of the parameters, see [the module docs](crate::cli#common-parameters).
+#[cfg(feature = "std-fs-io")]
+pub async fn check_approvals(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
+    input_path: &str,
+) -> Result<ApprovalsCheck, CliError> {
+    check_approvals_with_transport(
+        &HttpTransport::default(),
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+        input_path,
+    )
+    .await
+}
+
+/// Same as [`check_approvals`], but sends the request via the given `transport`.
+#[cfg(feature = "std-fs-io")]
+pub async fn check_approvals_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
+    input_path: &str,
+) -> Result<ApprovalsCheck, CliError> {
+    let rpc_id = parse::rpc_id(maybe_rpc_id);
+    let verbosity = parse::verbosity(verbosity_level);
+    let maybe_block_id = parse::block_identifier(maybe_block_id)?;
+    let deploy = crate::read_deploy_file(input_path)?;
+    crate::check_approvals_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_id,
+        &deploy,
+    )
+    .await
+    .map_err(CliError::from)
+}
+
+/// Reads a plaintext PEM-encoded secret key from a file, encrypts it into a
+/// [`Keystore`](crate::keystore::Keystore), and outputs the keystore to a file or stdout.
+///
+/// The password is read as per [`keystore::read_new_password`](crate::keystore::read_new_password).
+///
 /// `maybe_output_path` specifies the output file path, or if empty, will print it to `stdout`.  If
 /// `force` is true, and a file exists at `maybe_output_path`, it will be overwritten.  If `force`
 /// is false and a file exists at `maybe_output_path`, [`Error::FileAlreadyExists`] is returned
 /// and the file will not be written.
 #[cfg(feature = "std-fs-io")]
-pub fn sign_deploy_file(
+pub fn encrypt_secret_key_file(
     input_path: &str,
-    secret_key_path: &str,
     maybe_output_path: &str,
     force: bool,
 ) -> Result<(), CliError> {
-    let secret_key = parse::secret_key_from_file(secret_key_path)?;
+    let password = crate::keystore::read_new_password()?;
+    let output = parse::output_kind(maybe_output_path, force);
+    crate::keystore::encrypt_secret_key_file(input_path, &password, output).map_err(CliError::from)
+}
+
+/// Reads a [`Keystore`](crate::keystore::Keystore) from a file, decrypts it, and outputs the
+/// PEM-encoded secret key to a file or stdout.
+///
+/// The password is read as per [`keystore::read_password`](crate::keystore::read_password).
+///
+/// `maybe_output_path` specifies the output file path, or if empty, will print it to `stdout`.  If
+/// `force` is true, and a file exists at `maybe_output_path`, it will be overwritten.  If `force`
+/// is false and a file exists at `maybe_output_path`, [`Error::FileAlreadyExists`] is returned
+/// and the file will not be written.
+#[cfg(feature = "std-fs-io")]
+pub fn decrypt_keystore_file(
+    input_path: &str,
+    maybe_output_path: &str,
+    force: bool,
+) -> Result<(), CliError> {
+    let password = crate::keystore::read_password(&format!(
+        "Enter password for keystore at {}: ",
+        input_path
+    ))?;
     let output = parse::output_kind(maybe_output_path, force);
-    crate::sign_deploy_file(input_path, &secret_key, output).map_err(CliError::from)
+    crate::keystore::decrypt_keystore_file(input_path, &password, output).map_err(CliError::from)
 }
 
 /// Reads a previously-saved [`Deploy`] from a file and sends it to the network for execution.
@@ -171,11 +536,30 @@ pub async fn send_deploy_file(
     node_address: &str,
     verbosity_level: u64,
     input_path: &str,
+) -> Result<SuccessResponse<PutDeployResult>, CliError> {
+    send_deploy_file_with_transport(
+        &HttpTransport::default(),
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        input_path,
+    )
+    .await
+}
+
+/// Same as [`send_deploy_file`], but sends the request via the given `transport`.
+#[cfg(feature = "std-fs-io")]
+pub async fn send_deploy_file_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    input_path: &str,
 ) -> Result<SuccessResponse<PutDeployResult>, CliError> {
     let rpc_id = parse::rpc_id(maybe_rpc_id);
     let verbosity = parse::verbosity(verbosity_level);
     let deploy = crate::read_deploy_file(input_path)?;
-    crate::put_deploy(rpc_id, node_address, verbosity, deploy)
+    crate::put_deploy_with_transport(transport, rpc_id, node_address, verbosity, deploy)
         .await
         .map_err(CliError::from)
 }
@@ -190,14 +574,42 @@ pub async fn speculative_send_deploy_file(
     node_address: &str,
     verbosity_level: u64,
     input_path: &str,
+) -> Result<SuccessResponse<SpeculativeExecResult>, CliError> {
+    speculative_send_deploy_file_with_transport(
+        &HttpTransport::default(),
+        maybe_block_id,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        input_path,
+    )
+    .await
+}
+
+/// Same as [`speculative_send_deploy_file`], but sends the request via the given `transport`.
+#[cfg(feature = "std-fs-io")]
+pub async fn speculative_send_deploy_file_with_transport(
+    transport: &dyn Transport,
+    maybe_block_id: &str,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    input_path: &str,
 ) -> Result<SuccessResponse<SpeculativeExecResult>, CliError> {
     let rpc_id = parse::rpc_id(maybe_rpc_id);
     let speculative_exec = parse::block_identifier(maybe_block_id)?;
     let verbosity = parse::verbosity(verbosity_level);
     let deploy = crate::read_deploy_file(input_path)?;
-    crate::speculative_exec(rpc_id, node_address, speculative_exec, verbosity, deploy)
-        .await
-        .map_err(CliError::from)
+    crate::speculative_exec_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        speculative_exec,
+        verbosity,
+        deploy,
+    )
+    .await
+    .map_err(CliError::from)
 }
 
 /// Transfers funds between purses.
@@ -209,6 +621,10 @@ pub async fn speculative_send_deploy_file(
 /// * `transfer_id` is a string to be parsed as a `u64` representing a user-defined identifier which
 ///   will be permanently associated with the transfer.
 ///
+/// As for [`put_deploy`], the payment amount of `payment_params` can be automatic, and the chain
+/// name and network limits are discovered from the node if the chain name of `deploy_params` is
+/// empty or its `discover_limits` is true.
+///
 /// For details of other parameters, see [the module docs](crate::cli#common-parameters).
 #[allow(clippy::too_many_arguments)]
 pub async fn transfer(
@@ -220,19 +636,51 @@ pub async fn transfer(
     transfer_id: &str,
     deploy_params: DeployStrParams<'_>,
     payment_params: PaymentStrParams<'_>,
+) -> Result<SuccessResponse<PutDeployResult>, CliError> {
+    transfer_with_transport(
+        &HttpTransport::default(),
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        amount,
+        target_account,
+        transfer_id,
+        deploy_params,
+        payment_params,
+    )
+    .await
+}
+
+/// Same as [`transfer`], but sends the request via the given `transport`.
+#[allow(clippy::too_many_arguments)]
+pub async fn transfer_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    amount: &str,
+    target_account: &str,
+    transfer_id: &str,
+    deploy_params: DeployStrParams<'_>,
+    payment_params: PaymentStrParams<'_>,
 ) -> Result<SuccessResponse<PutDeployResult>, CliError> {
     let rpc_id = parse::rpc_id(maybe_rpc_id);
     let verbosity = parse::verbosity(verbosity_level);
-    let deploy = deploy::new_transfer(
+    let maybe_auto_payment = parse::auto_payment(payment_params.payment_amount)?;
+    let deploy = deploy::new_transfer_for_node(
+        transport,
+        rpc_id.clone(),
+        node_address,
+        verbosity,
+        maybe_auto_payment,
         amount,
-        None,
         target_account,
         transfer_id,
         deploy_params,
         payment_params,
-        false,
-    )?;
-    crate::put_deploy(rpc_id, node_address, verbosity, deploy)
+    )
+    .await?;
+    crate::put_deploy_with_transport(transport, rpc_id, node_address, verbosity, deploy)
         .await
         .map_err(CliError::from)
 }
@@ -247,6 +695,9 @@ pub async fn transfer(
 /// * `transfer_id` is a string to be parsed as a `u64` representing a user-defined identifier which
 ///   will be permanently associated with the transfer.
 ///
+/// As for [`put_deploy`], the chain name and network limits are discovered from the node if the
+/// chain name of `deploy_params` is empty or its `discover_limits` is true.
+///
 /// For details of other parameters, see [the module docs](crate::cli#common-parameters).
 #[allow(clippy::too_many_arguments)]
 pub async fn speculative_transfer(
@@ -259,22 +710,62 @@ pub async fn speculative_transfer(
     transfer_id: &str,
     deploy_params: DeployStrParams<'_>,
     payment_params: PaymentStrParams<'_>,
+) -> Result<SuccessResponse<SpeculativeExecResult>, CliError> {
+    speculative_transfer_with_transport(
+        &HttpTransport::default(),
+        maybe_block_id,
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        amount,
+        target_account,
+        transfer_id,
+        deploy_params,
+        payment_params,
+    )
+    .await
+}
+
+/// Same as [`speculative_transfer`], but sends the request via the given `transport`.
+#[allow(clippy::too_many_arguments)]
+pub async fn speculative_transfer_with_transport(
+    transport: &dyn Transport,
+    maybe_block_id: &str,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    amount: &str,
+    target_account: &str,
+    transfer_id: &str,
+    deploy_params: DeployStrParams<'_>,
+    payment_params: PaymentStrParams<'_>,
 ) -> Result<SuccessResponse<SpeculativeExecResult>, CliError> {
     let rpc_id = parse::rpc_id(maybe_rpc_id);
     let verbosity = parse::verbosity(verbosity_level);
-    let deploy = deploy::new_transfer(
-        amount,
+    let deploy = deploy::new_transfer_for_node(
+        transport,
+        rpc_id.clone(),
+        node_address,
+        verbosity,
         None,
+        amount,
         target_account,
         transfer_id,
         deploy_params,
         payment_params,
-        false,
-    )?;
+    )
+    .await?;
     let speculative_exec = parse::block_identifier(maybe_block_id)?;
-    crate::speculative_exec(rpc_id, node_address, speculative_exec, verbosity, deploy)
-        .await
-        .map_err(CliError::from)
+    crate::speculative_exec_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        speculative_exec,
+        verbosity,
+        deploy,
+    )
+    .await
+    .map_err(CliError::from)
 }
 
 /// Returns a transfer [`Deploy`] and outputs it to a file or stdout if the `std-fs-io` feature is
@@ -284,13 +775,15 @@ pub async fn speculative_transfer(
 /// and then sent to the network for execution using [`send_deploy_file`].  Alternatively, the
 /// returned `Deploy` can be signed via the [`Deploy::sign`] method.
 ///
-/// If the `std-fs-io` feature is NOT enabled, `maybe_output_path` and `force` are ignored.
-/// Otherwise, `maybe_output_path` specifies the output file path, or if empty, will print it to
-/// `stdout`.  If `force` is true, and a file exists at `maybe_output_path`, it will be
-/// overwritten.  If `force` is false and a file exists at `maybe_output_path`,
-/// [`Error::FileAlreadyExists`] is returned and the file will not be written.
+/// If the `std-fs-io` feature is NOT enabled, `maybe_output_path`, `format` and `force` are
+/// ignored.  Otherwise, `maybe_output_path` specifies the output file path, or if empty, will print
+/// it to `stdout`.  `format` is "json" or "binary", or if empty, defaults to "json".  If `force` is
+/// true, and a file exists at `maybe_output_path`, it will be overwritten.  If `force` is false and
+/// a file exists at `maybe_output_path`, [`Error::FileAlreadyExists`] is returned and the file will
+/// not be written.
 pub fn make_transfer(
     #[allow(unused_variables)] maybe_output_path: &str,
+    #[allow(unused_variables)] format: &str,
     amount: &str,
     target_account: &str,
     transfer_id: &str,
@@ -309,8 +802,9 @@ pub fn make_transfer(
     )?;
     #[cfg(feature = "std-fs-io")]
     {
+        let format = parse::deploy_format(format)?;
         let output = parse::output_kind(maybe_output_path, force);
-        crate::output_deploy(output, &deploy).map_err(CliError::from)?;
+        crate::output_deploy(output, format, &deploy).map_err(CliError::from)?;
     }
     Ok(deploy)
 }
@@ -325,25 +819,126 @@ pub async fn get_deploy(
     verbosity_level: u64,
     deploy_hash: &str,
     finalized_approvals: bool,
+) -> Result<SuccessResponse<GetDeployResult>, CliError> {
+    get_deploy_with_transport(
+        &HttpTransport::default(),
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        deploy_hash,
+        finalized_approvals,
+    )
+    .await
+}
+
+/// Same as [`get_deploy`], but sends the request via the given `transport`.
+pub async fn get_deploy_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    deploy_hash: &str,
+    finalized_approvals: bool,
 ) -> Result<SuccessResponse<GetDeployResult>, CliError> {
     let rpc_id = parse::rpc_id(maybe_rpc_id);
     let verbosity = parse::verbosity(verbosity_level);
     let deploy_hash = parse::deploy_hash(deploy_hash)?;
-    crate::get_deploy(
+    crate::get_deploy_with_transport(
+        transport,
         rpc_id,
         node_address,
-        verbosity,
-        deploy_hash,
-        finalized_approvals,
+        verbosity,
+        deploy_hash,
+        finalized_approvals,
+    )
+    .await
+    .map_err(CliError::from)
+}
+
+/// Waits for the [`Deploy`] with the given hash to be executed, by polling the node with
+/// `info_get_deploy` requests.
+///
+/// `deploy_hash` must be a hex-encoded, 32-byte hash digest.  `maybe_timeout` is the maximum time
+/// to wait, e.g. '5min'; if empty, waiting only stops once the deploy has expired.  If the deploy
+/// was executed but execution failed, [`CliError::DeployExecutionFailed`] is returned.
+///
+/// For further details, see [`crate::wait_for_deploy`], and for details of the other parameters,
+/// see [the module docs](crate::cli#common-parameters).
+#[cfg(feature = "tokio")]
+pub async fn wait_for_deploy(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    deploy_hash: &str,
+    maybe_timeout: &str,
+) -> Result<DeployOutcome, CliError> {
+    wait_for_deploy_with_transport(
+        &HttpTransport::default(),
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        deploy_hash,
+        maybe_timeout,
+    )
+    .await
+}
+
+/// Same as [`wait_for_deploy`], but sends the requests via the given `transport`.
+#[cfg(feature = "tokio")]
+pub async fn wait_for_deploy_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    deploy_hash: &str,
+    maybe_timeout: &str,
+) -> Result<DeployOutcome, CliError> {
+    let rpc_id = parse::rpc_id(maybe_rpc_id);
+    let verbosity = parse::verbosity(verbosity_level);
+    let deploy_hash = parse::deploy_hash(deploy_hash)?;
+    let maybe_timeout = parse::wait_timeout(maybe_timeout)?;
+    let outcome = crate::wait_for_deploy_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+        deploy_hash,
+        maybe_timeout,
+    )
+    .await?;
+    match outcome.error_message {
+        None => Ok(outcome),
+        Some(error_message) => Err(CliError::DeployExecutionFailed {
+            deploy_hash: outcome.deploy_hash,
+            block_hash: outcome.block_hash,
+            cost: outcome.cost,
+            error_message,
+        }),
+    }
+}
+
+/// Retrieves a [`Block`] from the network.
+///
+/// For details of the parameters, see [the module docs](crate::cli#common-parameters).
+pub async fn get_block(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
+) -> Result<SuccessResponse<GetBlockResult>, CliError> {
+    get_block_with_transport(
+        &HttpTransport::default(),
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
     )
     .await
-    .map_err(CliError::from)
 }
 
-/// Retrieves a [`Block`] from the network.
-///
-/// For details of the parameters, see [the module docs](crate::cli#common-parameters).
-pub async fn get_block(
+/// Same as [`get_block`], but sends the request via the given `transport`.
+pub async fn get_block_with_transport(
+    transport: &dyn Transport,
     maybe_rpc_id: &str,
     node_address: &str,
     verbosity_level: u64,
@@ -352,7 +947,7 @@ pub async fn get_block(
     let rpc_id = parse::rpc_id(maybe_rpc_id);
     let verbosity = parse::verbosity(verbosity_level);
     let maybe_block_id = parse::block_identifier(maybe_block_id)?;
-    crate::get_block(rpc_id, node_address, verbosity, maybe_block_id)
+    crate::get_block_with_transport(transport, rpc_id, node_address, verbosity, maybe_block_id)
         .await
         .map_err(CliError::from)
 }
@@ -365,13 +960,37 @@ pub async fn get_block_transfers(
     node_address: &str,
     verbosity_level: u64,
     maybe_block_id: &str,
+) -> Result<SuccessResponse<GetBlockTransfersResult>, CliError> {
+    get_block_transfers_with_transport(
+        &HttpTransport::default(),
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+    )
+    .await
+}
+
+/// Same as [`get_block_transfers`], but sends the request via the given `transport`.
+pub async fn get_block_transfers_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
 ) -> Result<SuccessResponse<GetBlockTransfersResult>, CliError> {
     let rpc_id = parse::rpc_id(maybe_rpc_id);
     let verbosity = parse::verbosity(verbosity_level);
     let maybe_block_id = parse::block_identifier(maybe_block_id)?;
-    crate::get_block_transfers(rpc_id, node_address, verbosity, maybe_block_id)
-        .await
-        .map_err(CliError::from)
+    crate::get_block_transfers_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_id,
+    )
+    .await
+    .map_err(CliError::from)
 }
 
 /// Retrieves a state root hash at a given [`Block`].
@@ -382,13 +1001,37 @@ pub async fn get_state_root_hash(
     node_address: &str,
     verbosity_level: u64,
     maybe_block_id: &str,
+) -> Result<SuccessResponse<GetStateRootHashResult>, CliError> {
+    get_state_root_hash_with_transport(
+        &HttpTransport::default(),
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+    )
+    .await
+}
+
+/// Same as [`get_state_root_hash`], but sends the request via the given `transport`.
+pub async fn get_state_root_hash_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
 ) -> Result<SuccessResponse<GetStateRootHashResult>, CliError> {
     let rpc_id = parse::rpc_id(maybe_rpc_id);
     let verbosity = parse::verbosity(verbosity_level);
     let maybe_block_id = parse::block_identifier(maybe_block_id)?;
-    crate::get_state_root_hash(rpc_id, node_address, verbosity, maybe_block_id)
-        .await
-        .map_err(CliError::from)
+    crate::get_state_root_hash_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_id,
+    )
+    .await
+    .map_err(CliError::from)
 }
 
 /// Retrieves era information from the network at a given [`Block`].
@@ -399,13 +1042,37 @@ pub async fn get_era_summary(
     node_address: &str,
     verbosity_level: u64,
     maybe_block_id: &str,
+) -> Result<SuccessResponse<GetEraSummaryResult>, CliError> {
+    get_era_summary_with_transport(
+        &HttpTransport::default(),
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+    )
+    .await
+}
+
+/// Same as [`get_era_summary`], but sends the request via the given `transport`.
+pub async fn get_era_summary_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
 ) -> Result<SuccessResponse<GetEraSummaryResult>, CliError> {
     let rpc_id = parse::rpc_id(maybe_rpc_id);
     let verbosity = parse::verbosity(verbosity_level);
     let maybe_block_id = parse::block_identifier(maybe_block_id)?;
-    crate::get_era_summary(rpc_id, node_address, verbosity, maybe_block_id)
-        .await
-        .map_err(CliError::from)
+    crate::get_era_summary_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_id,
+    )
+    .await
+    .map_err(CliError::from)
 }
 
 /// Retrieves a [`StoredValue`] from global state.
@@ -425,6 +1092,31 @@ pub async fn query_global_state(
     maybe_state_root_hash: &str,
     key: &str,
     path: &str,
+) -> Result<SuccessResponse<QueryGlobalStateResult>, CliError> {
+    query_global_state_with_transport(
+        &HttpTransport::default(),
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+        maybe_state_root_hash,
+        key,
+        path,
+    )
+    .await
+}
+
+/// Same as [`query_global_state`], but sends the request via the given `transport`.
+#[allow(clippy::too_many_arguments)]
+pub async fn query_global_state_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
+    maybe_state_root_hash: &str,
+    key: &str,
+    path: &str,
 ) -> Result<SuccessResponse<QueryGlobalStateResult>, CliError> {
     let rpc_id = parse::rpc_id(maybe_rpc_id);
     let verbosity = parse::verbosity(verbosity_level);
@@ -437,7 +1129,8 @@ pub async fn query_global_state(
         path.split('/').map(ToString::to_string).collect()
     };
 
-    crate::query_global_state(
+    crate::query_global_state_with_transport(
+        transport,
         rpc_id,
         node_address,
         verbosity,
@@ -464,6 +1157,28 @@ pub async fn query_balance(
     maybe_block_id: &str,
     maybe_state_root_hash: &str,
     purse_id: &str,
+) -> Result<SuccessResponse<QueryBalanceResult>, CliError> {
+    query_balance_with_transport(
+        &HttpTransport::default(),
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+        maybe_state_root_hash,
+        purse_id,
+    )
+    .await
+}
+
+/// Same as [`query_balance`], but sends the request via the given `transport`.
+pub async fn query_balance_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
+    maybe_state_root_hash: &str,
+    purse_id: &str,
 ) -> Result<SuccessResponse<QueryBalanceResult>, CliError> {
     let rpc_id = parse::rpc_id(maybe_rpc_id);
     let verbosity = parse::verbosity(verbosity_level);
@@ -471,7 +1186,8 @@ pub async fn query_balance(
         parse::global_state_identifier(maybe_block_id, maybe_state_root_hash)?;
     let purse_identifier = parse::purse_identifier(purse_id)?;
 
-    crate::query_balance(
+    crate::query_balance_with_transport(
+        transport,
         rpc_id,
         node_address,
         verbosity,
@@ -496,6 +1212,26 @@ pub async fn get_dictionary_item(
     verbosity_level: u64,
     state_root_hash: &str,
     dictionary_item_str_params: DictionaryItemStrParams<'_>,
+) -> Result<SuccessResponse<GetDictionaryItemResult>, CliError> {
+    get_dictionary_item_with_transport(
+        &HttpTransport::default(),
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        state_root_hash,
+        dictionary_item_str_params,
+    )
+    .await
+}
+
+/// Same as [`get_dictionary_item`], but sends the request via the given `transport`.
+pub async fn get_dictionary_item_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    state_root_hash: &str,
+    dictionary_item_str_params: DictionaryItemStrParams<'_>,
 ) -> Result<SuccessResponse<GetDictionaryItemResult>, CliError> {
     let rpc_id = parse::rpc_id(maybe_rpc_id);
     let verbosity = parse::verbosity(verbosity_level);
@@ -507,7 +1243,8 @@ pub async fn get_dictionary_item(
     let dictionary_item_identifier =
         DictionaryItemIdentifier::try_from(dictionary_item_str_params)?;
 
-    crate::get_dictionary_item(
+    crate::get_dictionary_item_with_transport(
+        transport,
         rpc_id,
         node_address,
         verbosity,
@@ -534,6 +1271,26 @@ pub async fn get_balance(
     verbosity_level: u64,
     state_root_hash: &str,
     purse: &str,
+) -> Result<SuccessResponse<GetBalanceResult>, CliError> {
+    get_balance_with_transport(
+        &HttpTransport::default(),
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        state_root_hash,
+        purse,
+    )
+    .await
+}
+
+/// Same as [`get_balance`], but sends the request via the given `transport`.
+pub async fn get_balance_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    state_root_hash: &str,
+    purse: &str,
 ) -> Result<SuccessResponse<GetBalanceResult>, CliError> {
     let rpc_id = parse::rpc_id(maybe_rpc_id);
     let verbosity = parse::verbosity(verbosity_level);
@@ -547,9 +1304,16 @@ pub async fn get_balance(
         error,
     })?;
 
-    crate::get_balance(rpc_id, node_address, verbosity, state_root_hash, purse)
-        .await
-        .map_err(CliError::from)
+    crate::get_balance_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+        state_root_hash,
+        purse,
+    )
+    .await
+    .map_err(CliError::from)
 }
 
 /// Retrieves an [`Account`] at a given [`Block`].
@@ -563,13 +1327,34 @@ pub async fn get_account(
     verbosity_level: u64,
     maybe_block_id: &str,
     account_identifier: &str,
+) -> Result<SuccessResponse<GetAccountResult>, CliError> {
+    get_account_with_transport(
+        &HttpTransport::default(),
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+        account_identifier,
+    )
+    .await
+}
+
+/// Same as [`get_account`], but sends the request via the given `transport`.
+pub async fn get_account_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
+    account_identifier: &str,
 ) -> Result<SuccessResponse<GetAccountResult>, CliError> {
     let rpc_id = parse::rpc_id(maybe_rpc_id);
     let verbosity = parse::verbosity(verbosity_level);
     let maybe_block_id = parse::block_identifier(maybe_block_id)?;
     let account_identifier = parse::account_identifier(account_identifier)?;
 
-    crate::get_account(
+    crate::get_account_with_transport(
+        transport,
         rpc_id,
         node_address,
         verbosity,
@@ -588,13 +1373,37 @@ pub async fn get_auction_info(
     node_address: &str,
     verbosity_level: u64,
     maybe_block_id: &str,
+) -> Result<SuccessResponse<GetAuctionInfoResult>, CliError> {
+    get_auction_info_with_transport(
+        &HttpTransport::default(),
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+    )
+    .await
+}
+
+/// Same as [`get_auction_info`], but sends the request via the given `transport`.
+pub async fn get_auction_info_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
 ) -> Result<SuccessResponse<GetAuctionInfoResult>, CliError> {
     let rpc_id = parse::rpc_id(maybe_rpc_id);
     let verbosity = parse::verbosity(verbosity_level);
     let maybe_block_id = parse::block_identifier(maybe_block_id)?;
-    crate::get_auction_info(rpc_id, node_address, verbosity, maybe_block_id)
-        .await
-        .map_err(CliError::from)
+    crate::get_auction_info_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_id,
+    )
+    .await
+    .map_err(CliError::from)
 }
 
 /// Retrieves the status changes of the active validators on the network.
@@ -604,10 +1413,26 @@ pub async fn get_validator_changes(
     maybe_rpc_id: &str,
     node_address: &str,
     verbosity_level: u64,
+) -> Result<SuccessResponse<GetValidatorChangesResult>, CliError> {
+    get_validator_changes_with_transport(
+        &HttpTransport::default(),
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+    )
+    .await
+}
+
+/// Same as [`get_validator_changes`], but sends the request via the given `transport`.
+pub async fn get_validator_changes_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
 ) -> Result<SuccessResponse<GetValidatorChangesResult>, CliError> {
     let rpc_id = parse::rpc_id(maybe_rpc_id);
     let verbosity = parse::verbosity(verbosity_level);
-    crate::get_validator_changes(rpc_id, node_address, verbosity)
+    crate::get_validator_changes_with_transport(transport, rpc_id, node_address, verbosity)
         .await
         .map_err(CliError::from)
 }
@@ -619,10 +1444,26 @@ pub async fn get_peers(
     maybe_rpc_id: &str,
     node_address: &str,
     verbosity_level: u64,
+) -> Result<SuccessResponse<GetPeersResult>, CliError> {
+    get_peers_with_transport(
+        &HttpTransport::default(),
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+    )
+    .await
+}
+
+/// Same as [`get_peers`], but sends the request via the given `transport`.
+pub async fn get_peers_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
 ) -> Result<SuccessResponse<GetPeersResult>, CliError> {
     let rpc_id = parse::rpc_id(maybe_rpc_id);
     let verbosity = parse::verbosity(verbosity_level);
-    crate::get_peers(rpc_id, node_address, verbosity)
+    crate::get_peers_with_transport(transport, rpc_id, node_address, verbosity)
         .await
         .map_err(CliError::from)
 }
@@ -634,10 +1475,26 @@ pub async fn get_node_status(
     maybe_rpc_id: &str,
     node_address: &str,
     verbosity_level: u64,
+) -> Result<SuccessResponse<GetNodeStatusResult>, CliError> {
+    get_node_status_with_transport(
+        &HttpTransport::default(),
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+    )
+    .await
+}
+
+/// Same as [`get_node_status`], but sends the request via the given `transport`.
+pub async fn get_node_status_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
 ) -> Result<SuccessResponse<GetNodeStatusResult>, CliError> {
     let rpc_id = parse::rpc_id(maybe_rpc_id);
     let verbosity = parse::verbosity(verbosity_level);
-    crate::get_node_status(rpc_id, node_address, verbosity)
+    crate::get_node_status_with_transport(transport, rpc_id, node_address, verbosity)
         .await
         .map_err(CliError::from)
 }
@@ -649,14 +1506,89 @@ pub async fn get_chainspec(
     maybe_rpc_id: &str,
     node_address: &str,
     verbosity_level: u64,
+) -> Result<SuccessResponse<GetChainspecResult>, CliError> {
+    get_chainspec_with_transport(
+        &HttpTransport::default(),
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+    )
+    .await
+}
+
+/// Same as [`get_chainspec`], but sends the request via the given `transport`.
+pub async fn get_chainspec_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
 ) -> Result<SuccessResponse<GetChainspecResult>, CliError> {
     let rpc_id = parse::rpc_id(maybe_rpc_id);
     let verbosity = parse::verbosity(verbosity_level);
-    crate::get_chainspec(rpc_id, node_address, verbosity)
+    crate::get_chainspec_with_transport(transport, rpc_id, node_address, verbosity)
         .await
         .map_err(CliError::from)
 }
 
+/// Writes the files of a network's chainspec, as retrieved by [`get_chainspec`], to the directory
+/// at `dir_path`, creating it if necessary.
+///
+/// The chainspec is written to "chainspec.toml", and the genesis accounts and global state, if
+/// provided, to "accounts.toml" and "global_state.toml" respectively.  If `force` is true, any
+/// existing files will be overwritten.  If `force` is false and any of these files already exists,
+/// [`Error::FileAlreadyExists`] is returned and no file will be written.
+#[cfg(feature = "std-fs-io")]
+pub fn save_chainspec_files(
+    chainspec_bytes: &ChainspecRawBytes,
+    dir_path: &str,
+    force: bool,
+) -> Result<(), CliError> {
+    crate::save_chainspec_files(chainspec_bytes, dir_path, force).map_err(CliError::from)
+}
+
+/// Retrieves the chainspecs of two nodes and compares them, setting by setting.
+///
+/// `other_node_address` is the address of the node whose chainspec is compared against that of
+/// the node at `node_address`.
+///
+/// For details of the other parameters, see [the module docs](crate::cli#common-parameters).
+pub async fn diff_chainspecs(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    other_node_address: &str,
+    verbosity_level: u64,
+) -> Result<ChainspecDiff, CliError> {
+    diff_chainspecs_with_transport(
+        &HttpTransport::default(),
+        maybe_rpc_id,
+        node_address,
+        other_node_address,
+        verbosity_level,
+    )
+    .await
+}
+
+/// Same as [`diff_chainspecs`], but sends the requests via the given `transport`.
+pub async fn diff_chainspecs_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    other_node_address: &str,
+    verbosity_level: u64,
+) -> Result<ChainspecDiff, CliError> {
+    let rpc_id = parse::rpc_id(maybe_rpc_id);
+    let verbosity = parse::verbosity(verbosity_level);
+    crate::diff_chainspecs_with_transport(
+        transport,
+        rpc_id,
+        node_address,
+        other_node_address,
+        verbosity,
+    )
+    .await
+    .map_err(CliError::from)
+}
+
 /// Retrieves the interface description (the schema including examples in OpenRPC format) of the
 /// JSON-RPC server's API.
 ///
@@ -665,10 +1597,26 @@ pub async fn list_rpcs(
     maybe_rpc_id: &str,
     node_address: &str,
     verbosity_level: u64,
+) -> Result<SuccessResponse<ListRpcsResult>, CliError> {
+    list_rpcs_with_transport(
+        &HttpTransport::default(),
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+    )
+    .await
+}
+
+/// Same as [`list_rpcs`], but sends the request via the given `transport`.
+pub async fn list_rpcs_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
 ) -> Result<SuccessResponse<ListRpcsResult>, CliError> {
     let rpc_id = parse::rpc_id(maybe_rpc_id);
     let verbosity = parse::verbosity(verbosity_level);
-    crate::list_rpcs(rpc_id, node_address, verbosity)
+    crate::list_rpcs_with_transport(transport, rpc_id, node_address, verbosity)
         .await
         .map_err(CliError::from)
 }
@@ -699,12 +1647,35 @@ pub async fn get_era_info(
     node_address: &str,
     verbosity_level: u64,
     maybe_block_id: &str,
+) -> Result<SuccessResponse<GetEraInfoResult>, CliError> {
+    #[allow(deprecated)]
+    get_era_info_with_transport(
+        &HttpTransport::default(),
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+    )
+    .await
+}
+
+/// Same as [`get_era_info`], but sends the request via the given `transport`.
+#[deprecated(
+    since = "2.0.0",
+    note = "prefer 'get_era_summary' as it doesn't require a switch block"
+)]
+pub async fn get_era_info_with_transport(
+    transport: &dyn Transport,
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
 ) -> Result<SuccessResponse<GetEraInfoResult>, CliError> {
     let rpc_id = parse::rpc_id(maybe_rpc_id);
     let verbosity = parse::verbosity(verbosity_level);
     let maybe_block_id = parse::block_identifier(maybe_block_id)?;
     #[allow(deprecated)]
-    crate::get_era_info(rpc_id, node_address, verbosity, maybe_block_id)
+    crate::get_era_info_with_transport(transport, rpc_id, node_address, verbosity, maybe_block_id)
         .await
         .map_err(CliError::from)
 }
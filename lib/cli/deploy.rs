@@ -2,12 +2,123 @@ use casper_types::{
     account::AccountHash, AsymmetricType, PublicKey, SecretKey, UIntParseError, URef, U512,
 };
 
-use super::{parse, CliError, DeployStrParams, PaymentStrParams, SessionStrParams};
+use super::{
+    parse::{self, AutoPayment},
+    CliError, DeployStrParams, PaymentStrParams, SessionStrParams,
+};
 use crate::{
-    types::{Deploy, DeployBuilder, MAX_SERIALIZED_SIZE_OF_DEPLOY},
-    TransferTarget,
+    types::{
+        Deploy, DeployBuilder, DeployHash, ExecutableDeployItem, TimeDiff, Timestamp,
+        MAX_SERIALIZED_SIZE_OF_DEPLOY,
+    },
+    JsonRpcId, Signer, TransferTarget, Transport, Verbosity,
 };
 
+/// The parts of a Deploy other than its payment and session code, parsed from `DeployStrParams`.
+struct DeployParts {
+    chain_name: String,
+    timestamp: Timestamp,
+    ttl: TimeDiff,
+    gas_price: u64,
+    dependencies: Vec<DeployHash>,
+    maybe_session_account: Option<PublicKey>,
+    maybe_signer: Option<Box<dyn Signer>>,
+    discover_limits: bool,
+}
+
+impl DeployParts {
+    fn new(
+        deploy_params: DeployStrParams,
+        allow_unsigned_deploy: bool,
+        context: &'static str,
+    ) -> Result<Self, CliError> {
+        Ok(DeployParts {
+            chain_name: deploy_params.chain_name.to_string(),
+            timestamp: parse::timestamp(deploy_params.timestamp)?,
+            ttl: parse::ttl(deploy_params.ttl)?,
+            gas_price: parse::gas_price(deploy_params.gas_price)?,
+            dependencies: parse::dependencies(&deploy_params.dependencies)?,
+            maybe_session_account: parse::session_account(deploy_params.session_account)?,
+            maybe_signer: get_maybe_signer(&deploy_params, allow_unsigned_deploy, context)?,
+            discover_limits: deploy_params.discover_limits,
+        })
+    }
+
+    fn builder(
+        &self,
+        session: ExecutableDeployItem,
+        payment: ExecutableDeployItem,
+    ) -> DeployBuilder<'_> {
+        let mut deploy_builder = DeployBuilder::new(self.chain_name.clone(), session)
+            .with_payment(payment)
+            .with_timestamp(self.timestamp)
+            .with_ttl(self.ttl)
+            .with_gas_price(self.gas_price)
+            .with_dependencies(self.dependencies.clone());
+        if let Some(signer) = &self.maybe_signer {
+            deploy_builder = deploy_builder.with_signer(signer.as_ref());
+        }
+        if let Some(account) = &self.maybe_session_account {
+            deploy_builder = deploy_builder.with_account(account.clone());
+        }
+        deploy_builder
+    }
+
+    /// Builds the deploy for the specified node, as per [`with_payment_and_session_for_node`].
+    #[allow(clippy::too_many_arguments)]
+    async fn build_for_node(
+        mut self,
+        transport: &dyn Transport,
+        rpc_id: JsonRpcId,
+        node_address: &str,
+        verbosity: Verbosity,
+        maybe_margin_percent: Option<u32>,
+        session: ExecutableDeployItem,
+        payment: ExecutableDeployItem,
+    ) -> Result<Deploy, CliError> {
+        let maybe_network_limits = if self.chain_name.is_empty() || self.discover_limits {
+            let network_limits = crate::discover_network_limits_with_transport(
+                transport,
+                rpc_id.clone(),
+                node_address,
+                verbosity,
+            )
+            .await?;
+            // A given chain name is checked against the node's by `NetworkLimits::check`.
+            if self.chain_name.is_empty() {
+                self.chain_name = network_limits.chain_name.clone();
+            }
+            Some(network_limits)
+        } else {
+            None
+        };
+
+        let mut deploy_builder = self.builder(session, payment);
+        if let Some(network_limits) = &maybe_network_limits {
+            deploy_builder = deploy_builder.with_network_limits(network_limits);
+        }
+        let deploy = match maybe_margin_percent {
+            Some(margin_percent) => {
+                deploy_builder
+                    .build_with_auto_payment_with_transport(
+                        transport,
+                        rpc_id,
+                        node_address,
+                        verbosity,
+                        margin_percent,
+                    )
+                    .await?
+            }
+            None => deploy_builder.build()?,
+        };
+        match maybe_network_limits {
+            // The size has already been checked against the network's limit.
+            Some(_) => Ok(deploy),
+            None => check_size(deploy),
+        }
+    }
+}
+
 /// Creates new Deploy with specified payment and session data.
 pub fn with_payment_and_session(
     deploy_params: DeployStrParams,
@@ -15,35 +126,56 @@ pub fn with_payment_and_session(
     session_params: SessionStrParams,
     allow_unsigned_deploy: bool,
 ) -> Result<Deploy, CliError> {
-    let chain_name = deploy_params.chain_name.to_string();
     let session = parse::session_executable_deploy_item(session_params)?;
     let payment = parse::payment_executable_deploy_item(payment_params)?;
-    let timestamp = parse::timestamp(deploy_params.timestamp)?;
-    let ttl = parse::ttl(deploy_params.ttl)?;
-    let maybe_session_account = parse::session_account(deploy_params.session_account)?;
-
-    let mut deploy_builder = DeployBuilder::new(chain_name, session)
-        .with_payment(payment)
-        .with_timestamp(timestamp)
-        .with_ttl(ttl);
-
-    let maybe_secret_key = get_maybe_secret_key(
-        deploy_params.secret_key,
+    let parts = DeployParts::new(
+        deploy_params,
         allow_unsigned_deploy,
         "with_payment_and_session",
     )?;
-    if let Some(secret_key) = &maybe_secret_key {
-        deploy_builder = deploy_builder.with_secret_key(secret_key);
-    }
-    if let Some(account) = maybe_session_account {
-        deploy_builder = deploy_builder.with_account(account);
-    }
-
-    let deploy = deploy_builder.build().map_err(crate::Error::from)?;
-    deploy
-        .is_valid_size(MAX_SERIALIZED_SIZE_OF_DEPLOY)
+    let deploy = parts
+        .builder(session, payment)
+        .build()
         .map_err(crate::Error::from)?;
-    Ok(deploy)
+    check_size(deploy)
+}
+
+/// Creates new Deploy with specified payment and session data, to be sent to the specified node.
+///
+/// If the chain name of `deploy_params` is empty or its `discover_limits` is true, the network's
+/// limits are discovered from the node, and the deploy is checked against those limits rather than
+/// [`MAX_SERIALIZED_SIZE_OF_DEPLOY`] before being signed.  An empty chain name is replaced by the
+/// node's, while a given one must match the node's.
+///
+/// If `maybe_auto_payment` is `Some`, the deploy has a standard payment of the cost of
+/// speculatively executing it on the node plus its margin.  In that case, the other fields of
+/// `payment_params` must be empty, as the speculatively executed deploy has a standard payment of
+/// its trial amount.
+#[allow(clippy::too_many_arguments)]
+pub(super) async fn with_payment_and_session_for_node(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_auto_payment: Option<AutoPayment>,
+    deploy_params: DeployStrParams<'_>,
+    payment_params: PaymentStrParams<'_>,
+    session_params: SessionStrParams<'_>,
+) -> Result<Deploy, CliError> {
+    let session = parse::session_executable_deploy_item(session_params)?;
+    let payment = payment_for_node(payment_params, maybe_auto_payment)?;
+    let parts = DeployParts::new(deploy_params, false, "with_payment_and_session_for_node")?;
+    parts
+        .build_for_node(
+            transport,
+            rpc_id,
+            node_address,
+            verbosity,
+            maybe_auto_payment.map(|auto_payment| auto_payment.margin_percent),
+            session,
+            payment,
+        )
+        .await
 }
 
 /// Creates new Transfer with specified data.
@@ -56,8 +188,71 @@ pub fn new_transfer(
     payment_params: PaymentStrParams,
     allow_unsigned_deploy: bool,
 ) -> Result<Deploy, CliError> {
-    let chain_name = deploy_params.chain_name.to_string();
     let payment = parse::payment_executable_deploy_item(payment_params)?;
+    let session = transfer_session(amount, source_purse, target_account, transfer_id)?;
+    let parts = DeployParts::new(deploy_params, allow_unsigned_deploy, "new_transfer")?;
+    let deploy = parts
+        .builder(session, payment)
+        .build()
+        .map_err(crate::Error::from)?;
+    check_size(deploy)
+}
+
+/// Creates new Transfer with specified data, to be sent to the specified node.
+///
+/// As for [`with_payment_and_session_for_node`], the chain name and network limits are discovered
+/// if the chain name is empty or `discover_limits` is true, and the payment amount is automatic if
+/// `maybe_auto_payment` is `Some`.
+#[allow(clippy::too_many_arguments)]
+pub(super) async fn new_transfer_for_node(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_auto_payment: Option<AutoPayment>,
+    amount: &str,
+    target_account: &str,
+    transfer_id: &str,
+    deploy_params: DeployStrParams<'_>,
+    payment_params: PaymentStrParams<'_>,
+) -> Result<Deploy, CliError> {
+    let payment = payment_for_node(payment_params, maybe_auto_payment)?;
+    let session = transfer_session(amount, None, target_account, transfer_id)?;
+    let parts = DeployParts::new(deploy_params, false, "new_transfer_for_node")?;
+    parts
+        .build_for_node(
+            transport,
+            rpc_id,
+            node_address,
+            verbosity,
+            maybe_auto_payment.map(|auto_payment| auto_payment.margin_percent),
+            session,
+            payment,
+        )
+        .await
+}
+
+/// Parses the payment code of a deploy to be sent to a node, which for an automatic payment amount
+/// is that of the trial deploy, i.e. a standard payment of its trial amount.
+fn payment_for_node(
+    payment_params: PaymentStrParams,
+    maybe_auto_payment: Option<AutoPayment>,
+) -> Result<ExecutableDeployItem, CliError> {
+    match maybe_auto_payment {
+        Some(auto_payment) => parse::payment_executable_deploy_item(PaymentStrParams {
+            payment_amount: &auto_payment.trial_amount.to_string(),
+            ..payment_params
+        }),
+        None => parse::payment_executable_deploy_item(payment_params),
+    }
+}
+
+fn transfer_session(
+    amount: &str,
+    source_purse: Option<URef>,
+    target_account: &str,
+    transfer_id: &str,
+) -> Result<ExecutableDeployItem, CliError> {
     let amount = U512::from_dec_str(amount).map_err(|err| CliError::FailedToParseUint {
         context: "new_transfer amount",
         error: UIntParseError::FromDecStr(err),
@@ -80,36 +275,48 @@ pub fn new_transfer(
     };
 
     let transfer_id = parse::transfer_id(transfer_id)?;
-    let maybe_transfer_id = Some(transfer_id);
-
-    let timestamp = parse::timestamp(deploy_params.timestamp)?;
-    let ttl = parse::ttl(deploy_params.ttl)?;
-    let maybe_session_account = parse::session_account(deploy_params.session_account)?;
-
-    let mut deploy_builder =
-        DeployBuilder::new_transfer(chain_name, amount, source_purse, target, maybe_transfer_id)
-            .with_payment(payment)
-            .with_timestamp(timestamp)
-            .with_ttl(ttl);
+    Ok(ExecutableDeployItem::new_transfer(
+        amount,
+        source_purse,
+        target,
+        Some(transfer_id),
+    ))
+}
 
-    let maybe_secret_key = get_maybe_secret_key(
-        deploy_params.secret_key,
-        allow_unsigned_deploy,
-        "new_transfer",
-    )?;
-    if let Some(secret_key) = &maybe_secret_key {
-        deploy_builder = deploy_builder.with_secret_key(secret_key);
-    }
-    if let Some(account) = maybe_session_account {
-        deploy_builder = deploy_builder.with_account(account);
-    }
-    let deploy = deploy_builder.build().map_err(crate::Error::from)?;
+fn check_size(deploy: Deploy) -> Result<Deploy, CliError> {
     deploy
         .is_valid_size(MAX_SERIALIZED_SIZE_OF_DEPLOY)
         .map_err(crate::Error::from)?;
     Ok(deploy)
 }
 
+/// Retrieves the `Signer`, if any, specified by either the secret key or the signer command of
+/// `deploy_params`.
+///
+/// Returns a `CliError::ConflictingArguments` if both are provided, or as per
+/// [`get_maybe_secret_key`] if neither is.
+fn get_maybe_signer(
+    deploy_params: &DeployStrParams,
+    allow_unsigned_deploy: bool,
+    context: &'static str,
+) -> Result<Option<Box<dyn Signer>>, CliError> {
+    if let Some(external_signer) = parse::external_signer(deploy_params.signer_command)? {
+        if !deploy_params.secret_key.is_empty() {
+            return Err(CliError::ConflictingArguments {
+                context: context.to_string(),
+                args: vec![
+                    format!("secret_key={}", deploy_params.secret_key),
+                    format!("signer_command={}", deploy_params.signer_command),
+                ],
+            });
+        }
+        return Ok(Some(Box::new(external_signer)));
+    }
+    let maybe_secret_key =
+        get_maybe_secret_key(deploy_params.secret_key, allow_unsigned_deploy, context)?;
+    Ok(maybe_secret_key.map(|secret_key| Box::new(secret_key) as Box<dyn Signer>))
+}
+
 /// Retrieves a `SecretKey` based on the provided secret key string and configuration options.
 ///
 /// # Arguments
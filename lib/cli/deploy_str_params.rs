@@ -1,3 +1,6 @@
+#[cfg(doc)]
+use crate::{types::Deploy, ExternalSigner};
+
 /// Container for `Deploy` construction options.
 #[derive(Default, Debug)]
 pub struct DeployStrParams<'a> {
@@ -7,6 +10,12 @@ pub struct DeployStrParams<'a> {
     /// If `secret_key` is empty, the new deploy will not be signed and will need to be signed (e.g.
     /// via [`sign_deploy_file`](super::sign_deploy_file)) at least once in order to be made valid.
     pub secret_key: &'a str,
+    /// Command of an external process used to sign the new deploy, e.g. a client of a signing
+    /// service.  It is split into the program and its args as a POSIX shell would.
+    ///
+    /// For details of how the process is used, see [`ExternalSigner`].  It is an error for both
+    /// `secret_key` and `signer_command` to be non-empty.
+    pub signer_command: &'a str,
     /// RFC3339-like formatted timestamp. e.g. `2018-02-16T00:31:37Z`.
     ///
     /// If `timestamp` is empty, the current time will be used. Note that timestamp is UTC, not
@@ -23,11 +32,29 @@ pub struct DeployStrParams<'a> {
     pub ttl: &'a str,
     /// Name of the chain, to avoid the `Deploy` from being accidentally or maliciously included in
     /// a different chain.
+    ///
+    /// For functions which send the `Deploy` to a node, if `chain_name` is empty, it will be
+    /// discovered from the node along with the network's limits.
     pub chain_name: &'a str,
     /// The hex-encoded public key of the account context under which the session code will be
     /// executed.
     ///
     /// If `session_account` is empty, the account's public key will be derived from the provided
-    /// `secret_key`.  It is an error for both fields to be empty.
+    /// `secret_key`.  It is an error for both fields to be empty, and `session_account` must be
+    /// provided if `signer_command` is used.
     pub session_account: &'a str,
+    /// The price per gas unit, in motes, to be paid for execution of the `Deploy`.
+    ///
+    /// If `gas_price` is empty, [`Deploy::DEFAULT_GAS_PRICE`] will be used.
+    pub gas_price: &'a str,
+    /// Hex-encoded hashes of the deploys which must be executed before this `Deploy`.
+    ///
+    /// If `dependencies` is empty, the `Deploy` will have no dependencies.
+    pub dependencies: Vec<&'a str>,
+    /// For functions which send the `Deploy` to a node, whether to discover the network's limits
+    /// from the node even if `chain_name` is given, in which case it must match the node's.
+    ///
+    /// The limits are discovered as per [`discover_network_limits`](crate::discover_network_limits)
+    /// and the `Deploy` is checked against them before being signed.
+    pub discover_limits: bool,
 }
@@ -0,0 +1,497 @@
+use std::{collections::BTreeMap, env};
+#[cfg(feature = "std-fs-io")]
+use std::{fs, path::Path};
+
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+use casper_types::bytesrepr::Bytes;
+
+use super::{CliError, DeployStrParams, PaymentStrParams, SessionStrParams};
+
+/// A declarative description of a `Deploy`, from which the [`DeployStrParams`],
+/// [`SessionStrParams`] and [`PaymentStrParams`] used to create it are taken.
+///
+/// A template is a TOML or JSON document whose top-level fields are those of `DeployStrParams`,
+/// with `session` and `payment` tables whose fields are those of `SessionStrParams` and
+/// `PaymentStrParams` without their `session_` and `payment_` prefixes, and with
+/// `is_session_transfer` named `transfer`.  All values are strings, other than `dependencies`
+/// and `args_simple` which are arrays of strings, `transfer` which is a boolean, and `args_json`
+/// which may be given either as a string or as an array of objects.  For example:
+///
+/// ```toml
+/// chain_name = "casper-test"
+/// ttl = "1hour"
+/// secret_key = "keys/${SIGNER}/secret_key.pem"
+///
+/// [session]
+/// package_name = "erc20"
+/// entry_point = "transfer"
+/// args_simple = ["recipient:key='${RECIPIENT}'", "amount:u256='${AMOUNT}'"]
+///
+/// [payment]
+/// amount = "auto"
+/// ```
+///
+/// Any string may contain placeholders of the form `${NAME}`, which are replaced by
+/// [`fill_placeholders`](Self::fill_placeholders).
+#[derive(Clone, Default, PartialEq, Eq, Deserialize, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct DeployTemplate {
+    secret_key: String,
+    signer_command: String,
+    timestamp: String,
+    ttl: String,
+    chain_name: String,
+    session_account: String,
+    gas_price: String,
+    dependencies: Vec<String>,
+    session: SessionTemplate,
+    payment: PaymentTemplate,
+}
+
+/// The `session` table of a [`DeployTemplate`].
+#[derive(Clone, Default, PartialEq, Eq, Deserialize, Debug)]
+#[serde(default, deny_unknown_fields)]
+struct SessionTemplate {
+    hash: String,
+    name: String,
+    package_hash: String,
+    package_name: String,
+    path: String,
+    args_simple: Vec<String>,
+    #[serde(deserialize_with = "deserialize_json_args")]
+    args_json: String,
+    args_complex: String,
+    version: String,
+    entry_point: String,
+    transfer: bool,
+}
+
+/// The `payment` table of a [`DeployTemplate`].
+#[derive(Clone, Default, PartialEq, Eq, Deserialize, Debug)]
+#[serde(default, deny_unknown_fields)]
+struct PaymentTemplate {
+    amount: String,
+    hash: String,
+    name: String,
+    package_hash: String,
+    package_name: String,
+    path: String,
+    args_simple: Vec<String>,
+    #[serde(deserialize_with = "deserialize_json_args")]
+    args_json: String,
+    args_complex: String,
+    version: String,
+    entry_point: String,
+}
+
+impl SessionTemplate {
+    fn values_mut(&mut self) -> impl Iterator<Item = &mut String> {
+        [
+            &mut self.hash,
+            &mut self.name,
+            &mut self.package_hash,
+            &mut self.package_name,
+            &mut self.path,
+            &mut self.args_complex,
+            &mut self.version,
+            &mut self.entry_point,
+        ]
+        .into_iter()
+    }
+}
+
+impl PaymentTemplate {
+    fn values_mut(&mut self) -> impl Iterator<Item = &mut String> {
+        [
+            &mut self.amount,
+            &mut self.hash,
+            &mut self.name,
+            &mut self.package_hash,
+            &mut self.package_name,
+            &mut self.path,
+            &mut self.args_complex,
+            &mut self.version,
+            &mut self.entry_point,
+        ]
+        .into_iter()
+    }
+}
+
+impl DeployTemplate {
+    /// Parses a template from a TOML document.
+    pub fn from_toml(toml: &str) -> Result<Self, CliError> {
+        toml::from_str(toml).map_err(|error| CliError::InvalidDeployTemplate {
+            context: "TOML".to_string(),
+            error: error.to_string(),
+        })
+    }
+
+    /// Parses a template from a JSON document.
+    pub fn from_json(json: &str) -> Result<Self, CliError> {
+        serde_json::from_str(json).map_err(|error| CliError::InvalidDeployTemplate {
+            context: "JSON".to_string(),
+            error: error.to_string(),
+        })
+    }
+
+    /// Reads a template from a file and fills its placeholders from `vars` as per
+    /// [`fill_placeholders`](Self::fill_placeholders).
+    ///
+    /// The file is parsed as JSON if its extension is `.json`, otherwise as TOML.  Relative paths
+    /// in the `secret_key`, `path` and `args_complex` fields are taken to be relative to the
+    /// directory containing the template, so that a template can be kept alongside the files it
+    /// refers to.
+    #[cfg(feature = "std-fs-io")]
+    pub fn from_file<P: AsRef<Path>>(
+        path: P,
+        vars: &BTreeMap<String, String>,
+    ) -> Result<Self, CliError> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path).map_err(|error| crate::Error::IoError {
+            context: format!("unable to read deploy template at {}", path.display()),
+            error,
+        })?;
+        let template = if path
+            .extension()
+            .map_or(false, |extension| extension == "json")
+        {
+            Self::from_json(&contents)?
+        } else {
+            Self::from_toml(&contents)?
+        };
+
+        let mut template = template.fill_placeholders(vars)?;
+        if let Some(dir) = path.parent() {
+            for value in [
+                &mut template.secret_key,
+                &mut template.session.path,
+                &mut template.session.args_complex,
+                &mut template.payment.path,
+                &mut template.payment.args_complex,
+            ] {
+                if !value.is_empty() && Path::new(value.as_str()).is_relative() {
+                    *value = dir.join(value.as_str()).display().to_string();
+                }
+            }
+        }
+        Ok(template)
+    }
+
+    /// Replaces every placeholder of the form `${NAME}` with the value of `NAME` in `vars`, or if
+    /// absent there, with the value of the environment variable `NAME`.
+    ///
+    /// Within `args_json`, values are escaped as the contents of a JSON string.  Within
+    /// `args_simple`, values may not contain a single quote, as that delimits the arg's value.
+    ///
+    /// Returns [`CliError::MissingTemplateValue`] if a placeholder has no value.
+    pub fn fill_placeholders(mut self, vars: &BTreeMap<String, String>) -> Result<Self, CliError> {
+        let values = [
+            &mut self.secret_key,
+            &mut self.signer_command,
+            &mut self.timestamp,
+            &mut self.ttl,
+            &mut self.chain_name,
+            &mut self.session_account,
+            &mut self.gas_price,
+        ]
+        .into_iter()
+        .chain(self.dependencies.iter_mut())
+        .chain(self.session.values_mut())
+        .chain(self.payment.values_mut());
+        for value in values {
+            *value = fill(value, vars, Escaping::None)?;
+        }
+        for args_json in [&mut self.session.args_json, &mut self.payment.args_json] {
+            *args_json = fill(args_json, vars, Escaping::Json)?;
+        }
+        let args_simple = self
+            .session
+            .args_simple
+            .iter_mut()
+            .chain(self.payment.args_simple.iter_mut());
+        for arg in args_simple {
+            *arg = fill(arg, vars, Escaping::SimpleArg)?;
+        }
+        Ok(self)
+    }
+
+    /// Returns the `DeployStrParams` of the template, where any non-empty fields of `overrides`,
+    /// e.g. as given on the command line, take precedence.
+    ///
+    /// If either `overrides.secret_key` or `overrides.signer_command` is non-empty, both are taken
+    /// from `overrides`.
+    pub fn deploy_params<'a>(&'a self, overrides: DeployStrParams<'a>) -> DeployStrParams<'a> {
+        fn or<'a>(override_value: &'a str, value: &'a str) -> &'a str {
+            if override_value.is_empty() {
+                value
+            } else {
+                override_value
+            }
+        }
+
+        let (secret_key, signer_command) =
+            if overrides.secret_key.is_empty() && overrides.signer_command.is_empty() {
+                (self.secret_key.as_str(), self.signer_command.as_str())
+            } else {
+                (overrides.secret_key, overrides.signer_command)
+            };
+        let dependencies = if overrides.dependencies.is_empty() {
+            self.dependencies.iter().map(String::as_str).collect()
+        } else {
+            overrides.dependencies
+        };
+        DeployStrParams {
+            secret_key,
+            signer_command,
+            timestamp: or(overrides.timestamp, &self.timestamp),
+            ttl: or(overrides.ttl, &self.ttl),
+            chain_name: or(overrides.chain_name, &self.chain_name),
+            session_account: or(overrides.session_account, &self.session_account),
+            gas_price: or(overrides.gas_price, &self.gas_price),
+            dependencies,
+            discover_limits: overrides.discover_limits,
+        }
+    }
+
+    /// Returns the `SessionStrParams` of the template.
+    pub fn session_params(&self) -> SessionStrParams<'_> {
+        let session = &self.session;
+        SessionStrParams {
+            session_hash: &session.hash,
+            session_name: &session.name,
+            session_package_hash: &session.package_hash,
+            session_package_name: &session.package_name,
+            session_path: &session.path,
+            session_bytes: Bytes::new(),
+            session_args_simple: session.args_simple.iter().map(String::as_str).collect(),
+            session_args_json: &session.args_json,
+            session_args_complex: &session.args_complex,
+            session_version: &session.version,
+            session_entry_point: &session.entry_point,
+            is_session_transfer: session.transfer,
+        }
+    }
+
+    /// Returns the `PaymentStrParams` of the template.
+    pub fn payment_params(&self) -> PaymentStrParams<'_> {
+        let payment = &self.payment;
+        PaymentStrParams {
+            payment_amount: &payment.amount,
+            payment_hash: &payment.hash,
+            payment_name: &payment.name,
+            payment_package_hash: &payment.package_hash,
+            payment_package_name: &payment.package_name,
+            payment_path: &payment.path,
+            payment_bytes: Bytes::new(),
+            payment_args_simple: payment.args_simple.iter().map(String::as_str).collect(),
+            payment_args_json: &payment.args_json,
+            payment_args_complex: &payment.args_complex,
+            payment_version: &payment.version,
+            payment_entry_point: &payment.entry_point,
+        }
+    }
+}
+
+/// Deserializes `args_json` given either as a string or as JSON, which is then re-encoded.
+fn deserialize_json_args<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    match Value::deserialize(deserializer)? {
+        Value::String(json_args) => Ok(json_args),
+        value => Ok(value.to_string()),
+    }
+}
+
+/// How the value of a placeholder is inserted into the template string containing it.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Escaping {
+    /// The value is inserted as is.
+    None,
+    /// The value is escaped as the contents of a JSON string.
+    Json,
+    /// The value is inserted as is, but must not contain a single quote.
+    SimpleArg,
+}
+
+/// Returns `value` with its placeholders replaced as per [`DeployTemplate::fill_placeholders`].
+fn fill(
+    value: &str,
+    vars: &BTreeMap<String, String>,
+    escaping: Escaping,
+) -> Result<String, CliError> {
+    let mut filled = String::with_capacity(value.len());
+    let mut remainder = value;
+    while let Some(start) = remainder.find("${") {
+        filled.push_str(&remainder[..start]);
+        let placeholder = &remainder[start + 2..];
+        let end = placeholder
+            .find('}')
+            .ok_or_else(|| CliError::InvalidDeployTemplate {
+                context: "placeholder".to_string(),
+                error: format!("unterminated placeholder in '{}'", value),
+            })?;
+        let name = &placeholder[..end];
+        let var = match vars.get(name) {
+            Some(var) => var.clone(),
+            None => env::var(name).map_err(|_| CliError::MissingTemplateValue {
+                name: name.to_string(),
+            })?,
+        };
+        match escaping {
+            Escaping::None => filled.push_str(&var),
+            Escaping::Json => {
+                let quoted = Value::String(var).to_string();
+                filled.push_str(&quoted[1..quoted.len() - 1]);
+            }
+            Escaping::SimpleArg => {
+                if var.contains('\'') {
+                    return Err(CliError::InvalidDeployTemplate {
+                        context: "placeholder".to_string(),
+                        error: format!(
+                            "value of '{}' contains a single quote, which is not allowed in \
+                            args_simple",
+                            name
+                        ),
+                    });
+                }
+                filled.push_str(&var);
+            }
+        }
+        remainder = &placeholder[end + 1..];
+    }
+    filled.push_str(remainder);
+    Ok(filled)
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::U256;
+
+    use super::*;
+    use crate::{cli::parse, types::ExecutableDeployItem};
+
+    const PKG_HASH: &str = "09dcee4b212cfd53642ab323fbef07dafafc6f945a80a00147f62910a915c4e6";
+
+    fn vars() -> BTreeMap<String, String> {
+        [
+            ("PKG_HASH", PKG_HASH),
+            ("AMOUNT", "1000"),
+            ("QUOTED", r#"say "hi", it's"#),
+        ]
+        .into_iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+    }
+
+    #[test]
+    fn should_fill_placeholders_of_toml_template() {
+        let toml = r#"
+            chain_name = "casper-test"
+            ttl = "1hour"
+            dependencies = ["${PKG_HASH}"]
+
+            [session]
+            package_hash = "${PKG_HASH}"
+            entry_point = "mint"
+            args_simple = ["amount:u256='${AMOUNT}'"]
+
+            [payment]
+            amount = "2500000000"
+        "#;
+        let template = DeployTemplate::from_toml(toml)
+            .unwrap()
+            .fill_placeholders(&vars())
+            .unwrap();
+
+        let deploy_params = template.deploy_params(DeployStrParams {
+            ttl: "2hours",
+            ..Default::default()
+        });
+        assert_eq!(deploy_params.chain_name, "casper-test");
+        assert_eq!(deploy_params.ttl, "2hours");
+        assert_eq!(deploy_params.dependencies, vec![PKG_HASH]);
+
+        let session = parse::session_executable_deploy_item(template.session_params()).unwrap();
+        assert!(matches!(
+            session,
+            ExecutableDeployItem::StoredVersionedContractByHash { ref entry_point, .. }
+                if entry_point == "mint"
+        ));
+        let amount = session.args().get("amount").unwrap().clone();
+        assert_eq!(amount.into_t::<U256>().unwrap(), U256::from(1000));
+        let payment = parse::payment_executable_deploy_item(template.payment_params()).unwrap();
+        assert_eq!(
+            payment,
+            ExecutableDeployItem::new_standard_payment(2_500_000_000_u64)
+        );
+    }
+
+    #[test]
+    fn should_accept_json_args_as_json() {
+        let json = r#"{
+            "chain_name": "casper-test",
+            "session": {
+                "name": "contract",
+                "entry_point": "mint",
+                "args_json": [{ "name": "amount", "type": "U256", "value": "${AMOUNT}" }]
+            }
+        }"#;
+        let template = DeployTemplate::from_json(json)
+            .unwrap()
+            .fill_placeholders(&vars())
+            .unwrap();
+        assert_eq!(
+            template.session.args_json,
+            r#"[{"name":"amount","type":"U256","value":"1000"}]"#
+        );
+    }
+
+    #[test]
+    fn should_escape_quotes_in_args() {
+        let json = r#"{
+            "session": {
+                "args_json": [{ "name": "greeting", "type": "String", "value": "${QUOTED}" }]
+            }
+        }"#;
+        let template = DeployTemplate::from_json(json)
+            .unwrap()
+            .fill_placeholders(&vars())
+            .unwrap();
+        let args_json: Value = serde_json::from_str(&template.session.args_json).unwrap();
+        assert_eq!(args_json[0]["value"], r#"say "hi", it's"#);
+
+        let toml = r#"
+            [session]
+            args_simple = ["greeting:string='${QUOTED}'"]
+        "#;
+        let template = DeployTemplate::from_toml(toml).unwrap();
+        assert!(matches!(
+            template.fill_placeholders(&vars()),
+            Err(CliError::InvalidDeployTemplate { .. })
+        ));
+    }
+
+    #[test]
+    fn should_fail_to_fill_missing_or_unterminated_placeholder() {
+        let template = DeployTemplate::from_toml(
+            r#"chain_name = "${CASPER_CLIENT_TEMPLATE_TEST_UNSET_VARIABLE}""#,
+        )
+        .unwrap();
+        assert!(matches!(
+            template.fill_placeholders(&vars()),
+            Err(CliError::MissingTemplateValue { name })
+                if name == "CASPER_CLIENT_TEMPLATE_TEST_UNSET_VARIABLE"
+        ));
+
+        let template = DeployTemplate::from_toml(r#"chain_name = "${AMOUNT""#).unwrap();
+        assert!(matches!(
+            template.fill_placeholders(&vars()),
+            Err(CliError::InvalidDeployTemplate { .. })
+        ));
+
+        assert!(matches!(
+            DeployTemplate::from_toml("unknown_field = \"\""),
+            Err(CliError::InvalidDeployTemplate { .. })
+        ));
+    }
+}
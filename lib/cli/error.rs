@@ -3,11 +3,15 @@ use std::num::ParseIntError;
 use humantime::{DurationError, TimestampError};
 use thiserror::Error;
 
+#[cfg(feature = "tokio")]
+use casper_types::U512;
 #[cfg(doc)]
 use casper_types::{account::AccountHash, Key, NamedArg, PublicKey, RuntimeArgs, URef};
 use casper_types::{CLValueError, KeyFromStrError, UIntParseError, URefFromStrError};
 
 use crate::cli::JsonArgsError;
+#[cfg(feature = "tokio")]
+use crate::types::{BlockHash, DeployHash};
 #[cfg(doc)]
 use crate::{
     rpcs::{DictionaryItemIdentifier, GlobalStateIdentifier},
@@ -132,6 +136,38 @@ pub enum CliError {
         error: String,
     },
 
+    /// Invalid deploy template.
+    #[error("invalid deploy template ({context}): {error}")]
+    InvalidDeployTemplate {
+        /// Contextual description of where this error occurred.
+        context: String,
+        /// An error message.
+        error: String,
+    },
+
+    /// No value was provided for a placeholder in a deploy template.
+    #[error("no value provided for placeholder '{name}' in deploy template")]
+    MissingTemplateValue {
+        /// The name of the placeholder.
+        name: String,
+    },
+
+    /// A deploy was executed, but execution failed.
+    #[cfg(feature = "tokio")]
+    #[error(
+        "deploy {deploy_hash} failed in block {block_hash} at a cost of {cost}: {error_message}"
+    )]
+    DeployExecutionFailed {
+        /// The hash of the deploy.
+        deploy_hash: DeployHash,
+        /// The hash of the block in which the deploy was executed.
+        block_hash: BlockHash,
+        /// The cost of executing the deploy.
+        cost: U512,
+        /// The error message reported for the failed execution.
+        error_message: String,
+    },
+
     /// Error while parsing the json-args from a string to JSON.
     #[error(
         "failed to parse json-args to JSON: {0}.  They should be a JSON Array of Objects, each of \
@@ -9,7 +9,9 @@ use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 
 use casper_types::{
-    bytesrepr::{ToBytes, OPTION_NONE_TAG, OPTION_SOME_TAG, RESULT_ERR_TAG, RESULT_OK_TAG},
+    bytesrepr::{
+        self, FromBytes, ToBytes, OPTION_NONE_TAG, OPTION_SOME_TAG, RESULT_ERR_TAG, RESULT_OK_TAG,
+    },
     AsymmetricType, CLType, CLValue, Key, NamedArg, PublicKey, URef, U128, U256, U512,
 };
 
@@ -322,6 +324,130 @@ fn write_json_to_bytesrepr(
     Ok(())
 }
 
+/// Decodes the given `CLValue` into JSON of the form accepted as the "value" of a JSON arg, i.e.
+/// the inverse of constructing a `CLValue` from a [`JsonArg`].
+///
+/// Returns `None` if the value cannot be decoded, e.g. if its type is `CLType::Any`.
+pub(crate) fn cl_value_to_json(cl_value: &CLValue) -> Option<Value> {
+    match read_json_from_bytesrepr(cl_value.cl_type(), cl_value.inner_bytes()) {
+        Ok((json_value, remainder)) if remainder.is_empty() => Some(json_value),
+        _ => None,
+    }
+}
+
+fn read_json_from_bytesrepr<'a>(
+    cl_type: &CLType,
+    bytes: &'a [u8],
+) -> Result<(Value, &'a [u8]), bytesrepr::Error> {
+    match cl_type {
+        CLType::Bool => read_json::<bool>(bytes),
+        CLType::I32 => read_json::<i32>(bytes),
+        CLType::I64 => read_json::<i64>(bytes),
+        CLType::U8 => read_json::<u8>(bytes),
+        CLType::U32 => read_json::<u32>(bytes),
+        CLType::U64 => read_json::<u64>(bytes),
+        CLType::U128 => read_json_string::<U128>(bytes),
+        CLType::U256 => read_json_string::<U256>(bytes),
+        CLType::U512 => read_json_string::<U512>(bytes),
+        CLType::Unit => Ok((Value::Null, bytes)),
+        CLType::String => read_json::<String>(bytes),
+        CLType::Key => {
+            let (value, rem) = Key::from_bytes(bytes)?;
+            Ok((json!(value.to_formatted_string()), rem))
+        }
+        CLType::URef => {
+            let (value, rem) = URef::from_bytes(bytes)?;
+            Ok((json!(value.to_formatted_string()), rem))
+        }
+        CLType::PublicKey => {
+            let (value, rem) = PublicKey::from_bytes(bytes)?;
+            Ok((json!(value.to_hex()), rem))
+        }
+        CLType::Option(inner_cl_type) => match u8::from_bytes(bytes)? {
+            (OPTION_NONE_TAG, rem) => Ok((Value::Null, rem)),
+            (OPTION_SOME_TAG, rem) => read_json_from_bytesrepr(inner_cl_type, rem),
+            _ => Err(bytesrepr::Error::Formatting),
+        },
+        CLType::List(inner_cl_type) if **inner_cl_type == CLType::U8 => {
+            // Output a `Vec<u8>` as a hex-encoded string.
+            let (value, rem) = Vec::<u8>::from_bytes(bytes)?;
+            Ok((json!(base16::encode_lower(&value)), rem))
+        }
+        CLType::List(inner_cl_type) => {
+            let (count, mut rem) = u32::from_bytes(bytes)?;
+            let mut items = vec![];
+            for _ in 0..count {
+                let (item, next_rem) = read_json_from_bytesrepr(inner_cl_type, rem)?;
+                items.push(item);
+                rem = next_rem;
+            }
+            Ok((Value::Array(items), rem))
+        }
+        CLType::ByteArray(length) => {
+            let (value, rem) = bytesrepr::safe_split_at(bytes, *length as usize)?;
+            Ok((json!(base16::encode_lower(value)), rem))
+        }
+        CLType::Result { ok, err } => match u8::from_bytes(bytes)? {
+            (RESULT_OK_TAG, rem) => {
+                let (value, rem) = read_json_from_bytesrepr(ok, rem)?;
+                Ok((json!({ "Ok": value }), rem))
+            }
+            (RESULT_ERR_TAG, rem) => {
+                let (value, rem) = read_json_from_bytesrepr(err, rem)?;
+                Ok((json!({ "Err": value }), rem))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        },
+        CLType::Map {
+            key: key_type,
+            value: value_type,
+        } => {
+            // Output maps as arrays of objects, since not all key types are valid as the keys of a
+            // JSON Object.
+            let (count, mut rem) = u32::from_bytes(bytes)?;
+            let mut entries = vec![];
+            for _ in 0..count {
+                let (key, next_rem) = read_json_from_bytesrepr(key_type, rem)?;
+                let (value, next_rem) = read_json_from_bytesrepr(value_type, next_rem)?;
+                entries.push(json!({ "key": key, "value": value }));
+                rem = next_rem;
+            }
+            Ok((Value::Array(entries), rem))
+        }
+        CLType::Tuple1(inner_cl_types) => read_json_tuple(inner_cl_types, bytes),
+        CLType::Tuple2(inner_cl_types) => read_json_tuple(inner_cl_types, bytes),
+        CLType::Tuple3(inner_cl_types) => read_json_tuple(inner_cl_types, bytes),
+        // The encoding of a value of type `Any` is not self-describing.
+        CLType::Any => Err(bytesrepr::Error::Formatting),
+    }
+}
+
+fn read_json<T: FromBytes + Serialize>(bytes: &[u8]) -> Result<(Value, &[u8]), bytesrepr::Error> {
+    let (value, rem) = T::from_bytes(bytes)?;
+    Ok((json!(value), rem))
+}
+
+/// Outputs big integers as decimal strings, since they may not fit in a JSON Number.
+fn read_json_string<T: FromBytes + ToString>(
+    bytes: &[u8],
+) -> Result<(Value, &[u8]), bytesrepr::Error> {
+    let (value, rem) = T::from_bytes(bytes)?;
+    Ok((json!(value.to_string()), rem))
+}
+
+fn read_json_tuple<'a>(
+    inner_cl_types: &[Box<CLType>],
+    mut bytes: &'a [u8],
+) -> Result<(Value, &'a [u8]), bytesrepr::Error> {
+    let mut items = vec![];
+    for inner_cl_type in inner_cl_types {
+        let (item, rem) = read_json_from_bytesrepr(inner_cl_type, bytes)?;
+        items.push(item);
+        bytes = rem;
+    }
+    Ok((Value::Array(items), bytes))
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::BTreeMap;
@@ -1019,4 +1145,49 @@ mod tests {
             error
         );
     }
+
+    #[test]
+    fn should_decode_to_json() {
+        let check = |type_str: &str, value_str: &str| {
+            let input = create_input(type_str, value_str);
+            let json_arg: JsonArg = serde_json::from_str(&input).unwrap();
+            let named_arg = NamedArg::try_from(json_arg).unwrap();
+            let expected: Value = serde_json::from_str(value_str).unwrap();
+            assert_eq!(cl_value_to_json(named_arg.cl_value()), Some(expected));
+        };
+        check(r#""Bool""#, "true");
+        check(r#""I32""#, "-1");
+        check(r#""U64""#, "18446744073709551615");
+        check(r#""U512""#, r#""123456789012345678901234567890""#);
+        check(r#""Unit""#, "null");
+        check(r#""String""#, r#""a string""#);
+        check(
+            r#""Key""#,
+            r#""account-hash-1111111111111111111111111111111111111111111111111111111111111111""#,
+        );
+        check(
+            r#""URef""#,
+            r#""uref-1111111111111111111111111111111111111111111111111111111111111111-007""#,
+        );
+        check(
+            r#""PublicKey""#,
+            r#""011111111111111111111111111111111111111111111111111111111111111111""#,
+        );
+        check(r#"{"Option":"U8"}"#, "null");
+        check(r#"{"Option":"U8"}"#, "1");
+        check(r#"{"List":"U8"}"#, r#""0102ff""#);
+        check(r#"{"List":"String"}"#, r#"["a","b"]"#);
+        check(r#"{"ByteArray":2}"#, r#""0aff""#);
+        check(r#"{"Result":{"ok":"Bool","err":"U8"}}"#, r#"{"Err":1}"#);
+        check(
+            r#"{"Map":{"key":"U8","value":"String"}}"#,
+            r#"[{"key":1,"value":"a"},{"key":2,"value":"b"}]"#,
+        );
+        check(r#"{"Tuple3":["Bool","U8","String"]}"#, r#"[true,1,"a"]"#);
+
+        let any = CLValue::from_components(CLType::Any, vec![1, 2, 3]);
+        assert_eq!(cl_value_to_json(&any), None);
+        let truncated = CLValue::from_components(CLType::U64, vec![1, 2, 3]);
+        assert_eq!(cl_value_to_json(&truncated), None);
+    }
 }
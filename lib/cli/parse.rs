@@ -21,13 +21,13 @@ use casper_types::{
 };
 
 use super::{simple_args, CliError, PaymentStrParams, SessionStrParams};
-#[cfg(feature = "std-fs-io")]
-use crate::OutputKind;
 use crate::{
-    types::{BlockHash, DeployHash, ExecutableDeployItem, TimeDiff, Timestamp},
-    AccountIdentifier, BlockIdentifier, GlobalStateIdentifier, JsonRpcId, PurseIdentifier,
-    Verbosity,
+    types::{BlockHash, Deploy, DeployHash, ExecutableDeployItem, TimeDiff, Timestamp},
+    AccountIdentifier, BlockIdentifier, ExternalSigner, GlobalStateIdentifier, JsonRpcId,
+    PurseIdentifier, Verbosity,
 };
+#[cfg(feature = "std-fs-io")]
+use crate::{DeployFormat, OutputKind};
 
 pub(super) fn rpc_id(maybe_rpc_id: &str) -> JsonRpcId {
     if maybe_rpc_id.is_empty() {
@@ -56,16 +56,40 @@ pub(super) fn output_kind(maybe_output_path: &str, force: bool) -> OutputKind {
     }
 }
 
+#[cfg(feature = "std-fs-io")]
+pub(super) fn deploy_format(value: &str) -> Result<DeployFormat, CliError> {
+    match value.to_lowercase().as_str() {
+        "" | "json" => Ok(DeployFormat::Json),
+        "binary" => Ok(DeployFormat::Binary),
+        _ => Err(CliError::InvalidArgument {
+            context: "deploy_format",
+            error: format!("expected 'json' or 'binary', got '{}'", value),
+        }),
+    }
+}
+
+/// Reads a secret key from a plaintext PEM file or a keystore, in which case the password is read
+/// as per [`keystore::read_password`](crate::keystore::read_password).
 #[cfg(feature = "std-fs-io")]
 pub(super) fn secret_key_from_file<P: AsRef<Path>>(
     secret_key_path: P,
 ) -> Result<SecretKey, CliError> {
-    SecretKey::from_file(secret_key_path).map_err(|error| {
-        CliError::Core(crate::Error::CryptoError {
-            context: "secret key",
-            error,
-        })
-    })
+    crate::keystore::read_secret_key_file(secret_key_path).map_err(CliError::from)
+}
+
+/// Parses a signer command into an [`ExternalSigner`], splitting it into the program and its args
+/// as a POSIX shell would, so that quotes and backslashes can be used to include whitespace in a
+/// word.  Returns `None` if `value` is empty.
+pub(super) fn external_signer(value: &str) -> Result<Option<ExternalSigner>, CliError> {
+    let mut words = shell_words::split(value)
+        .map_err(|error| CliError::InvalidArgument {
+            context: "signer_command",
+            error: error.to_string(),
+        })?
+        .into_iter();
+    Ok(words
+        .next()
+        .map(|program| ExternalSigner::new(program, words.collect())))
 }
 
 pub(super) fn timestamp(value: &str) -> Result<Timestamp, CliError> {
@@ -85,6 +109,90 @@ pub(super) fn ttl(value: &str) -> Result<TimeDiff, CliError> {
     })
 }
 
+#[cfg(feature = "tokio")]
+pub(super) fn wait_timeout(value: &str) -> Result<Option<TimeDiff>, CliError> {
+    if value.is_empty() {
+        return Ok(None);
+    }
+    TimeDiff::from_str(value)
+        .map(Some)
+        .map_err(|error| CliError::FailedToParseTimeDiff {
+            context: "wait timeout",
+            error,
+        })
+}
+
+pub(super) fn gas_price(value: &str) -> Result<u64, CliError> {
+    if value.is_empty() {
+        return Ok(Deploy::DEFAULT_GAS_PRICE);
+    }
+    value.parse().map_err(|error| CliError::FailedToParseInt {
+        context: "gas_price",
+        error,
+    })
+}
+
+/// The margin added to the speculatively-executed cost for a payment amount of "auto".
+const DEFAULT_AUTO_PAYMENT_MARGIN_PERCENT: u32 = 10;
+/// The standard payment amount of the trial deploy for a payment amount of "auto", unless one is
+/// given, i.e. 100 CSPR.
+const DEFAULT_AUTO_PAYMENT_TRIAL_AMOUNT: u64 = 100_000_000_000;
+
+/// An automatic payment amount, i.e. `auto`, `auto:<MARGIN>%` or
+/// `auto:<MARGIN>%:<TRIAL_AMOUNT>`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(super) struct AutoPayment {
+    /// The margin in percent added to the speculatively-executed cost.
+    pub(super) margin_percent: u32,
+    /// The standard payment amount of the speculatively-executed trial deploy, which caps the gas
+    /// it can use and which the account's balance must cover.
+    pub(super) trial_amount: U512,
+}
+
+/// Returns the automatic payment amount if `value` is `auto`, `auto:<MARGIN>%` or
+/// `auto:<MARGIN>%:<TRIAL_AMOUNT>`, or `None` if it isn't an automatic payment amount.
+pub(super) fn auto_payment(value: &str) -> Result<Option<AutoPayment>, CliError> {
+    let suffix = match value.strip_prefix("auto") {
+        Some(suffix) => suffix,
+        None => return Ok(None),
+    };
+    let mut auto_payment = AutoPayment {
+        margin_percent: DEFAULT_AUTO_PAYMENT_MARGIN_PERCENT,
+        trial_amount: U512::from(DEFAULT_AUTO_PAYMENT_TRIAL_AMOUNT),
+    };
+    if suffix.is_empty() {
+        return Ok(Some(auto_payment));
+    }
+    let invalid_argument = || CliError::InvalidArgument {
+        context: "auto_payment",
+        error: format!(
+            "expected 'auto', 'auto:<MARGIN>%' or 'auto:<MARGIN>%:<TRIAL_AMOUNT>', got '{}'",
+            value
+        ),
+    };
+    let mut parts = suffix
+        .strip_prefix(':')
+        .ok_or_else(invalid_argument)?
+        .splitn(2, ':');
+    let margin = parts.next().unwrap_or_default();
+    auto_payment.margin_percent = margin
+        .strip_suffix('%')
+        .unwrap_or(margin)
+        .parse()
+        .map_err(|_| invalid_argument())?;
+    if let Some(trial_amount) = parts.next() {
+        auto_payment.trial_amount = Some(trial_amount)
+            .filter(|trial_amount| !trial_amount.is_empty())
+            .and_then(|trial_amount| U512::from_dec_str(trial_amount).ok())
+            .ok_or_else(invalid_argument)?;
+    }
+    Ok(Some(auto_payment))
+}
+
+pub(super) fn dependencies(values: &[&str]) -> Result<Vec<DeployHash>, CliError> {
+    values.iter().map(|value| deploy_hash(value)).collect()
+}
+
 pub(super) fn session_account(value: &str) -> Result<Option<PublicKey>, CliError> {
     if value.is_empty() {
         return Ok(None);
@@ -949,6 +1057,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn should_parse_auto_payment() {
+        let margin_percent = |value: &str| auto_payment(value).unwrap().unwrap().margin_percent;
+        let trial_amount = |value: &str| auto_payment(value).unwrap().unwrap().trial_amount;
+
+        assert_eq!(auto_payment("").unwrap(), None);
+        assert_eq!(auto_payment("2500000000").unwrap(), None);
+        assert_eq!(
+            auto_payment("auto").unwrap(),
+            Some(AutoPayment {
+                margin_percent: DEFAULT_AUTO_PAYMENT_MARGIN_PERCENT,
+                trial_amount: U512::from(DEFAULT_AUTO_PAYMENT_TRIAL_AMOUNT),
+            })
+        );
+        assert_eq!(margin_percent("auto:25%"), 25);
+        assert_eq!(margin_percent("auto:0"), 0);
+        assert_eq!(
+            trial_amount("auto:25%"),
+            U512::from(DEFAULT_AUTO_PAYMENT_TRIAL_AMOUNT)
+        );
+        assert_eq!(margin_percent("auto:5%:500000000000"), 5);
+        assert_eq!(
+            trial_amount("auto:5%:500000000000"),
+            U512::from(500_000_000_000_u64)
+        );
+        for invalid in ["auto:-5%", "automatic", "auto:5%:", "auto:5%:1cspr"] {
+            assert!(matches!(
+                auto_payment(invalid),
+                Err(CliError::InvalidArgument { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn should_parse_external_signer() {
+        assert_eq!(external_signer("").unwrap(), None);
+        assert_eq!(
+            external_signer(r#"'/opt/signing service/sign' --key "key 1" plain"#).unwrap(),
+            Some(ExternalSigner::new(
+                "/opt/signing service/sign",
+                vec![
+                    "--key".to_string(),
+                    "key 1".to_string(),
+                    "plain".to_string()
+                ]
+            ))
+        );
+        assert!(matches!(
+            external_signer("sign --key \"unterminated"),
+            Err(CliError::InvalidArgument { .. })
+        ));
+    }
+
     #[test]
     fn should_fail_to_parse_conflicting_arg_types() {
         let test_context = "parsing session args conflict (simple json complex)";
@@ -1,8 +1,13 @@
+#[cfg(feature = "std-fs-io")]
+use casper_types::bytesrepr::ToBytes;
 use casper_types::{AsymmetricType, PublicKey, SecretKey};
 
 use crate::Error;
 #[cfg(feature = "std-fs-io")]
-use crate::{types::ExecutableDeployItem, OutputKind, MAX_SERIALIZED_SIZE_OF_DEPLOY};
+use crate::{
+    types::{ExecutableDeployItem, TimeDiff},
+    DeployFormat, OutputKind, MAX_SERIALIZED_SIZE_OF_DEPLOY,
+};
 
 use super::*;
 
@@ -129,7 +134,7 @@ fn should_create_deploy() {
             .unwrap();
 
     let mut output = Vec::new();
-    crate::write_deploy(&_deploy, &mut output).unwrap();
+    crate::write_deploy(&_deploy, DeployFormat::Json, &mut output).unwrap();
 
     // The test output can be used to generate data for SAMPLE_DEPLOY:
     // let secret_key = SecretKey::generate_ed25519().unwrap();
@@ -149,6 +154,44 @@ fn should_create_deploy() {
     assert_eq!(expected.session(), actual.session());
 }
 
+#[test]
+#[cfg(feature = "std-fs-io")]
+fn should_create_deploy_with_gas_price_and_dependencies() {
+    let deploy_params = DeployStrParams {
+        gas_price: "3",
+        dependencies: vec![PKG_HASH, PKG_HASH],
+        ..deploy_params()
+    };
+    let payment_params = PaymentStrParams::with_amount("100");
+    let session_params =
+        SessionStrParams::with_package_hash(PKG_HASH, VERSION, ENTRYPOINT, args_simple(), "", "");
+
+    let deploy =
+        deploy::with_payment_and_session(deploy_params, payment_params, session_params, false)
+            .unwrap();
+
+    assert_eq!(deploy.header().gas_price(), 3);
+    let dependencies: Vec<String> = deploy
+        .header()
+        .dependencies()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(dependencies, vec![PKG_HASH.to_string()]);
+
+    let invalid_params = DeployStrParams {
+        dependencies: vec!["not a hash"],
+        ..deploy_params()
+    };
+    let error = deploy::with_payment_and_session(
+        invalid_params,
+        PaymentStrParams::with_amount("100"),
+        SessionStrParams::with_package_hash(PKG_HASH, VERSION, ENTRYPOINT, args_simple(), "", ""),
+        false,
+    )
+    .unwrap_err();
+    assert!(matches!(error, CliError::FailedToParseDigest { .. }));
+}
+
 #[test]
 #[cfg(feature = "std-fs-io")]
 fn should_fail_to_create_large_deploy() {
@@ -202,10 +245,16 @@ fn should_sign_deploy() {
     let tempdir = tempfile::tempdir().unwrap();
     let path = tempdir.path().join("deploy.json");
 
-    crate::output_deploy(OutputKind::file(&path, false), &deploy).unwrap();
+    crate::output_deploy(OutputKind::file(&path, false), DeployFormat::Json, &deploy).unwrap();
 
     let secret_key = SecretKey::generate_ed25519().unwrap();
-    crate::sign_deploy_file(&path, &secret_key, OutputKind::file(&path, true)).unwrap();
+    crate::sign_deploy_file(
+        &path,
+        &secret_key,
+        DeployFormat::Json,
+        OutputKind::file(&path, true),
+    )
+    .unwrap();
     let signed_deploy = crate::read_deploy_file(&path).unwrap();
 
     assert_eq!(
@@ -214,6 +263,261 @@ fn should_sign_deploy() {
     );
 }
 
+#[test]
+#[cfg(feature = "std-fs-io")]
+fn should_round_trip_deploy_in_binary_format() {
+    let deploy = crate::read_deploy(SAMPLE_DEPLOY.as_bytes()).unwrap();
+    let tempdir = tempfile::tempdir().unwrap();
+    let json_path = tempdir.path().join("deploy.json");
+    let binary_path = tempdir.path().join("deploy.bin");
+
+    crate::output_deploy(
+        OutputKind::file(&json_path, false),
+        DeployFormat::Json,
+        &deploy,
+    )
+    .unwrap();
+    crate::output_deploy(
+        OutputKind::file(&binary_path, false),
+        DeployFormat::Binary,
+        &deploy,
+    )
+    .unwrap();
+    let binary = std::fs::read(&binary_path).unwrap();
+    assert_eq!(binary, deploy.to_bytes().unwrap());
+    assert!(binary.len() < std::fs::read(&json_path).unwrap().len());
+
+    let read_deploy = crate::read_deploy_file(&binary_path).unwrap();
+    assert_eq!(read_deploy, deploy);
+    read_deploy
+        .verify(deploy.header().chain_name(), deploy.header().ttl())
+        .unwrap();
+
+    // Signing a binary deploy file and writing it as JSON preserves the hash.
+    let secret_key = SecretKey::generate_ed25519().unwrap();
+    crate::sign_deploy_file(
+        &binary_path,
+        &secret_key,
+        DeployFormat::Json,
+        OutputKind::file(&json_path, true),
+    )
+    .unwrap();
+    let signed_deploy = crate::read_deploy_file(&json_path).unwrap();
+    assert_eq!(signed_deploy.id(), deploy.id());
+    assert_eq!(
+        signed_deploy.approvals().len(),
+        deploy.approvals().len() + 1
+    );
+
+    assert!(matches!(
+        crate::read_deploy(&binary[..binary.len() - 1]),
+        Err(Error::FailedToDecodeFromBytes { .. })
+    ));
+    assert!(matches!(
+        crate::read_deploy(&b"[\"not a deploy\"]"[..]),
+        Err(Error::FailedToDecodeFromJson { .. })
+    ));
+}
+
+#[test]
+#[cfg(feature = "std-fs-io")]
+fn should_verify_deploy() {
+    let new_deploy = || {
+        deploy::with_payment_and_session(
+            deploy_params(),
+            PaymentStrParams::with_amount("100"),
+            SessionStrParams::with_package_hash(
+                PKG_HASH,
+                VERSION,
+                ENTRYPOINT,
+                args_simple(),
+                "",
+                "",
+            ),
+            false,
+        )
+        .unwrap()
+    };
+    let chain_name = deploy_params().chain_name;
+    let max_ttl = TimeDiff::from_millis(10_000);
+
+    let deploy = new_deploy();
+    let tempdir = tempfile::tempdir().unwrap();
+    let path = tempdir.path().join("deploy.json");
+    crate::output_deploy(OutputKind::file(&path, false), DeployFormat::Json, &deploy).unwrap();
+    verify_deploy_file(path.to_str().unwrap(), chain_name, "10s").unwrap();
+
+    assert!(matches!(
+        deploy.verify("other-chain", max_ttl),
+        Err(Error::DeployChainNameMismatch { .. })
+    ));
+    assert!(matches!(
+        deploy.verify(chain_name, TimeDiff::from_millis(9_999)),
+        Err(Error::DeployExcessiveTtl { .. })
+    ));
+
+    let mut tampered = deploy.clone();
+    tampered.payment = tampered.session.clone();
+    assert!(matches!(
+        tampered.verify(chain_name, max_ttl),
+        Err(Error::DeployBodyHashMismatch { .. })
+    ));
+
+    // A deploy created at a different time has a different hash, so its approvals are invalid for
+    // the original deploy.
+    let mut other = new_deploy();
+    while other.id() == deploy.id() {
+        other = new_deploy();
+    }
+    let mut tampered = deploy.clone();
+    tampered.hash = *other.id();
+    assert!(matches!(
+        tampered.verify(chain_name, max_ttl),
+        Err(Error::DeployHashMismatch { .. })
+    ));
+
+    let mut tampered = deploy;
+    tampered.approvals = other.approvals().to_vec();
+    assert!(matches!(
+        tampered.verify(chain_name, max_ttl),
+        Err(Error::DeployInvalidApproval { .. })
+    ));
+}
+
+#[test]
+#[cfg(feature = "std-fs-io")]
+fn should_merge_deploys() {
+    let deploy = crate::read_deploy(SAMPLE_DEPLOY.as_bytes()).unwrap();
+    let tempdir = tempfile::tempdir().unwrap();
+    let path_1 = tempdir.path().join("deploy_1.json");
+    let path_2 = tempdir.path().join("deploy_2.json");
+    let merged_path = tempdir.path().join("merged.json");
+    let other_path = tempdir.path().join("other.json");
+
+    let mut deploy_1 = deploy.clone();
+    deploy_1
+        .sign(&SecretKey::generate_ed25519().unwrap())
+        .unwrap();
+    crate::output_deploy(
+        OutputKind::file(&path_1, false),
+        DeployFormat::Json,
+        &deploy_1,
+    )
+    .unwrap();
+    let mut deploy_2 = deploy.clone();
+    deploy_2
+        .sign(&SecretKey::generate_ed25519().unwrap())
+        .unwrap();
+    crate::output_deploy(
+        OutputKind::file(&path_2, false),
+        DeployFormat::Json,
+        &deploy_2,
+    )
+    .unwrap();
+
+    let input_paths = [path_1.to_str().unwrap(), path_2.to_str().unwrap()];
+    merge_deploy_files(&input_paths, merged_path.to_str().unwrap(), "", false).unwrap();
+    let merged_deploy = crate::read_deploy_file(&merged_path).unwrap();
+    assert_eq!(merged_deploy.id(), deploy.id());
+    assert_eq!(
+        merged_deploy.approvals().len(),
+        deploy.approvals().len() + 2
+    );
+
+    let other_deploy = deploy::with_payment_and_session(
+        deploy_params(),
+        PaymentStrParams::with_amount("100"),
+        SessionStrParams::with_package_hash(PKG_HASH, VERSION, ENTRYPOINT, args_simple(), "", ""),
+        false,
+    )
+    .unwrap();
+    crate::output_deploy(
+        OutputKind::file(&other_path, false),
+        DeployFormat::Json,
+        &other_deploy,
+    )
+    .unwrap();
+    let input_paths = [path_1.to_str().unwrap(), other_path.to_str().unwrap()];
+    let result = merge_deploy_files(&input_paths, "", "", false);
+    assert!(matches!(
+        result,
+        Err(CliError::Core(Error::MismatchedDeploys { .. }))
+    ));
+
+    // An approval of a different deploy is not a valid approval of this one, whether it's in the
+    // first or a later input.
+    let forged_path = tempdir.path().join("forged.json");
+    let mut forged_deploy = deploy;
+    forged_deploy.approvals = other_deploy.approvals().to_vec();
+    crate::output_deploy(
+        OutputKind::file(&forged_path, false),
+        DeployFormat::Json,
+        &forged_deploy,
+    )
+    .unwrap();
+    for input_paths in [
+        [path_1.to_str().unwrap(), forged_path.to_str().unwrap()],
+        [forged_path.to_str().unwrap(), path_1.to_str().unwrap()],
+    ] {
+        let result = merge_deploy_files(&input_paths, "", "", false);
+        assert!(matches!(
+            result,
+            Err(CliError::Core(Error::DeployInvalidApproval { .. }))
+        ));
+    }
+}
+
+#[test]
+#[cfg(feature = "std-fs-io")]
+fn should_restamp_deploy() {
+    let deploy = crate::read_deploy(SAMPLE_DEPLOY.as_bytes()).unwrap();
+    let tempdir = tempfile::tempdir().unwrap();
+    let path = tempdir.path().join("deploy.json");
+    crate::output_deploy(OutputKind::file(&path, false), DeployFormat::Json, &deploy).unwrap();
+
+    let path = path.to_str().unwrap();
+    restamp_deploy_file(
+        path,
+        "",
+        "1h",
+        "",
+        "",
+        &["resources/test.pem"],
+        "",
+        path,
+        "",
+        true,
+    )
+    .unwrap();
+    let restamped_deploy = crate::read_deploy_file(path).unwrap();
+    assert_ne!(restamped_deploy.id(), deploy.id());
+    assert!(restamped_deploy.header().timestamp() > deploy.header().timestamp());
+    assert_eq!(
+        restamped_deploy.header().ttl(),
+        TimeDiff::from_millis(3_600_000)
+    );
+    assert_eq!(
+        restamped_deploy.header().gas_price(),
+        deploy.header().gas_price()
+    );
+    assert_eq!(restamped_deploy.payment(), deploy.payment());
+    assert_eq!(restamped_deploy.session(), deploy.session());
+
+    // The original approvals are dropped, and the deploy is re-signed by the given key.
+    let secret_key = crate::keystore::read_secret_key_file("resources/test.pem").unwrap();
+    assert_eq!(restamped_deploy.approvals().len(), 1);
+    assert_eq!(
+        *restamped_deploy.approvals()[0].signer(),
+        PublicKey::from(&secret_key)
+    );
+    restamped_deploy
+        .verify(
+            deploy.header().chain_name(),
+            TimeDiff::from_millis(3_600_000),
+        )
+        .unwrap();
+}
+
 #[test]
 #[cfg(feature = "std-fs-io")]
 fn should_create_transfer() {
@@ -335,6 +639,24 @@ fn should_fail_to_create_deploy_with_no_session_account() {
     ));
 }
 
+#[test]
+fn should_fail_to_create_deploy_with_secret_key_and_signer_command() {
+    let deploy_params = DeployStrParams {
+        signer_command: "signing-service-client --key-id 1",
+        ..deploy_params()
+    };
+    let payment_params = PaymentStrParams::with_amount("100");
+    let session_params =
+        SessionStrParams::with_package_hash(PKG_HASH, VERSION, ENTRYPOINT, args_simple(), "", "");
+
+    let deploy =
+        deploy::with_payment_and_session(deploy_params, payment_params, session_params, false);
+    assert!(matches!(
+        deploy.unwrap_err(),
+        CliError::ConflictingArguments { .. }
+    ));
+}
+
 #[test]
 fn should_create_unsigned_transfer() {
     use casper_types::{AsymmetricType, PublicKey};
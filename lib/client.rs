@@ -0,0 +1,887 @@
+//! A stateful handle for sending JSON-RPC requests to a single node.
+
+use std::sync::{
+    atomic::{AtomicI64, Ordering},
+    Arc, Mutex,
+};
+
+use rand::Rng;
+use reqwest::Client;
+use serde_json::Value;
+
+use casper_hashing::Digest;
+#[cfg(doc)]
+use casper_types::Transfer;
+use casper_types::{Key, URef};
+
+#[cfg(doc)]
+use crate::types::{Account, Block, StoredValue};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::HttpConfig;
+use crate::{
+    json_rpc::{self, JsonRpcCall},
+    rpcs::{
+        common::{BlockIdentifier, GlobalStateIdentifier},
+        results::{
+            GetAccountResult, GetAuctionInfoResult, GetBalanceResult, GetBlockResult,
+            GetBlockTransfersResult, GetChainspecResult, GetDeployResult, GetDictionaryItemResult,
+            GetEraInfoResult, GetEraSummaryResult, GetPeersResult, GetStateRootHashResult,
+            GetValidatorChangesResult, ListRpcsResult, PutDeployResult, QueryBalanceResult,
+            QueryGlobalStateResult, SpeculativeExecResult, VersionedGetNodeStatusResult,
+        },
+        v1_4_5::{
+            get_account::GetAccountParams as GetAccountParamsV1_4_5,
+            query_global_state::QueryGlobalStateParams as QueryGlobalStateParamsV1_4_5,
+        },
+        v1_6_0::{
+            get_account::GET_ACCOUNT_METHOD, get_chainspec::GET_CHAINSPEC_METHOD,
+            get_era_summary::GET_ERA_SUMMARY_METHOD, get_node_status::GET_NODE_STATUS_METHOD,
+            query_balance::QUERY_BALANCE_METHOD, query_global_state::QUERY_GLOBAL_STATE_METHOD,
+            speculative_exec::SPECULATIVE_EXEC_METHOD,
+        },
+        AccountIdentifier, DictionaryItemIdentifier, PurseIdentifier, RpcApiVersion,
+    },
+    types::{Deploy, DeployHash},
+    Batch, BatchResponse, Error, HttpTransport, JsonRpcId, SuccessResponse, Transport, Verbosity,
+};
+#[cfg(feature = "tokio")]
+use crate::{RetryPolicy, RetryingTransport};
+
+/// The means by which a [`CasperClient`] assigns a JSON-RPC identifier to each request.
+#[derive(Clone, Default, Eq, PartialEq, Debug)]
+pub enum RpcIdStrategy {
+    /// Each request is assigned a random `i64`.
+    #[default]
+    Random,
+    /// Requests are assigned sequential integers, starting at the given value.
+    Sequential(i64),
+    /// Every request is assigned the given identifier.
+    Fixed(JsonRpcId),
+}
+
+/// The source of the transport to be used by a [`CasperClient`].
+#[derive(Debug)]
+enum TransportSource {
+    Default,
+    Custom(Arc<dyn Transport>),
+    #[cfg(not(target_arch = "wasm32"))]
+    HttpConfig(HttpConfig),
+}
+
+/// A builder for constructing a [`CasperClient`].
+#[derive(Debug)]
+pub struct CasperClientBuilder {
+    node_address: String,
+    rpc_id_strategy: RpcIdStrategy,
+    verbosity: Verbosity,
+    transport_source: TransportSource,
+    rpc_api_version: Option<RpcApiVersion>,
+    #[cfg(feature = "tokio")]
+    retry_policy: Option<RetryPolicy>,
+}
+
+impl CasperClientBuilder {
+    /// Returns a new `CasperClientBuilder` for a client which will send requests to the node at
+    /// `node_address`, e.g. `http://127.0.0.1:7777`.
+    pub fn new<A: Into<String>>(node_address: A) -> Self {
+        CasperClientBuilder {
+            node_address: node_address.into(),
+            rpc_id_strategy: RpcIdStrategy::default(),
+            verbosity: Verbosity::Low,
+            transport_source: TransportSource::Default,
+            rpc_api_version: None,
+            #[cfg(feature = "tokio")]
+            retry_policy: None,
+        }
+    }
+
+    /// Sets the strategy used to assign a JSON-RPC identifier to each request.
+    ///
+    /// If not provided, [`RpcIdStrategy::Random`] is used.
+    pub fn with_rpc_id_strategy(mut self, rpc_id_strategy: RpcIdStrategy) -> Self {
+        self.rpc_id_strategy = rpc_id_strategy;
+        self
+    }
+
+    /// Sets the level of detail of the requests and responses emitted as `tracing` events.
+    ///
+    /// If not provided, [`Verbosity::Low`] is used, i.e. long string fields are abbreviated.
+    pub fn with_verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.verbosity = verbosity;
+        self
+    }
+
+    /// Sets the settings of the HTTP client used to send requests.
+    ///
+    /// This replaces any transport or HTTP client previously set on the builder.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_http_config(mut self, config: HttpConfig) -> Self {
+        self.transport_source = TransportSource::HttpConfig(config);
+        self
+    }
+
+    /// Sets the `reqwest::Client` used to send HTTP requests.
+    ///
+    /// This replaces any transport or HTTP settings previously set on the builder.
+    pub fn with_http_client(mut self, client: Client) -> Self {
+        self.transport_source = TransportSource::Custom(Arc::new(HttpTransport::new(client)));
+        self
+    }
+
+    /// Sets the transport used to send requests.
+    ///
+    /// This replaces any HTTP client or HTTP settings previously set on the builder.  If none of
+    /// these are set, the default [`HttpTransport`] is used.
+    pub fn with_transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.transport_source = TransportSource::Custom(transport);
+        self
+    }
+
+    /// Sets the JSON-RPC API version of the node.
+    ///
+    /// If not provided, the version is detected via an `info_get_status` request the first time
+    /// it is needed.
+    pub fn with_rpc_api_version(mut self, rpc_api_version: RpcApiVersion) -> Self {
+        self.rpc_api_version = Some(rpc_api_version);
+        self
+    }
+
+    /// Sets the policy for retrying requests which fail due to transient errors.
+    ///
+    /// The policy applies to whichever transport the client uses.  If not provided, failed
+    /// requests are not retried.
+    #[cfg(feature = "tokio")]
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Returns the new `CasperClient`.
+    ///
+    /// Returns an error if the settings provided via
+    /// [`with_http_config`](Self::with_http_config) are invalid.
+    pub fn build(mut self) -> Result<CasperClient, Error> {
+        let transport: Arc<dyn Transport> =
+            match std::mem::replace(&mut self.transport_source, TransportSource::Default) {
+                TransportSource::Default => Arc::new(HttpTransport::default()),
+                TransportSource::Custom(transport) => transport,
+                #[cfg(not(target_arch = "wasm32"))]
+                TransportSource::HttpConfig(config) => {
+                    Arc::new(HttpTransport::with_config(&config)?)
+                }
+            };
+        #[cfg(feature = "tokio")]
+        let transport: Arc<dyn Transport> = match self.retry_policy.take() {
+            Some(retry_policy) => Arc::new(RetryingTransport::new(transport, retry_policy)),
+            None => transport,
+        };
+        Ok(self.into_client(transport))
+    }
+
+    fn into_client(self, transport: Arc<dyn Transport>) -> CasperClient {
+        let next_rpc_id = match &self.rpc_id_strategy {
+            RpcIdStrategy::Sequential(start) => *start,
+            RpcIdStrategy::Random | RpcIdStrategy::Fixed(_) => 0,
+        };
+        CasperClient {
+            node_address: self.node_address,
+            rpc_id_strategy: self.rpc_id_strategy,
+            next_rpc_id: AtomicI64::new(next_rpc_id),
+            verbosity: self.verbosity,
+            transport,
+            rpc_api_version: Mutex::new(self.rpc_api_version),
+        }
+    }
+}
+
+/// A handle for sending JSON-RPC requests to a single node.
+///
+/// The node address, verbosity and transport are provided once when the client is constructed
+/// via a [`CasperClientBuilder`], and a JSON-RPC identifier is assigned to each request according
+/// to the client's [`RpcIdStrategy`].  There is one method per JSON-RPC, each of which calls the
+/// `_with_transport` variant of the free function of the same name in the root of the library; the
+/// free functions remain independent of the client.
+///
+/// Unlike the free functions, which always use the latest version of the node's JSON-RPC API, the
+/// client sends the request parameters expected by the node's version, and requests which the
+/// node's version can't serve fail with [`Error::UnsupportedByNodeVersion`] without being sent.
+/// The version is detected once, via `info_get_status`, the first time it is needed (see
+/// [`rpc_api_version`](Self::rpc_api_version)).
+///
+/// Only the result of [`get_node_status`](Self::get_node_status) is parsed according to the
+/// node's version.  The results of all other requests are parsed as for v1.6.x nodes, and if one
+/// from an older node fails to parse, the request fails with [`Error::UnsupportedByNodeVersion`].
+#[derive(Debug)]
+pub struct CasperClient {
+    node_address: String,
+    rpc_id_strategy: RpcIdStrategy,
+    next_rpc_id: AtomicI64,
+    verbosity: Verbosity,
+    transport: Arc<dyn Transport>,
+    rpc_api_version: Mutex<Option<RpcApiVersion>>,
+}
+
+impl CasperClient {
+    /// Returns a new `CasperClient` with default settings which sends requests to the node at
+    /// `node_address`.
+    pub fn new<A: Into<String>>(node_address: A) -> Self {
+        CasperClientBuilder::new(node_address).into_client(Arc::new(HttpTransport::default()))
+    }
+
+    /// Returns a new `CasperClientBuilder` for a client which sends requests to the node at
+    /// `node_address`.
+    pub fn builder<A: Into<String>>(node_address: A) -> CasperClientBuilder {
+        CasperClientBuilder::new(node_address)
+    }
+
+    /// Returns the address of the node to which requests are sent.
+    pub fn node_address(&self) -> &str {
+        &self.node_address
+    }
+
+    /// Returns the level of detail of the requests and responses emitted as `tracing` events.
+    pub fn verbosity(&self) -> Verbosity {
+        self.verbosity
+    }
+
+    /// Returns the transport used to send requests.
+    pub fn transport(&self) -> &dyn Transport {
+        self.transport.as_ref()
+    }
+
+    /// Returns the JSON-RPC identifier to be used for the next request.
+    pub fn next_rpc_id(&self) -> JsonRpcId {
+        match &self.rpc_id_strategy {
+            RpcIdStrategy::Random => JsonRpcId::from(rand::thread_rng().gen::<i64>()),
+            RpcIdStrategy::Sequential(_) => {
+                JsonRpcId::from(self.next_rpc_id.fetch_add(1, Ordering::Relaxed))
+            }
+            RpcIdStrategy::Fixed(rpc_id) => rpc_id.clone(),
+        }
+    }
+
+    /// Returns the JSON-RPC API version of the node.
+    ///
+    /// Unless provided via [`CasperClientBuilder::with_rpc_api_version`], this is detected by
+    /// sending an `info_get_status` request the first time it is called, and cached thereafter.
+    /// Returns [`Error::UnsupportedNodeVersion`] if the node's version isn't supported.
+    pub async fn rpc_api_version(&self) -> Result<RpcApiVersion, Error> {
+        let maybe_rpc_api_version = *json_rpc::lock(&self.rpc_api_version);
+        if let Some(rpc_api_version) = maybe_rpc_api_version {
+            return Ok(rpc_api_version);
+        }
+        let (_, rpc_api_version) = self.get_raw_node_status().await?;
+        Ok(rpc_api_version)
+    }
+
+    /// Puts a [`Deploy`] to the network for execution.
+    ///
+    /// See [`put_deploy`](crate::put_deploy).
+    pub async fn put_deploy(
+        &self,
+        deploy: Deploy,
+    ) -> Result<SuccessResponse<PutDeployResult>, Error> {
+        let result = crate::put_deploy_with_transport(
+            self.transport(),
+            self.next_rpc_id(),
+            &self.node_address,
+            self.verbosity,
+            deploy,
+        )
+        .await;
+        self.check_result(result).await
+    }
+
+    /// Puts a [`Deploy`] to the node for speculative execution on that node only.
+    ///
+    /// See [`speculative_exec`](crate::speculative_exec).
+    pub async fn speculative_exec(
+        &self,
+        block_identifier: Option<BlockIdentifier>,
+        deploy: Deploy,
+    ) -> Result<SuccessResponse<SpeculativeExecResult>, Error> {
+        self.rpc_api_version()
+            .await?
+            .ensure_supports(SPECULATIVE_EXEC_METHOD)?;
+        let result = crate::speculative_exec_with_transport(
+            self.transport(),
+            self.next_rpc_id(),
+            &self.node_address,
+            block_identifier,
+            self.verbosity,
+            deploy,
+        )
+        .await;
+        self.check_result(result).await
+    }
+
+    /// Retrieves a [`Deploy`] and its metadata (i.e. execution results) from the network.
+    ///
+    /// See [`get_deploy`](crate::get_deploy).
+    pub async fn get_deploy(
+        &self,
+        deploy_hash: DeployHash,
+        finalized_approvals: bool,
+    ) -> Result<SuccessResponse<GetDeployResult>, Error> {
+        let result = crate::get_deploy_with_transport(
+            self.transport(),
+            self.next_rpc_id(),
+            &self.node_address,
+            self.verbosity,
+            deploy_hash,
+            finalized_approvals,
+        )
+        .await;
+        self.check_result(result).await
+    }
+
+    /// Retrieves a [`Block`] from the network.
+    ///
+    /// See [`get_block`](crate::get_block).
+    pub async fn get_block(
+        &self,
+        maybe_block_identifier: Option<BlockIdentifier>,
+    ) -> Result<SuccessResponse<GetBlockResult>, Error> {
+        let result = crate::get_block_with_transport(
+            self.transport(),
+            self.next_rpc_id(),
+            &self.node_address,
+            self.verbosity,
+            maybe_block_identifier,
+        )
+        .await;
+        self.check_result(result).await
+    }
+
+    /// Retrieves all [`Transfer`] items for a given [`Block`].
+    ///
+    /// See [`get_block_transfers`](crate::get_block_transfers).
+    pub async fn get_block_transfers(
+        &self,
+        maybe_block_identifier: Option<BlockIdentifier>,
+    ) -> Result<SuccessResponse<GetBlockTransfersResult>, Error> {
+        let result = crate::get_block_transfers_with_transport(
+            self.transport(),
+            self.next_rpc_id(),
+            &self.node_address,
+            self.verbosity,
+            maybe_block_identifier,
+        )
+        .await;
+        self.check_result(result).await
+    }
+
+    /// Retrieves a state root hash at a given [`Block`].
+    ///
+    /// See [`get_state_root_hash`](crate::get_state_root_hash).
+    pub async fn get_state_root_hash(
+        &self,
+        maybe_block_identifier: Option<BlockIdentifier>,
+    ) -> Result<SuccessResponse<GetStateRootHashResult>, Error> {
+        let result = crate::get_state_root_hash_with_transport(
+            self.transport(),
+            self.next_rpc_id(),
+            &self.node_address,
+            self.verbosity,
+            maybe_block_identifier,
+        )
+        .await;
+        self.check_result(result).await
+    }
+
+    /// Retrieves era information from the network at a given [`Block`].
+    ///
+    /// See [`get_era_summary`](crate::get_era_summary).
+    pub async fn get_era_summary(
+        &self,
+        maybe_block_identifier: Option<BlockIdentifier>,
+    ) -> Result<SuccessResponse<GetEraSummaryResult>, Error> {
+        self.rpc_api_version()
+            .await?
+            .ensure_supports(GET_ERA_SUMMARY_METHOD)?;
+        let result = crate::get_era_summary_with_transport(
+            self.transport(),
+            self.next_rpc_id(),
+            &self.node_address,
+            self.verbosity,
+            maybe_block_identifier,
+        )
+        .await;
+        self.check_result(result).await
+    }
+
+    /// Retrieves era information from the network at a given switch [`Block`].
+    ///
+    /// See [`get_era_info`](crate::get_era_info).
+    #[deprecated(
+        since = "2.0.0",
+        note = "prefer 'get_era_summary' as it doesn't require a switch block"
+    )]
+    pub async fn get_era_info(
+        &self,
+        maybe_block_identifier: Option<BlockIdentifier>,
+    ) -> Result<SuccessResponse<GetEraInfoResult>, Error> {
+        #[allow(deprecated)]
+        let result = crate::get_era_info_with_transport(
+            self.transport(),
+            self.next_rpc_id(),
+            &self.node_address,
+            self.verbosity,
+            maybe_block_identifier,
+        )
+        .await;
+        self.check_result(result).await
+    }
+
+    /// Retrieves a [`StoredValue`] from global state at a given [`Block`] or state root hash.
+    ///
+    /// Nodes prior to v1.6.x require `global_state_identifier` to be provided.
+    ///
+    /// See [`query_global_state`](crate::query_global_state).
+    pub async fn query_global_state(
+        &self,
+        global_state_identifier: Option<GlobalStateIdentifier>,
+        key: Key,
+        path: Vec<String>,
+    ) -> Result<SuccessResponse<QueryGlobalStateResult>, Error> {
+        let rpc_api_version = self.rpc_api_version().await?;
+        if rpc_api_version < RpcApiVersion::V1_6_0 {
+            let global_state_identifier =
+                global_state_identifier.ok_or(Error::UnsupportedByNodeVersion {
+                    rpc_method: QUERY_GLOBAL_STATE_METHOD,
+                    rpc_api_version,
+                    context: "a global state identifier is required",
+                })?;
+            let params = QueryGlobalStateParamsV1_4_5::new(global_state_identifier, key, path);
+            let result = self
+                .call()
+                .send_request(QUERY_GLOBAL_STATE_METHOD, Some(params))
+                .await;
+            return self.check_result(result).await;
+        }
+        let result = crate::query_global_state_with_transport(
+            self.transport(),
+            self.next_rpc_id(),
+            &self.node_address,
+            self.verbosity,
+            global_state_identifier,
+            key,
+            path,
+        )
+        .await;
+        self.check_result(result).await
+    }
+
+    /// Retrieves a purse's balance from global state at a given [`Block`] or state root hash.
+    ///
+    /// See [`query_balance`](crate::query_balance).
+    pub async fn query_balance(
+        &self,
+        maybe_global_state_identifier: Option<GlobalStateIdentifier>,
+        purse_identifier: PurseIdentifier,
+    ) -> Result<SuccessResponse<QueryBalanceResult>, Error> {
+        self.rpc_api_version()
+            .await?
+            .ensure_supports(QUERY_BALANCE_METHOD)?;
+        let result = crate::query_balance_with_transport(
+            self.transport(),
+            self.next_rpc_id(),
+            &self.node_address,
+            self.verbosity,
+            maybe_global_state_identifier,
+            purse_identifier,
+        )
+        .await;
+        self.check_result(result).await
+    }
+
+    /// Retrieves a [`StoredValue`] from a dictionary at a given state root hash.
+    ///
+    /// See [`get_dictionary_item`](crate::get_dictionary_item).
+    pub async fn get_dictionary_item(
+        &self,
+        state_root_hash: Digest,
+        dictionary_item_identifier: DictionaryItemIdentifier,
+    ) -> Result<SuccessResponse<GetDictionaryItemResult>, Error> {
+        let result = crate::get_dictionary_item_with_transport(
+            self.transport(),
+            self.next_rpc_id(),
+            &self.node_address,
+            self.verbosity,
+            state_root_hash,
+            dictionary_item_identifier,
+        )
+        .await;
+        self.check_result(result).await
+    }
+
+    /// Retrieves a purse's balance at a given state root hash.
+    ///
+    /// See [`get_balance`](crate::get_balance).
+    pub async fn get_balance(
+        &self,
+        state_root_hash: Digest,
+        purse: URef,
+    ) -> Result<SuccessResponse<GetBalanceResult>, Error> {
+        let result = crate::get_balance_with_transport(
+            self.transport(),
+            self.next_rpc_id(),
+            &self.node_address,
+            self.verbosity,
+            state_root_hash,
+            purse,
+        )
+        .await;
+        self.check_result(result).await
+    }
+
+    /// Retrieves an [`Account`] at a given [`Block`].
+    ///
+    /// Nodes prior to v1.6.x require `account_identifier` to be a public key.
+    ///
+    /// See [`get_account`](crate::get_account).
+    pub async fn get_account(
+        &self,
+        maybe_block_identifier: Option<BlockIdentifier>,
+        account_identifier: AccountIdentifier,
+    ) -> Result<SuccessResponse<GetAccountResult>, Error> {
+        let rpc_api_version = self.rpc_api_version().await?;
+        if rpc_api_version < RpcApiVersion::V1_6_0 {
+            let public_key = match account_identifier {
+                AccountIdentifier::PublicKey(public_key) => public_key,
+                AccountIdentifier::AccountHash(_) => {
+                    return Err(Error::UnsupportedByNodeVersion {
+                        rpc_method: GET_ACCOUNT_METHOD,
+                        rpc_api_version,
+                        context: "the account identifier must be a public key",
+                    })
+                }
+            };
+            let params = GetAccountParamsV1_4_5::new(public_key, maybe_block_identifier);
+            let result = self
+                .call()
+                .send_request(GET_ACCOUNT_METHOD, Some(params))
+                .await;
+            return self.check_result(result).await;
+        }
+        let result = crate::get_account_with_transport(
+            self.transport(),
+            self.next_rpc_id(),
+            &self.node_address,
+            self.verbosity,
+            maybe_block_identifier,
+            account_identifier,
+        )
+        .await;
+        self.check_result(result).await
+    }
+
+    /// Retrieves the bids and validators at a given [`Block`].
+    ///
+    /// See [`get_auction_info`](crate::get_auction_info).
+    pub async fn get_auction_info(
+        &self,
+        maybe_block_identifier: Option<BlockIdentifier>,
+    ) -> Result<SuccessResponse<GetAuctionInfoResult>, Error> {
+        let result = crate::get_auction_info_with_transport(
+            self.transport(),
+            self.next_rpc_id(),
+            &self.node_address,
+            self.verbosity,
+            maybe_block_identifier,
+        )
+        .await;
+        self.check_result(result).await
+    }
+
+    /// Retrieves the status changes of the active validators on the network.
+    ///
+    /// See [`get_validator_changes`](crate::get_validator_changes).
+    pub async fn get_validator_changes(
+        &self,
+    ) -> Result<SuccessResponse<GetValidatorChangesResult>, Error> {
+        let result = crate::get_validator_changes_with_transport(
+            self.transport(),
+            self.next_rpc_id(),
+            &self.node_address,
+            self.verbosity,
+        )
+        .await;
+        self.check_result(result).await
+    }
+
+    /// Retrieves the IDs and addresses of the node's peers.
+    ///
+    /// See [`get_peers`](crate::get_peers).
+    pub async fn get_peers(&self) -> Result<SuccessResponse<GetPeersResult>, Error> {
+        let result = crate::get_peers_with_transport(
+            self.transport(),
+            self.next_rpc_id(),
+            &self.node_address,
+            self.verbosity,
+        )
+        .await;
+        self.check_result(result).await
+    }
+
+    /// Retrieves the status of the node.
+    ///
+    /// The result is parsed according to the `api_version` reported by the node, which is also
+    /// cached as the node's [`rpc_api_version`](Self::rpc_api_version).
+    ///
+    /// See [`get_node_status`](crate::get_node_status).
+    pub async fn get_node_status(
+        &self,
+    ) -> Result<SuccessResponse<VersionedGetNodeStatusResult>, Error> {
+        let (response, rpc_api_version) = self.get_raw_node_status().await?;
+        let invalid_response = |source| Error::InvalidRpcResponse {
+            rpc_id: response.id.clone(),
+            rpc_method: GET_NODE_STATUS_METHOD,
+            response_kind: "Success",
+            response: response.result.clone(),
+            source: Some(source),
+        };
+        let result = match rpc_api_version {
+            RpcApiVersion::V1_4_5 => serde_json::from_value(response.result.clone())
+                .map(VersionedGetNodeStatusResult::V1_4_5),
+            RpcApiVersion::V1_5_0 | RpcApiVersion::V1_6_0 => {
+                serde_json::from_value(response.result.clone())
+                    .map(VersionedGetNodeStatusResult::V1_5_0)
+            }
+        }
+        .map_err(invalid_response)?;
+        Ok(SuccessResponse::new(response.id, result))
+    }
+
+    /// Retrieves the Chainspec of the network.
+    ///
+    /// See [`get_chainspec`](crate::get_chainspec).
+    pub async fn get_chainspec(&self) -> Result<SuccessResponse<GetChainspecResult>, Error> {
+        self.rpc_api_version()
+            .await?
+            .ensure_supports(GET_CHAINSPEC_METHOD)?;
+        let result = crate::get_chainspec_with_transport(
+            self.transport(),
+            self.next_rpc_id(),
+            &self.node_address,
+            self.verbosity,
+        )
+        .await;
+        self.check_result(result).await
+    }
+
+    /// Retrieves the interface description (the schema including examples in OpenRPC format) of
+    /// the JSON-RPC server's API.
+    ///
+    /// See [`list_rpcs`](crate::list_rpcs).
+    pub async fn list_rpcs(&self) -> Result<SuccessResponse<ListRpcsResult>, Error> {
+        let result = crate::list_rpcs_with_transport(
+            self.transport(),
+            self.next_rpc_id(),
+            &self.node_address,
+            self.verbosity,
+        )
+        .await;
+        self.check_result(result).await
+    }
+
+    /// Sends all the requests queued in `batch` to the node in a single round trip.
+    ///
+    /// IDs for the queued requests can be taken from [`next_rpc_id`](Self::next_rpc_id), but since
+    /// they must be distinct, this is unsuitable with [`RpcIdStrategy::Fixed`].
+    ///
+    /// See [`Batch::send`].
+    pub async fn send_batch(&self, batch: Batch) -> Result<BatchResponse, Error> {
+        batch
+            .send_with_transport(self.transport(), &self.node_address, self.verbosity)
+            .await
+    }
+
+    /// Sends an `info_get_status` request, returning the unparsed result along with the node's
+    /// JSON-RPC API version, which is cached.
+    async fn get_raw_node_status(&self) -> Result<(SuccessResponse<Value>, RpcApiVersion), Error> {
+        let response: SuccessResponse<Value> = self
+            .call()
+            .send_request::<(), _>(GET_NODE_STATUS_METHOD, None)
+            .await?;
+        let rpc_api_version =
+            RpcApiVersion::from_result(&response.id, GET_NODE_STATUS_METHOD, &response.result)?;
+        *json_rpc::lock(&self.rpc_api_version) = Some(rpc_api_version);
+        Ok((response, rpc_api_version))
+    }
+
+    /// Returns `result`, unless it's a failure to parse the result of a request to a node older
+    /// than v1.6.x, in which case returns [`Error::UnsupportedByNodeVersion`].
+    async fn check_result<T>(
+        &self,
+        result: Result<SuccessResponse<T>, Error>,
+    ) -> Result<SuccessResponse<T>, Error> {
+        let rpc_method = match &result {
+            Err(Error::InvalidRpcResponse {
+                rpc_method,
+                source: Some(_),
+                ..
+            }) => *rpc_method,
+            _ => return result,
+        };
+        match self.rpc_api_version().await {
+            Ok(rpc_api_version) if rpc_api_version < RpcApiVersion::V1_6_0 => {
+                Err(Error::UnsupportedByNodeVersion {
+                    rpc_method,
+                    rpc_api_version,
+                    context: "the result is only supported in its v1.6.x format",
+                })
+            }
+            _ => result,
+        }
+    }
+
+    /// Returns a new call to the node, using the next JSON-RPC ID.
+    fn call(&self) -> JsonRpcCall<'_> {
+        JsonRpcCall::new(
+            self.transport(),
+            self.next_rpc_id(),
+            &self.node_address,
+            self.verbosity,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "tokio")]
+    use std::sync::atomic::AtomicUsize;
+
+    #[cfg(feature = "tokio")]
+    use async_trait::async_trait;
+    #[cfg(feature = "tokio")]
+    use jsonrpc_lite::JsonRpc;
+    #[cfg(feature = "tokio")]
+    use serde_json::json;
+
+    #[cfg(feature = "tokio")]
+    use casper_types::account::AccountHash;
+
+    #[cfg(feature = "tokio")]
+    use crate::rpcs::v1_6_0::get_peers::GET_PEERS_METHOD;
+
+    use super::*;
+
+    /// A transport which responds to `info_get_status` as a v1.4.x node, counting the requests, and
+    /// to `info_get_peers` with a result which doesn't parse as the latest version's.
+    #[cfg(feature = "tokio")]
+    #[derive(Default, Debug)]
+    struct V1_4Transport {
+        status_requests: AtomicUsize,
+    }
+
+    #[cfg(feature = "tokio")]
+    #[async_trait]
+    impl Transport for V1_4Transport {
+        async fn send(
+            &self,
+            _url: &str,
+            rpc_id: &JsonRpcId,
+            rpc_method: &'static str,
+            _request: &JsonRpc,
+        ) -> Result<JsonRpc, Error> {
+            if rpc_method == GET_PEERS_METHOD {
+                return Ok(JsonRpc::success(
+                    rpc_id,
+                    &json!({ "api_version": "1.4.15", "peers": "none" }),
+                ));
+            }
+            assert_eq!(rpc_method, GET_NODE_STATUS_METHOD);
+            let _ = self.status_requests.fetch_add(1, Ordering::SeqCst);
+            Ok(JsonRpc::success(
+                rpc_id,
+                &json!({
+                    "api_version": "1.4.15",
+                    "chainspec_name": "casper-test",
+                    "starting_state_root_hash": "00".repeat(32),
+                    "peers": [],
+                    "last_added_block_info": null,
+                    "our_public_signing_key": null,
+                    "round_length": null,
+                    "next_upgrade": null,
+                    "build_version": "1.4.15-039d438f2",
+                    "uptime": "1h"
+                }),
+            ))
+        }
+    }
+
+    #[test]
+    fn should_assign_rpc_ids_per_strategy() {
+        let client = CasperClient::builder("http://localhost:7777")
+            .with_rpc_id_strategy(RpcIdStrategy::Sequential(10))
+            .build()
+            .expect("should build");
+        assert_eq!(client.next_rpc_id(), JsonRpcId::from(10));
+        assert_eq!(client.next_rpc_id(), JsonRpcId::from(11));
+
+        let fixed_id = JsonRpcId::from("fixed".to_string());
+        let client = CasperClient::builder("http://localhost:7777")
+            .with_rpc_id_strategy(RpcIdStrategy::Fixed(fixed_id.clone()))
+            .build()
+            .expect("should build");
+        assert_eq!(client.next_rpc_id(), fixed_id);
+        assert_eq!(client.next_rpc_id(), fixed_id);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn should_adapt_to_node_version() {
+        let transport = Arc::new(V1_4Transport::default());
+        let client = CasperClient::builder("http://localhost:7777")
+            .with_transport(transport.clone())
+            .build()
+            .expect("should build");
+
+        let status = client.get_node_status().await.unwrap();
+        assert!(matches!(
+            status.result,
+            VersionedGetNodeStatusResult::V1_4_5(_)
+        ));
+        assert_eq!(status.result.chainspec_name(), "casper-test");
+        assert_eq!(
+            client.rpc_api_version().await.unwrap(),
+            RpcApiVersion::V1_4_5
+        );
+
+        // Neither request should be sent, and the version should have been cached.
+        let error = client.get_chainspec().await.unwrap_err();
+        assert!(matches!(
+            error,
+            Error::UnsupportedByNodeVersion {
+                rpc_method: GET_CHAINSPEC_METHOD,
+                rpc_api_version: RpcApiVersion::V1_4_5,
+                ..
+            }
+        ));
+        let account_identifier = AccountIdentifier::AccountHash(AccountHash::new([1; 32]));
+        let error = client
+            .get_account(None, account_identifier)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::UnsupportedByNodeVersion {
+                rpc_method: GET_ACCOUNT_METHOD,
+                ..
+            }
+        ));
+
+        // A result which fails to parse is reported as unsupported by the node's version.
+        let error = client.get_peers().await.unwrap_err();
+        assert!(matches!(
+            error,
+            Error::UnsupportedByNodeVersion {
+                rpc_method: GET_PEERS_METHOD,
+                rpc_api_version: RpcApiVersion::V1_4_5,
+                ..
+            }
+        ));
+        assert_eq!(transport.status_requests.load(Ordering::SeqCst), 1);
+    }
+}
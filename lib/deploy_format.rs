@@ -0,0 +1,34 @@
+use std::ops::RangeInclusive;
+
+#[cfg(doc)]
+use crate::types::Deploy;
+
+/// The tag bytes of the system, Ed25519 and secp256k1 public keys respectively.
+const PUBLIC_KEY_TAGS: RangeInclusive<u8> = 0..=2;
+
+/// The encoding of a [`Deploy`] written to a file or stdout.
+///
+/// When reading a deploy, the format is detected automatically.
+#[derive(Copy, Clone, Default, Eq, PartialEq, Debug)]
+pub enum DeployFormat {
+    /// Pretty-printed JSON.
+    #[default]
+    Json,
+    /// The `bytesrepr` encoding, i.e. the same bytes which are hashed and signed, and which are
+    /// much smaller than the JSON encoding for deploys with large session code.
+    Binary,
+}
+
+impl DeployFormat {
+    /// Returns the format of the encoded deploy in `bytes`.
+    ///
+    /// A binary deploy starts with the tag byte of its account's public key, so anything not
+    /// starting with a valid tag is treated as JSON, in order that malformed JSON is reported as
+    /// such.
+    pub(crate) fn detect(bytes: &[u8]) -> Self {
+        match bytes.first() {
+            Some(byte) if PUBLIC_KEY_TAGS.contains(byte) => DeployFormat::Binary,
+            _ => DeployFormat::Json,
+        }
+    }
+}
@@ -0,0 +1,259 @@
+use serde::Serialize;
+use serde_json::Value;
+
+use casper_types::{account::AccountHash, CLType, ContractVersion, PublicKey, RuntimeArgs};
+
+use crate::{
+    cli,
+    types::{Deploy, DeployHash, ExecutableDeployItem, TimeDiff, Timestamp},
+};
+
+/// A named runtime arg of a [`Deploy`], decoded from its `CLValue`.
+#[derive(Clone, PartialEq, Serialize, Debug)]
+pub struct InspectedArg {
+    /// The name of the arg.
+    pub name: String,
+    /// The type of the arg.
+    #[serde(rename = "type")]
+    pub cl_type: CLType,
+    /// The value of the arg as JSON, in the form accepted by [`JsonArg`](cli::JsonArg), or `None`
+    /// if it could not be decoded, e.g. since its type is `CLType::Any`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<Value>,
+    /// The hex-encoded bytes of the value, only provided if the value could not be decoded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bytes: Option<String>,
+}
+
+/// The payment or session code of a [`Deploy`], with its args decoded.
+#[derive(Clone, PartialEq, Serialize, Debug)]
+pub struct InspectedDeployItem {
+    /// The name of the [`ExecutableDeployItem`] variant, e.g. "StoredContractByHash".
+    pub variant: &'static str,
+    /// The number of bytes of compiled Wasm, for `ModuleBytes`.  For payment code, zero bytes
+    /// means the standard payment is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub module_bytes_length: Option<usize>,
+    /// The hex-encoded hash of the stored contract or contract package.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    /// The name of the named key under which the contract or contract package is referenced.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The version of the stored contract package to call, for the versioned variants, where
+    /// `Some(None)` means the highest enabled version.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<Option<ContractVersion>>,
+    /// The entry point to be called, or `None` for a `Transfer`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entry_point: Option<String>,
+    /// The decoded args.
+    pub args: Vec<InspectedArg>,
+}
+
+impl InspectedDeployItem {
+    fn new(item: &ExecutableDeployItem) -> Self {
+        let mut inspected = InspectedDeployItem {
+            variant: "",
+            module_bytes_length: None,
+            hash: None,
+            name: None,
+            version: None,
+            entry_point: None,
+            args: inspect_args(item.args()),
+        };
+        match item {
+            ExecutableDeployItem::ModuleBytes { module_bytes, .. } => {
+                inspected.variant = "ModuleBytes";
+                inspected.module_bytes_length = Some(module_bytes.len());
+                inspected.entry_point = Some("call".to_string());
+            }
+            ExecutableDeployItem::StoredContractByHash {
+                hash, entry_point, ..
+            } => {
+                inspected.variant = "StoredContractByHash";
+                inspected.hash = Some(base16::encode_lower(hash.value()));
+                inspected.entry_point = Some(entry_point.clone());
+            }
+            ExecutableDeployItem::StoredContractByName {
+                name, entry_point, ..
+            } => {
+                inspected.variant = "StoredContractByName";
+                inspected.name = Some(name.clone());
+                inspected.entry_point = Some(entry_point.clone());
+            }
+            ExecutableDeployItem::StoredVersionedContractByHash {
+                hash,
+                version,
+                entry_point,
+                ..
+            } => {
+                inspected.variant = "StoredVersionedContractByHash";
+                inspected.hash = Some(base16::encode_lower(hash.value()));
+                inspected.version = Some(*version);
+                inspected.entry_point = Some(entry_point.clone());
+            }
+            ExecutableDeployItem::StoredVersionedContractByName {
+                name,
+                version,
+                entry_point,
+                ..
+            } => {
+                inspected.variant = "StoredVersionedContractByName";
+                inspected.name = Some(name.clone());
+                inspected.version = Some(*version);
+                inspected.entry_point = Some(entry_point.clone());
+            }
+            ExecutableDeployItem::Transfer { .. } => inspected.variant = "Transfer",
+        }
+        inspected
+    }
+}
+
+/// A signer of a [`Deploy`] and the account hash of its public key.
+#[derive(Clone, PartialEq, Eq, Serialize, Debug)]
+pub struct InspectedApproval {
+    /// The public key of the signer.
+    pub signer: PublicKey,
+    /// The account hash of the signer's public key.
+    pub account_hash: AccountHash,
+}
+
+/// A human-readable view of a [`Deploy`], for checking what it does before signing or sending it.
+#[derive(Clone, PartialEq, Serialize, Debug)]
+pub struct DeployInspection {
+    /// The hash of the deploy.
+    pub deploy_hash: DeployHash,
+    /// The public key of the deploy's account.
+    pub account: PublicKey,
+    /// The account hash of the deploy's account.
+    pub account_hash: AccountHash,
+    /// The deploy creation timestamp.
+    pub timestamp: Timestamp,
+    /// The duration for which the deploy will stay valid.
+    pub ttl: TimeDiff,
+    /// The timestamp after which the deploy can no longer be included in a block.
+    pub expires: Timestamp,
+    /// The price per gas unit.
+    pub gas_price: u64,
+    /// The deploys which have to be run before this one.
+    pub dependencies: Vec<DeployHash>,
+    /// The name of the chain on which the deploy is supposed to be run.
+    pub chain_name: String,
+    /// The payment code.
+    pub payment: InspectedDeployItem,
+    /// The session code.
+    pub session: InspectedDeployItem,
+    /// The approvals.
+    pub approvals: Vec<InspectedApproval>,
+}
+
+impl DeployInspection {
+    /// Returns a human-readable view of `deploy`, with its runtime args decoded from their
+    /// `CLValue`s.
+    pub fn new(deploy: &Deploy) -> Self {
+        let header = deploy.header();
+        DeployInspection {
+            deploy_hash: *deploy.id(),
+            account: header.account().clone(),
+            account_hash: header.account().to_account_hash(),
+            timestamp: header.timestamp(),
+            ttl: header.ttl(),
+            expires: header.expires(),
+            gas_price: header.gas_price(),
+            dependencies: header.dependencies().copied().collect(),
+            chain_name: header.chain_name().to_string(),
+            payment: InspectedDeployItem::new(deploy.payment()),
+            session: InspectedDeployItem::new(deploy.session()),
+            approvals: deploy
+                .approvals()
+                .iter()
+                .map(|approval| InspectedApproval {
+                    signer: approval.signer().clone(),
+                    account_hash: approval.signer().to_account_hash(),
+                })
+                .collect(),
+        }
+    }
+}
+
+fn inspect_args(args: &RuntimeArgs) -> Vec<InspectedArg> {
+    args.named_args()
+        .map(|named_arg| {
+            let cl_value = named_arg.cl_value();
+            let value = cli::cl_value_to_json(cl_value);
+            let bytes = match value {
+                Some(_) => None,
+                None => Some(base16::encode_lower(cl_value.inner_bytes())),
+            };
+            InspectedArg {
+                name: named_arg.name().to_string(),
+                cl_type: cl_value.cl_type().clone(),
+                value,
+                bytes,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use casper_types::{runtime_args, CLValue, SecretKey, URef, U512};
+
+    use super::*;
+    use crate::types::DeployBuilder;
+
+    #[test]
+    fn should_decode_runtime_args() {
+        let secret_key = SecretKey::ed25519_from_bytes([1; 32]).unwrap();
+        let args = runtime_args! {
+            "amount" => U512::from(10_u64),
+            "purse" => Option::<URef>::None,
+            "names" => vec!["a".to_string(), "b".to_string()],
+        };
+        let session = ExecutableDeployItem::new_stored_versioned_contract_by_name(
+            "package".to_string(),
+            None,
+            "do_something".to_string(),
+            args,
+        );
+        let mut deploy = DeployBuilder::new("casper-test", session)
+            .with_standard_payment(100_000_000_u64)
+            .with_secret_key(&secret_key)
+            .build()
+            .unwrap();
+        let mut args = RuntimeArgs::new();
+        args.insert_cl_value("opaque", CLValue::from_components(CLType::Any, vec![1, 2]));
+        deploy.payment = ExecutableDeployItem::new_module_bytes(vec![0; 4].into(), args);
+
+        let inspection = DeployInspection::new(&deploy);
+        assert_eq!(inspection.deploy_hash, *deploy.id());
+        assert_eq!(
+            inspection.approvals[0].account_hash,
+            PublicKey::from(&secret_key).to_account_hash()
+        );
+
+        let session = serde_json::to_value(&inspection.session).unwrap();
+        assert_eq!(
+            session,
+            json!({
+                "variant": "StoredVersionedContractByName",
+                "name": "package",
+                "version": null,
+                "entry_point": "do_something",
+                "args": [
+                    { "name": "amount", "type": "U512", "value": "10" },
+                    { "name": "purse", "type": { "Option": "URef" }, "value": null },
+                    { "name": "names", "type": { "List": "String" }, "value": ["a", "b"] }
+                ]
+            })
+        );
+
+        let payment = &inspection.payment;
+        assert_eq!(payment.module_bytes_length, Some(4));
+        assert_eq!(payment.args[0].value, None);
+        assert_eq!(payment.args[0].bytes.as_deref(), Some("0102"));
+    }
+}
@@ -1,15 +1,31 @@
+use std::error::Error as StdError;
 #[cfg(feature = "std-fs-io")]
 use std::{io, path::PathBuf};
 
 use thiserror::Error;
 
-use casper_types::{bytesrepr::Error as ToBytesError, crypto, Key};
+use casper_hashing::Digest;
+use casper_types::{
+    bytesrepr::Error as ToBytesError, crypto, Key, ProtocolVersion, PublicKey, U512,
+};
 #[cfg(doc)]
 use casper_types::{CLValue, URef};
 
+#[cfg(all(doc, feature = "std-fs-io"))]
+use crate::keystore::Keystore;
+#[cfg(feature = "tokio")]
+use crate::types::Timestamp;
+use crate::{
+    rpcs::RpcApiVersion,
+    types::{DeployHash, TimeDiff},
+    validation::ValidateResponseError,
+    JsonRpcId,
+};
 #[cfg(doc)]
-use crate::types::{Deploy, DeployBuilder, TimeDiff, Timestamp};
-use crate::{validation::ValidateResponseError, JsonRpcId};
+use crate::{
+    types::{Deploy, DeployBuilder},
+    ExternalSigner, ReplayTransport, Transport,
+};
 
 /// Errors that may be returned by `casper_client` functions.
 #[derive(Error, Debug)]
@@ -43,6 +59,134 @@ pub enum Error {
     #[error("deploy requires payment code - use `with_payment` or `with_standard_payment`")]
     DeployMissingPaymentCode,
 
+    /// The body hash in the [`Deploy`]'s header is not the hash of its payment and session code.
+    #[error(
+        "deploy {deploy_hash} has incorrect body hash: header has {body_hash} but actual body hash \
+        is {actual_body_hash}"
+    )]
+    DeployBodyHashMismatch {
+        /// The hash of the deploy.
+        deploy_hash: DeployHash,
+        /// The body hash in the deploy's header.
+        body_hash: Digest,
+        /// The actual hash of the deploy's payment and session code.
+        actual_body_hash: Digest,
+    },
+
+    /// The [`Deploy`]'s hash is not the hash of its header.
+    #[error("deploy {deploy_hash} has incorrect hash: actual hash is {actual_deploy_hash}")]
+    DeployHashMismatch {
+        /// The hash of the deploy.
+        deploy_hash: DeployHash,
+        /// The actual hash of the deploy's header.
+        actual_deploy_hash: DeployHash,
+    },
+
+    /// An approval of the [`Deploy`] has a signature which is invalid for the given signer.
+    #[error("deploy {deploy_hash} has invalid approval from {signer}: {error}")]
+    DeployInvalidApproval {
+        /// The hash of the deploy.
+        deploy_hash: DeployHash,
+        /// The public key of the signer of the invalid approval.
+        signer: PublicKey,
+        /// The reported error.
+        error: crypto::Error,
+    },
+
+    /// The [`Deploy`] is for a different chain than expected.
+    #[error(
+        "deploy {deploy_hash} is for chain '{actual_chain_name}' but expected \
+        '{expected_chain_name}'"
+    )]
+    DeployChainNameMismatch {
+        /// The hash of the deploy.
+        deploy_hash: DeployHash,
+        /// The expected chain name.
+        expected_chain_name: String,
+        /// The chain name in the deploy's header.
+        actual_chain_name: String,
+    },
+
+    /// The [`Deploy`]'s time-to-live exceeds the maximum permitted.
+    #[error("deploy {deploy_hash} has ttl of {ttl} which exceeds limit of {max_ttl}")]
+    DeployExcessiveTtl {
+        /// The hash of the deploy.
+        deploy_hash: DeployHash,
+        /// The maximum permitted time-to-live.
+        max_ttl: TimeDiff,
+        /// The time-to-live of the deploy.
+        ttl: TimeDiff,
+    },
+
+    /// The [`Deploy`] has more dependencies than the maximum permitted.
+    #[error(
+        "deploy {deploy_hash} has {dependency_count} dependencies which exceeds limit of \
+        {max_dependencies}"
+    )]
+    DeployExcessiveDependencies {
+        /// The hash of the deploy.
+        deploy_hash: DeployHash,
+        /// The maximum permitted number of dependencies.
+        max_dependencies: u8,
+        /// The number of dependencies of the deploy.
+        dependency_count: usize,
+    },
+
+    /// The [`Deploy`]'s standard payment buys more gas than can be used in a block.
+    #[error(
+        "deploy {deploy_hash} has payment for {gas} gas which exceeds block gas limit of \
+        {block_gas_limit}"
+    )]
+    DeployExceedsBlockGasLimit {
+        /// The hash of the deploy.
+        deploy_hash: DeployHash,
+        /// The maximum amount of gas which can be used in a block.
+        block_gas_limit: u64,
+        /// The amount of gas paid for by the deploy.
+        gas: U512,
+    },
+
+    /// Approvals could not be merged as the [`Deploy`]s are not copies of the same deploy.
+    #[error("can't merge approvals of deploy {other_deploy_hash} into deploy {deploy_hash}")]
+    MismatchedDeploys {
+        /// The hash of the deploy into which approvals were to be merged.
+        deploy_hash: DeployHash,
+        /// The hash of the deploy from which approvals were to be merged.
+        other_deploy_hash: DeployHash,
+    },
+
+    /// No [`Deploy`]s were provided to be merged.
+    #[error("no deploys to merge")]
+    NoDeploysToMerge,
+
+    /// The speculative execution of a [`Deploy`] failed, so its cost could not be used to set its
+    /// payment amount.
+    #[error("speculative execution of deploy {deploy_hash} failed: {error_message}")]
+    SpeculativeExecutionFailed {
+        /// The hash of the speculatively executed deploy.
+        deploy_hash: DeployHash,
+        /// The error message reported by the node.
+        error_message: String,
+    },
+
+    /// Failed to run the command of an [`ExternalSigner`], or to communicate with its process.
+    #[error("failed to run signer command '{command}': {error}")]
+    FailedToRunSignerCommand {
+        /// The signer command.
+        command: String,
+        /// The underlying error.
+        error: std::io::Error,
+    },
+
+    /// The command of an [`ExternalSigner`] exited unsuccessfully or gave an invalid response.
+    #[error("signer command '{command}' failed: {error}")]
+    SignerCommandFailed {
+        /// The signer command.
+        command: String,
+        /// Description of the failure.
+        error: String,
+    },
+
     /// Invalid [`Key`] variant.
     #[error("expected {} but got {}", .expected_variant, .actual)]
     InvalidKeyVariant {
@@ -74,6 +218,125 @@ pub enum Error {
         error: reqwest::Error,
     },
 
+    /// Failed to send the request or receive the response via a custom [`Transport`].
+    #[error("transport failure for rpc-id {rpc_id} {rpc_method}: {error}")]
+    TransportFailure {
+        /// The JSON-RPC ID.
+        rpc_id: JsonRpcId,
+        /// The JSON-RPC request method.
+        rpc_method: &'static str,
+        /// The reported error.
+        error: Box<dyn StdError + Send + Sync>,
+    },
+
+    /// Invalid HTTP client configuration.
+    #[error("invalid http client configuration: {context}: {error}")]
+    InvalidHttpConfig {
+        /// Contextual description of the invalid setting.
+        context: String,
+        /// The reported error.
+        error: Box<dyn StdError + Send + Sync>,
+    },
+
+    /// A node pool was constructed without any node addresses.
+    #[error("node pool requires at least one node address")]
+    EmptyNodePool,
+
+    /// Two requests in a batch have the same JSON-RPC ID.
+    #[error("batch contains more than one request with rpc-id {rpc_id}")]
+    DuplicateBatchRpcId {
+        /// The duplicated JSON-RPC ID.
+        rpc_id: JsonRpcId,
+    },
+
+    /// The node's response to a batch did not include a response to one of its requests.
+    #[error("batch response has no response for rpc-id {rpc_id} {rpc_method}")]
+    MissingBatchResponse {
+        /// The JSON-RPC ID.
+        rpc_id: JsonRpcId,
+        /// The JSON-RPC request method.
+        rpc_method: &'static str,
+    },
+
+    /// Failed to start the runtime used to drive requests made via the blocking API.
+    #[cfg(feature = "blocking")]
+    #[error("failed to start runtime for blocking api: {error}")]
+    FailedToStartRuntime {
+        /// The reported error.
+        error: std::io::Error,
+    },
+
+    /// A [`ReplayTransport`] has no recorded response matching a request.
+    #[error("no recorded response for rpc-id {rpc_id} {rpc_method}")]
+    NoRecordedResponse {
+        /// The JSON-RPC ID.
+        rpc_id: JsonRpcId,
+        /// The JSON-RPC request method.
+        rpc_method: &'static str,
+    },
+
+    /// Failed to connect to a node's event stream.
+    #[cfg(feature = "tokio")]
+    #[error("failed to connect to event stream at {url}: {error}")]
+    FailedToConnectToEventStream {
+        /// The URL of the event stream.
+        url: String,
+        /// The reported error.
+        error: reqwest::Error,
+    },
+
+    /// A node's event stream was interrupted, and could not be resumed.
+    #[cfg(feature = "tokio")]
+    #[error("event stream at {url} was interrupted and could not be resumed")]
+    EventStreamInterrupted {
+        /// The URL of the event stream.
+        url: String,
+    },
+
+    /// A deploy was not executed within the time allowed for waiting.
+    #[cfg(feature = "tokio")]
+    #[error("deploy {deploy_hash} was not executed within {timeout}")]
+    TimedOutWaitingForDeploy {
+        /// The hash of the deploy.
+        deploy_hash: DeployHash,
+        /// The time allowed for waiting.
+        timeout: TimeDiff,
+    },
+
+    /// A deploy expired without being executed.
+    #[cfg(feature = "tokio")]
+    #[error("deploy {deploy_hash} expired at {expires} without being executed")]
+    DeployExpired {
+        /// The hash of the deploy.
+        deploy_hash: DeployHash,
+        /// The time at which the deploy expired.
+        expires: Timestamp,
+    },
+
+    /// The node's JSON-RPC API version is not supported by this client.
+    #[error(
+        "node json-rpc api version {api_version} is not supported; supported versions are 1.4.x, \
+        1.5.x and 1.6.x"
+    )]
+    UnsupportedNodeVersion {
+        /// The `api_version` reported by the node.
+        api_version: ProtocolVersion,
+    },
+
+    /// The node's JSON-RPC API version does not support the request.
+    #[error(
+        "{rpc_method} is not supported by nodes at json-rpc api version {rpc_api_version}: \
+        {context}"
+    )]
+    UnsupportedByNodeVersion {
+        /// The JSON-RPC request method.
+        rpc_method: &'static str,
+        /// The node's JSON-RPC API version.
+        rpc_api_version: RpcApiVersion,
+        /// Further context about the request which is not supported.
+        context: &'static str,
+    },
+
     /// Failed to parse the response.
     #[error("failed to parse response for rpc-id {rpc_id} {rpc_method}: {error}")]
     FailedToParseResponse {
@@ -131,6 +394,15 @@ pub enum Error {
         error: serde_json::Error,
     },
 
+    /// Failed to decode from bytes.
+    #[error("failed to decode from bytes: {context}: {error}")]
+    FailedToDecodeFromBytes {
+        /// Contextual description of where this error occurred.
+        context: &'static str,
+        /// Underlying decoding error.
+        error: ToBytesError,
+    },
+
     /// Failed to create new file because it already exists.
     #[cfg(feature = "std-fs-io")]
     #[error("file at {} already exists", .0.display())]
@@ -144,6 +416,38 @@ pub enum Error {
     #[error("unsupported keygen algorithm: {0}")]
     UnsupportedAlgorithm(String),
 
+    /// The network's chainspec could not be parsed.
+    #[error("invalid chainspec: {0}")]
+    InvalidChainspec(String),
+
+    /// The bytes of a Wasm module could not be parsed.
+    #[error("invalid Wasm module: {0}")]
+    InvalidWasm(String),
+
+    /// A Wasm module would be rejected by the network as session or payment code.
+    #[error("disallowed Wasm module: {0}")]
+    DisallowedWasm(String),
+
+    /// Invalid or unsupported [`Keystore`].
+    #[cfg(feature = "std-fs-io")]
+    #[error("invalid keystore: {0}")]
+    InvalidKeystore(String),
+
+    /// Failed to decrypt a [`Keystore`], most likely due to an incorrect password.
+    #[cfg(feature = "std-fs-io")]
+    #[error("failed to decrypt keystore: the password is incorrect or the keystore is corrupt")]
+    KeystoreDecryptionFailed,
+
+    /// The password entered for a new [`Keystore`] did not match its confirmation.
+    #[cfg(feature = "std-fs-io")]
+    #[error("keystore passwords do not match")]
+    KeystorePasswordMismatch,
+
+    /// An empty password was provided for a new [`Keystore`].
+    #[cfg(feature = "std-fs-io")]
+    #[error("keystore password must not be empty")]
+    EmptyKeystorePassword,
+
     /// Context-adding wrapper for `std::io::Error`.
     #[cfg(feature = "std-fs-io")]
     #[error("input/output error: {context}: {error}")]
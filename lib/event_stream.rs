@@ -0,0 +1,454 @@
+//! A client for the node's event stream, served via Server-Sent Events (SSE).
+
+use std::{collections::VecDeque, mem, time::Duration};
+
+use reqwest::{Client, Response};
+use serde::{Deserialize, Serialize};
+
+use casper_types::{EraId, ExecutionEffect, ProtocolVersion, PublicKey};
+
+use crate::{
+    types::{Block, BlockHash, Deploy, DeployHash, ExecutionResult, Proof, TimeDiff, Timestamp},
+    Error,
+};
+
+const DEFAULT_MAX_RECONNECTS: u32 = 5;
+const DEFAULT_RECONNECT_DELAY: Duration = Duration::from_secs(1);
+const START_FROM_QUERY: &str = "start_from";
+
+/// One of the event streams provided by a node.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum EventStreamChannel {
+    /// The `events/main` stream, providing all events other than `DeployAccepted` and
+    /// `FinalitySignature`.
+    Main,
+    /// The `events/deploys` stream, providing `DeployAccepted` events.
+    Deploys,
+    /// The `events/sigs` stream, providing `FinalitySignature` events.
+    Sigs,
+}
+
+impl EventStreamChannel {
+    /// Returns the path of the stream, relative to the node's SSE server address.
+    pub fn path(&self) -> &'static str {
+        match self {
+            EventStreamChannel::Main => "events/main",
+            EventStreamChannel::Deploys => "events/deploys",
+            EventStreamChannel::Sigs => "events/sigs",
+        }
+    }
+}
+
+/// The type of an event, i.e. the variant of its [`SseData`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum EventType {
+    /// An [`SseData::ApiVersion`] event.
+    ApiVersion,
+    /// An [`SseData::BlockAdded`] event.
+    BlockAdded,
+    /// An [`SseData::DeployAccepted`] event.
+    DeployAccepted,
+    /// An [`SseData::DeployProcessed`] event.
+    DeployProcessed,
+    /// An [`SseData::DeployExpired`] event.
+    DeployExpired,
+    /// An [`SseData::Fault`] event.
+    Fault,
+    /// An [`SseData::FinalitySignature`] event.
+    FinalitySignature,
+    /// An [`SseData::Step`] event.
+    Step,
+    /// An [`SseData::Shutdown`] event.
+    Shutdown,
+}
+
+impl EventType {
+    /// All the event types.
+    pub const ALL: [EventType; 9] = [
+        EventType::ApiVersion,
+        EventType::BlockAdded,
+        EventType::DeployAccepted,
+        EventType::DeployProcessed,
+        EventType::DeployExpired,
+        EventType::Fault,
+        EventType::FinalitySignature,
+        EventType::Step,
+        EventType::Shutdown,
+    ];
+
+    /// Returns the name of the event type, as used in the JSON-encoded event.
+    pub fn name(&self) -> &'static str {
+        match self {
+            EventType::ApiVersion => "ApiVersion",
+            EventType::BlockAdded => "BlockAdded",
+            EventType::DeployAccepted => "DeployAccepted",
+            EventType::DeployProcessed => "DeployProcessed",
+            EventType::DeployExpired => "DeployExpired",
+            EventType::Fault => "Fault",
+            EventType::FinalitySignature => "FinalitySignature",
+            EventType::Step => "Step",
+            EventType::Shutdown => "Shutdown",
+        }
+    }
+}
+
+/// The details of a block having been added to the linear chain.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct BlockAdded {
+    /// The hash of the block.
+    pub block_hash: BlockHash,
+    /// The block.
+    pub block: Box<Block>,
+}
+
+/// The details of a deploy having been executed.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct DeployProcessed {
+    /// The hash of the deploy.
+    pub deploy_hash: DeployHash,
+    /// The public key of the account which sent the deploy.
+    pub account: PublicKey,
+    /// The creation timestamp of the deploy.
+    pub timestamp: Timestamp,
+    /// The time-to-live of the deploy.
+    pub ttl: TimeDiff,
+    /// The deploys on which the deploy depends.
+    pub dependencies: Vec<DeployHash>,
+    /// The hash of the block in which the deploy was executed.
+    pub block_hash: BlockHash,
+    /// The result of executing the deploy.
+    pub execution_result: casper_types::ExecutionResult,
+}
+
+impl DeployProcessed {
+    /// Returns the execution result, along with the hash of the block in which the deploy was
+    /// executed.
+    pub fn execution_result(&self) -> ExecutionResult {
+        ExecutionResult {
+            block_hash: self.block_hash,
+            result: self.execution_result.clone(),
+        }
+    }
+}
+
+/// A validator's signature of a block, confirming it is finalized.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct FinalitySignature {
+    /// The hash of the signed block.
+    pub block_hash: BlockHash,
+    /// The era in which the block was created.
+    pub era_id: EraId,
+    /// The validator's public key and signature.
+    #[serde(flatten)]
+    pub proof: Proof,
+}
+
+/// The data of an event emitted by a node.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub enum SseData {
+    /// The version of the node's API.  This is always the first event sent on a stream.
+    ApiVersion(ProtocolVersion),
+    /// A block was added to the linear chain.
+    BlockAdded(BlockAdded),
+    /// A deploy was received by the node.
+    DeployAccepted(Box<Deploy>),
+    /// A deploy was executed.
+    DeployProcessed(Box<DeployProcessed>),
+    /// A deploy expired without being executed.
+    DeployExpired {
+        /// The hash of the deploy.
+        deploy_hash: DeployHash,
+    },
+    /// A validator was found to be faulty.
+    Fault {
+        /// The era in which the fault was detected.
+        era_id: EraId,
+        /// The public key of the faulty validator.
+        public_key: PublicKey,
+        /// The time at which the fault was detected.
+        timestamp: Timestamp,
+    },
+    /// A validator signed a block.
+    FinalitySignature(Box<FinalitySignature>),
+    /// The auction system contract was run at the end of an era.
+    Step {
+        /// The era which ended.
+        era_id: EraId,
+        /// The effect of running the auction system contract.
+        execution_effect: ExecutionEffect,
+    },
+    /// The node is shutting down.
+    Shutdown,
+}
+
+impl SseData {
+    /// Returns the type of the event.
+    pub fn event_type(&self) -> EventType {
+        match self {
+            SseData::ApiVersion(_) => EventType::ApiVersion,
+            SseData::BlockAdded(_) => EventType::BlockAdded,
+            SseData::DeployAccepted(_) => EventType::DeployAccepted,
+            SseData::DeployProcessed(_) => EventType::DeployProcessed,
+            SseData::DeployExpired { .. } => EventType::DeployExpired,
+            SseData::Fault { .. } => EventType::Fault,
+            SseData::FinalitySignature(_) => EventType::FinalitySignature,
+            SseData::Step { .. } => EventType::Step,
+            SseData::Shutdown => EventType::Shutdown,
+        }
+    }
+}
+
+/// An event received from a node's event stream.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct Event {
+    /// The ID of the event, which can be used to resume the stream from this point.  The initial
+    /// `ApiVersion` event has no ID.
+    pub id: Option<u32>,
+    /// The data of the event.
+    pub data: SseData,
+}
+
+/// A connection to one of a node's event streams.
+///
+/// The stream is connected on the first call to [`next`](Self::next).  If the connection is lost,
+/// the stream reconnects, resuming from the event following the last one received, so that no
+/// events are missed as long as the node still holds them.  After
+/// [`with_max_reconnects`](Self::with_max_reconnects) consecutive failed attempts, an error is
+/// returned.
+#[derive(Debug)]
+pub struct EventStream {
+    client: Client,
+    url: String,
+    start_from: Option<u32>,
+    max_reconnects: u32,
+    reconnect_delay: Duration,
+    failed_attempts: u32,
+    response: Option<Response>,
+    parser: SseParser,
+}
+
+impl EventStream {
+    /// Constructs a new `EventStream` for the given `channel` of the node whose SSE server is at
+    /// `node_address`, e.g. `http://127.0.0.1:9999`.
+    pub fn new(node_address: &str, channel: EventStreamChannel) -> Self {
+        EventStream {
+            client: Client::new(),
+            url: format!("{}/{}", node_address.trim_end_matches('/'), channel.path()),
+            start_from: None,
+            max_reconnects: DEFAULT_MAX_RECONNECTS,
+            reconnect_delay: DEFAULT_RECONNECT_DELAY,
+            failed_attempts: 0,
+            response: None,
+            parser: SseParser::default(),
+        }
+    }
+
+    /// Sets the `reqwest::Client` used to connect to the node.
+    pub fn with_http_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Sets the ID of the first event to be received, for resuming a stream from a previous
+    /// session.  If not provided, only events emitted after connecting are received.
+    pub fn with_start_from(mut self, event_id: u32) -> Self {
+        self.start_from = Some(event_id);
+        self
+    }
+
+    /// Sets the maximum number of consecutive failed attempts to reconnect before giving up.
+    ///
+    /// If not provided, this is 5.
+    pub fn with_max_reconnects(mut self, max_reconnects: u32) -> Self {
+        self.max_reconnects = max_reconnects;
+        self
+    }
+
+    /// Sets the delay before each attempt to reconnect.
+    ///
+    /// If not provided, this is one second.
+    pub fn with_reconnect_delay(mut self, reconnect_delay: Duration) -> Self {
+        self.reconnect_delay = reconnect_delay;
+        self
+    }
+
+    /// Returns the URL of the stream.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns the next event, waiting for one to be emitted if necessary.
+    pub async fn next(&mut self) -> Result<Event, Error> {
+        loop {
+            if let Some(event) = self.parser.next_event()? {
+                if let Some(event_id) = event.id {
+                    self.start_from = Some(event_id.saturating_add(1));
+                }
+                return Ok(event);
+            }
+
+            let chunk = match self.response.as_mut() {
+                Some(response) => response.chunk().await,
+                None => {
+                    self.connect().await?;
+                    continue;
+                }
+            };
+            match chunk {
+                Ok(Some(chunk)) => {
+                    self.failed_attempts = 0;
+                    self.parser.push(&chunk);
+                }
+                Ok(None) | Err(_) => {
+                    // Any partially-received event is discarded; it will be received again after
+                    // reconnecting.
+                    self.response = None;
+                    self.parser = SseParser::default();
+                    if self.failed_attempts >= self.max_reconnects {
+                        return Err(Error::EventStreamInterrupted {
+                            url: self.url.clone(),
+                        });
+                    }
+                    self.failed_attempts += 1;
+                    tokio::time::sleep(self.reconnect_delay).await;
+                }
+            }
+        }
+    }
+
+    async fn connect(&mut self) -> Result<(), Error> {
+        loop {
+            let mut request = self.client.get(&self.url);
+            if let Some(event_id) = self.start_from {
+                request = request.query(&[(START_FROM_QUERY, event_id)]);
+            }
+            let result = request
+                .send()
+                .await
+                .and_then(|response| response.error_for_status());
+            match result {
+                Ok(response) => {
+                    self.response = Some(response);
+                    return Ok(());
+                }
+                Err(error) => {
+                    if self.failed_attempts >= self.max_reconnects {
+                        return Err(Error::FailedToConnectToEventStream {
+                            url: self.url.clone(),
+                            error,
+                        });
+                    }
+                    self.failed_attempts += 1;
+                    tokio::time::sleep(self.reconnect_delay).await;
+                }
+            }
+        }
+    }
+}
+
+/// An incremental parser of the `text/event-stream` format.
+#[derive(Default, Debug)]
+struct SseParser {
+    /// Bytes received after the last complete line.
+    partial_line: Vec<u8>,
+    /// The `data` lines of the event being received.
+    data: Vec<String>,
+    /// The ID of the event being received.
+    id: Option<u32>,
+    /// Events received in full, as their ID and data.
+    events: VecDeque<(Option<u32>, String)>,
+}
+
+impl SseParser {
+    fn push(&mut self, bytes: &[u8]) {
+        self.partial_line.extend_from_slice(bytes);
+        while let Some(index) = self.partial_line.iter().position(|byte| *byte == b'\n') {
+            let line: Vec<u8> = self.partial_line.drain(..=index).collect();
+            let line = String::from_utf8_lossy(&line);
+            self.process_line(line.trim_end_matches(['\n', '\r']));
+        }
+    }
+
+    fn process_line(&mut self, line: &str) {
+        if line.is_empty() {
+            if !self.data.is_empty() {
+                let data = mem::take(&mut self.data).join("\n");
+                self.events.push_back((self.id.take(), data));
+            }
+            return;
+        }
+
+        let (field, value) = line.split_once(':').unwrap_or((line, ""));
+        let value = value.strip_prefix(' ').unwrap_or(value);
+        match field {
+            "data" => self.data.push(value.to_string()),
+            "id" => self.id = value.parse().ok(),
+            // Comments (i.e. keep-alives, with an empty field name) and other fields are ignored.
+            _ => (),
+        }
+    }
+
+    fn next_event(&mut self) -> Result<Option<Event>, Error> {
+        let (id, data) = match self.events.pop_front() {
+            Some(event) => event,
+            None => return Ok(None),
+        };
+        let data = serde_json::from_str(&data).map_err(|error| Error::FailedToDecodeFromJson {
+            context: "parsing event stream data",
+            error,
+        })?;
+        Ok(Some(Event { id, data }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_hashing::Digest;
+
+    use super::*;
+
+    const DEPLOY_HASH: &str = "0909090909090909090909090909090909090909090909090909090909090909";
+
+    #[test]
+    fn should_parse_events_split_across_chunks() {
+        let stream = format!(
+            "data:{{\"ApiVersion\":\"1.5.2\"}}\n\n:\n\ndata:{{\"DeployExpired\":{{\"deploy_hash\":\
+            \"{}\"}}}}\nid:21\n\ndata: \"Shutdown\"\r\nid:22\r\n\r\n",
+            DEPLOY_HASH
+        );
+
+        let mut parser = SseParser::default();
+        let mut events = vec![];
+        for chunk in stream.as_bytes().chunks(7) {
+            parser.push(chunk);
+            while let Some(event) = parser.next_event().unwrap() {
+                events.push(event);
+            }
+        }
+
+        assert_eq!(events.len(), 3);
+        assert!(events[0].id.is_none());
+        assert!(matches!(events[0].data, SseData::ApiVersion(_)));
+        assert_eq!(events[1].id, Some(21));
+        match &events[1].data {
+            SseData::DeployExpired { deploy_hash } => {
+                assert_eq!(*deploy_hash, DeployHash::new(Digest::from([9; 32])))
+            }
+            data => panic!("unexpected event data: {:?}", data),
+        }
+        assert_eq!(events[2].id, Some(22));
+        assert_eq!(events[2].data.event_type(), EventType::Shutdown);
+    }
+
+    #[test]
+    fn should_fail_to_parse_invalid_event() {
+        let mut parser = SseParser::default();
+        parser.push(b"data:{\"Unknown\":{}}\nid:1\n\n");
+        assert!(matches!(
+            parser.next_event(),
+            Err(Error::FailedToDecodeFromJson { .. })
+        ));
+    }
+}
@@ -1,7 +1,34 @@
 mod call;
+mod cassette;
+#[cfg(not(target_arch = "wasm32"))]
+mod http_config;
 mod id;
+#[cfg(not(target_arch = "wasm32"))]
+mod node_pool;
+#[cfg(feature = "tokio")]
+mod retry;
 mod success_response;
+pub(crate) mod trace;
+mod transport;
 
-pub(crate) use call::Call as JsonRpcCall;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+pub(crate) use call::{build_request, parse_response, rpc_url, Call as JsonRpcCall};
+#[cfg(feature = "std-fs-io")]
+pub use cassette::RecordingTransport;
+pub use cassette::{Cassette, Interaction, ReplayTransport};
+#[cfg(not(target_arch = "wasm32"))]
+pub use http_config::HttpConfig;
 pub use id::Id as JsonRpcId;
+#[cfg(not(target_arch = "wasm32"))]
+pub use node_pool::{NodeHealth, NodePool};
+#[cfg(feature = "tokio")]
+pub use retry::{RetryPolicy, RetryingTransport};
 pub use success_response::SuccessResponse;
+pub(crate) use transport::BATCH_RPC_METHOD;
+pub use transport::{BatchRequest, HttpTransport, Transport};
+
+/// Locks `mutex`, ignoring poisoning since the guarded values are always left in a valid state.
+pub(crate) fn lock<V>(mutex: &Mutex<V>) -> MutexGuard<'_, V> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
@@ -1,29 +1,42 @@
 use jsonrpc_lite::{JsonRpc, Params};
-use once_cell::sync::OnceCell;
-use reqwest::Client;
 use serde::{de::DeserializeOwned, Serialize};
 use serde_json::json;
+use tracing::Instrument;
 
+use super::{trace, Transport};
 use crate::{Error, JsonRpcId, SuccessResponse, Verbosity};
 
 const RPC_API_PATH: &str = "rpc";
-/// Statically declared client used when making HTTP requests
-/// so opened connections are pooled.
-static CLIENT: OnceCell<Client> = OnceCell::new();
+
+/// Returns the URL of the JSON-RPC endpoint of the node at `node_address`.
+pub(crate) fn rpc_url(node_address: &str) -> String {
+    let node_address = node_address.trim_end_matches('/');
+    if node_address.ends_with(RPC_API_PATH) {
+        node_address.to_string()
+    } else {
+        format!("{}/{}", node_address, RPC_API_PATH)
+    }
+}
 
 /// Struct representing a single JSON-RPC call to the casper node.
 #[derive(Debug)]
-pub(crate) struct Call {
+pub(crate) struct Call<'a> {
+    transport: &'a dyn Transport,
     rpc_id: JsonRpcId,
     node_address: String,
-    #[cfg_attr(not(feature = "std-fs-io"), allow(dead_code))]
     verbosity: Verbosity,
 }
 
 /// `Call` encapsulates JSON-RPC calls made to the casper node service.
-impl Call {
-    pub(crate) fn new(rpc_id: JsonRpcId, node_address: &str, verbosity: Verbosity) -> Self {
+impl<'a> Call<'a> {
+    pub(crate) fn new(
+        transport: &'a dyn Transport,
+        rpc_id: JsonRpcId,
+        node_address: &str,
+        verbosity: Verbosity,
+    ) -> Self {
         Self {
+            transport,
             rpc_id,
             node_address: node_address.trim_end_matches('/').to_string(),
             verbosity,
@@ -35,95 +48,90 @@ impl Call {
         method: &'static str,
         maybe_params: Option<P>,
     ) -> Result<SuccessResponse<R>, Error> {
-        let url = if self.node_address.ends_with(RPC_API_PATH) {
-            self.node_address
-        } else {
-            format!("{}/{}", self.node_address, RPC_API_PATH)
-        };
-
-        let rpc_request = match maybe_params {
-            Some(params) => {
-                let params = Params::Map(
-                    json!(params)
-                        .as_object()
-                        .unwrap_or_else(|| panic!("should be a JSON Map"))
-                        .clone(),
-                );
-                JsonRpc::request_with_params(&self.rpc_id, method, params)
-            }
-            None => JsonRpc::request(&self.rpc_id, method),
-        };
-
-        #[cfg(feature = "std-fs-io")]
-        crate::json_pretty_print(&rpc_request, self.verbosity)?;
-
-        let client = CLIENT.get_or_init(Client::new);
-        let http_response = client
-            .post(&url)
-            .json(&rpc_request)
-            .send()
-            .await
-            .map_err(|error| Error::FailedToGetResponse {
-                rpc_id: self.rpc_id.clone(),
-                rpc_method: method,
-                error,
-            })?;
-
-        if let Err(error) = http_response.error_for_status_ref() {
-            return Err(Error::ResponseIsHttpError {
-                rpc_id: self.rpc_id.clone(),
-                rpc_method: method,
-                error,
-            });
-        }
+        let span = trace::rpc_span(method, &self.rpc_id, &self.node_address);
+        async move {
+            let url = rpc_url(&self.node_address);
+            let rpc_request = build_request(&self.rpc_id, method, maybe_params);
+            trace::payload("sending request", &rpc_request, self.verbosity);
 
-        let rpc_response: JsonRpc =
-            http_response
-                .json()
+            let timer = trace::Timer::start();
+            let rpc_response = self
+                .transport
+                .send(&url, &self.rpc_id, method, &rpc_request)
                 .await
-                .map_err(|error| Error::FailedToParseResponse {
-                    rpc_id: self.rpc_id.clone(),
-                    rpc_method: method,
-                    error,
+                .map_err(|error| {
+                    trace::failure(&error, &timer);
+                    error
                 })?;
+            trace::outcome(trace::status(&rpc_response), &timer);
+            trace::payload("received response", &rpc_response, self.verbosity);
 
-        #[cfg(feature = "std-fs-io")]
-        crate::json_pretty_print(&rpc_response, self.verbosity)?;
-
-        let response_kind = match &rpc_response {
-            JsonRpc::Request(_) => "Request",
-            JsonRpc::Notification(_) => "Notification",
-            JsonRpc::Success(_) => "Success",
-            JsonRpc::Error(_) => "Error",
-        };
+            parse_response(&self.rpc_id, method, rpc_response)
+        }
+        .instrument(span)
+        .await
+    }
+}
 
-        if let Some(json_value) = rpc_response.get_result().cloned() {
-            let value =
-                serde_json::from_value(json_value).map_err(|err| Error::InvalidRpcResponse {
-                    rpc_id: self.rpc_id.clone(),
-                    rpc_method: method,
-                    response_kind,
-                    response: json!(rpc_response),
-                    source: Some(err),
-                })?;
-            let success_response = SuccessResponse::new(self.rpc_id.clone(), value);
-            return Ok(success_response);
+/// Returns a JSON-RPC request for `method`, with `maybe_params` serialized as a JSON map.
+pub(crate) fn build_request<P: Serialize>(
+    rpc_id: &JsonRpcId,
+    method: &'static str,
+    maybe_params: Option<P>,
+) -> JsonRpc {
+    match maybe_params {
+        Some(params) => {
+            let params = Params::Map(
+                json!(params)
+                    .as_object()
+                    .unwrap_or_else(|| panic!("should be a JSON Map"))
+                    .clone(),
+            );
+            JsonRpc::request_with_params(rpc_id, method, params)
         }
+        None => JsonRpc::request(rpc_id, method),
+    }
+}
 
-        if let Some(error) = rpc_response.get_error().cloned() {
-            return Err(Error::ResponseIsRpcError {
-                rpc_id: self.rpc_id.clone(),
+/// Parses the node's response to the request for `method` with the given `rpc_id`.
+pub(crate) fn parse_response<R: DeserializeOwned>(
+    rpc_id: &JsonRpcId,
+    method: &'static str,
+    rpc_response: JsonRpc,
+) -> Result<SuccessResponse<R>, Error> {
+    let response_kind = match &rpc_response {
+        JsonRpc::Request(_) => "Request",
+        JsonRpc::Notification(_) => "Notification",
+        JsonRpc::Success(_) => "Success",
+        JsonRpc::Error(_) => "Error",
+    };
+
+    if let Some(json_value) = rpc_response.get_result().cloned() {
+        let value =
+            serde_json::from_value(json_value).map_err(|err| Error::InvalidRpcResponse {
+                rpc_id: rpc_id.clone(),
                 rpc_method: method,
-                error,
-            });
-        }
+                response_kind,
+                response: json!(rpc_response),
+                source: Some(err),
+            })?;
+        let success_response = SuccessResponse::new(rpc_id.clone(), value);
+        return Ok(success_response);
+    }
 
-        Err(Error::InvalidRpcResponse {
-            rpc_id: self.rpc_id.clone(),
+    if let Some(error) = rpc_response.get_error().cloned() {
+        return Err(Error::ResponseIsRpcError {
+            rpc_id: rpc_id.clone(),
             rpc_method: method,
-            response_kind,
-            response: json!(rpc_response),
-            source: None,
-        })
+            error,
+        });
     }
+
+    Err(Error::InvalidRpcResponse {
+        rpc_id: rpc_id.clone(),
+        rpc_method: method,
+        response_kind,
+        response: json!(rpc_response),
+        source: None,
+    })
 }
@@ -0,0 +1,479 @@
+use std::sync::Mutex;
+#[cfg(feature = "std-fs-io")]
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use async_trait::async_trait;
+use jsonrpc_lite::JsonRpc;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::{lock, BatchRequest, Transport};
+use crate::{Error, JsonRpcId};
+
+/// A request sent to a node, along with the node's response.
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug)]
+pub struct Interaction {
+    /// The JSON-RPC request.
+    pub request: JsonRpc,
+    /// The node's JSON-RPC response.
+    pub response: JsonRpc,
+}
+
+/// A recording of the requests sent to a node and the node's responses, in the order in which
+/// they were sent.
+///
+/// A cassette is recorded via a [`RecordingTransport`] and replayed via a [`ReplayTransport`],
+/// allowing deterministic tests to be written against real responses without a running node.
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize, Debug)]
+pub struct Cassette {
+    interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    /// Returns a new, empty `Cassette`.
+    pub fn new() -> Self {
+        Cassette::default()
+    }
+
+    /// Returns the recorded interactions.
+    pub fn interactions(&self) -> &[Interaction] {
+        &self.interactions
+    }
+
+    /// Appends an interaction to the cassette.
+    pub fn push(&mut self, interaction: Interaction) {
+        self.interactions.push(interaction);
+    }
+
+    /// Reads a previously-saved cassette from a file.
+    #[cfg(feature = "std-fs-io")]
+    pub fn read<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let input = fs::read(path.as_ref()).map_err(|error| Error::IoError {
+            context: format!(
+                "unable to read cassette file at '{}'",
+                path.as_ref().display()
+            ),
+            error,
+        })?;
+        serde_json::from_slice(&input).map_err(|error| Error::FailedToDecodeFromJson {
+            context: "reading cassette",
+            error,
+        })
+    }
+
+    /// Writes the cassette to a file as JSON, overwriting any existing file.
+    #[cfg(feature = "std-fs-io")]
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let content =
+            serde_json::to_string_pretty(self).map_err(|error| Error::FailedToEncodeToJson {
+                context: "writing cassette",
+                error,
+            })?;
+        fs::write(path.as_ref(), content).map_err(|error| Error::IoError {
+            context: format!(
+                "unable to write cassette file at '{}'",
+                path.as_ref().display()
+            ),
+            error,
+        })
+    }
+}
+
+/// A [`Transport`] which sends requests via an inner transport, recording each successful
+/// request and response to a [`Cassette`] file.
+///
+/// The file is rewritten after every recorded interaction, so it is complete even if the process
+/// exits abruptly.  Requests which fail are not recorded.  Batches are sent as a batch via the
+/// inner transport, and recorded as their individual requests, each with the response bearing its
+/// JSON-RPC ID.
+#[cfg(feature = "std-fs-io")]
+#[derive(Debug)]
+pub struct RecordingTransport<T> {
+    inner: T,
+    path: PathBuf,
+    cassette: Mutex<Cassette>,
+}
+
+#[cfg(feature = "std-fs-io")]
+impl<T: Transport> RecordingTransport<T> {
+    /// Constructs a new `RecordingTransport` which sends requests via `inner`, recording them to a
+    /// new cassette at `path`.  Any existing file at `path` is overwritten once the first
+    /// interaction is recorded.
+    pub fn new<P: Into<PathBuf>>(inner: T, path: P) -> Self {
+        RecordingTransport {
+            inner,
+            path: path.into(),
+            cassette: Mutex::new(Cassette::new()),
+        }
+    }
+
+    /// Returns a copy of the cassette recorded so far.
+    pub fn cassette(&self) -> Cassette {
+        lock(&self.cassette).clone()
+    }
+}
+
+#[cfg(feature = "std-fs-io")]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl<T: Transport> Transport for RecordingTransport<T> {
+    async fn send(
+        &self,
+        url: &str,
+        rpc_id: &JsonRpcId,
+        rpc_method: &'static str,
+        request: &JsonRpc,
+    ) -> Result<JsonRpc, Error> {
+        let response = self.inner.send(url, rpc_id, rpc_method, request).await?;
+        let mut cassette = lock(&self.cassette);
+        cassette.push(Interaction {
+            request: request.clone(),
+            response: response.clone(),
+        });
+        cassette.write(&self.path)?;
+        Ok(response)
+    }
+
+    async fn send_batch(
+        &self,
+        url: &str,
+        requests: &[BatchRequest],
+    ) -> Result<Vec<JsonRpc>, Error> {
+        let responses = self.inner.send_batch(url, requests).await?;
+        let mut cassette = lock(&self.cassette);
+        for batch_request in requests {
+            let rpc_id = json!(batch_request.rpc_id);
+            if let Some(response) = responses
+                .iter()
+                .find(|response| json!(response)["id"] == rpc_id)
+            {
+                cassette.push(Interaction {
+                    request: batch_request.request.clone(),
+                    response: response.clone(),
+                });
+            }
+        }
+        cassette.write(&self.path)?;
+        Ok(responses)
+    }
+}
+
+/// A [`Transport`] which never contacts a node, instead replying with the responses recorded in a
+/// [`Cassette`].
+///
+/// A request is matched to a recorded interaction by its method and parameters, ignoring its
+/// JSON-RPC ID, and the recorded response is returned with its ID replaced by that of the request.
+/// Each recorded interaction is replayed at most once, in the order in which they were recorded.
+/// If no unreplayed interaction matches a request, [`Error::NoRecordedResponse`] is returned.  A
+/// batch is replayed only if every one of its requests matches an unreplayed interaction.
+#[derive(Debug)]
+pub struct ReplayTransport {
+    interactions: Vec<(Value, JsonRpc)>,
+    replayed: Mutex<Vec<bool>>,
+}
+
+impl ReplayTransport {
+    /// Constructs a new `ReplayTransport` which replays the interactions recorded in `cassette`.
+    pub fn new(cassette: Cassette) -> Self {
+        let interactions: Vec<_> = cassette
+            .interactions
+            .into_iter()
+            .map(|interaction| (without_id(&interaction.request), interaction.response))
+            .collect();
+        let replayed = Mutex::new(vec![false; interactions.len()]);
+        ReplayTransport {
+            interactions,
+            replayed,
+        }
+    }
+
+    /// Constructs a new `ReplayTransport` which replays the cassette saved at `path`.
+    #[cfg(feature = "std-fs-io")]
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        Ok(ReplayTransport::new(Cassette::read(path)?))
+    }
+
+    /// Marks the first interaction matching `request` which isn't yet marked in `replayed`, and
+    /// returns its recorded response with the ID replaced by `rpc_id`.
+    fn replay(
+        &self,
+        replayed: &mut [bool],
+        rpc_id: &JsonRpcId,
+        rpc_method: &'static str,
+        request: &JsonRpc,
+    ) -> Result<JsonRpc, Error> {
+        let request = without_id(request);
+        let index = self
+            .interactions
+            .iter()
+            .zip(replayed.iter())
+            .position(|((recorded_request, _), replayed)| {
+                !*replayed && *recorded_request == request
+            })
+            .ok_or_else(|| Error::NoRecordedResponse {
+                rpc_id: rpc_id.clone(),
+                rpc_method,
+            })?;
+        replayed[index] = true;
+
+        let mut response = json!(self.interactions[index].1);
+        response["id"] = json!(rpc_id);
+        serde_json::from_value(response).map_err(|error| Error::FailedToDecodeFromJson {
+            context: "replaying recorded response",
+            error,
+        })
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl Transport for ReplayTransport {
+    async fn send(
+        &self,
+        _url: &str,
+        rpc_id: &JsonRpcId,
+        rpc_method: &'static str,
+        request: &JsonRpc,
+    ) -> Result<JsonRpc, Error> {
+        let mut replayed = lock(&self.replayed);
+        self.replay(&mut replayed, rpc_id, rpc_method, request)
+    }
+
+    async fn send_batch(
+        &self,
+        _url: &str,
+        requests: &[BatchRequest],
+    ) -> Result<Vec<JsonRpc>, Error> {
+        let mut replayed = lock(&self.replayed);
+        let mut batch_replayed = replayed.clone();
+        let responses = requests
+            .iter()
+            .map(|batch_request| {
+                self.replay(
+                    &mut batch_replayed,
+                    &batch_request.rpc_id,
+                    batch_request.rpc_method,
+                    &batch_request.request,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        *replayed = batch_replayed;
+        Ok(responses)
+    }
+}
+
+/// Returns `request` as JSON, without its JSON-RPC ID.
+fn without_id(request: &JsonRpc) -> Value {
+    let mut request = json!(request);
+    if let Some(fields) = request.as_object_mut() {
+        let _ = fields.remove("id");
+    }
+    request
+}
+
+#[cfg(all(test, feature = "tokio", feature = "std-fs-io"))]
+mod tests {
+    use casper_hashing::Digest;
+
+    use super::*;
+    use crate::{
+        rpcs::{common::BlockIdentifier, results::GetStateRootHashResult},
+        Batch, BatchHandle, Verbosity,
+    };
+
+    #[derive(Debug)]
+    struct PeersTransport;
+
+    #[async_trait]
+    impl Transport for PeersTransport {
+        async fn send(
+            &self,
+            _url: &str,
+            rpc_id: &JsonRpcId,
+            _rpc_method: &'static str,
+            _request: &JsonRpc,
+        ) -> Result<JsonRpc, Error> {
+            Ok(JsonRpc::success(
+                rpc_id,
+                &json!({
+                    "api_version": "1.5.0",
+                    "peers": [{ "node_id": "tls:0102", "address": "127.0.0.1:22101" }]
+                }),
+            ))
+        }
+    }
+
+    /// A transport which only sends batches, responding to each request in reverse order with a
+    /// state root hash whose bytes are the request's numeric ID.
+    #[derive(Debug)]
+    struct BatchOnlyTransport;
+
+    #[async_trait]
+    impl Transport for BatchOnlyTransport {
+        async fn send(
+            &self,
+            _url: &str,
+            _rpc_id: &JsonRpcId,
+            _rpc_method: &'static str,
+            _request: &JsonRpc,
+        ) -> Result<JsonRpc, Error> {
+            panic!("requests should be sent as a batch");
+        }
+
+        async fn send_batch(
+            &self,
+            _url: &str,
+            requests: &[BatchRequest],
+        ) -> Result<Vec<JsonRpc>, Error> {
+            Ok(requests
+                .iter()
+                .rev()
+                .map(|batch_request| {
+                    let byte = match batch_request.rpc_id {
+                        JsonRpcId::Number(number) => number as u8,
+                        JsonRpcId::String(_) => 0,
+                    };
+                    JsonRpc::success(
+                        &batch_request.rpc_id,
+                        &json!({
+                            "api_version": "1.5.0",
+                            "state_root_hash": Digest::from([byte; Digest::LENGTH])
+                        }),
+                    )
+                })
+                .collect())
+        }
+    }
+
+    /// Returns a batch of two `chain_get_state_root_hash` requests, at heights 1 and 2.
+    fn state_root_hash_batch(
+        first_rpc_id: i64,
+        second_rpc_id: i64,
+    ) -> (
+        Batch,
+        BatchHandle<GetStateRootHashResult>,
+        BatchHandle<GetStateRootHashResult>,
+    ) {
+        let mut batch = Batch::new();
+        let first = batch.get_state_root_hash(
+            JsonRpcId::from(first_rpc_id),
+            Some(BlockIdentifier::Height(1)),
+        );
+        let second = batch.get_state_root_hash(
+            JsonRpcId::from(second_rpc_id),
+            Some(BlockIdentifier::Height(2)),
+        );
+        (batch, first, second)
+    }
+
+    #[tokio::test]
+    async fn should_record_and_replay() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let path = tempdir.path().join("cassette.json");
+
+        let recorder = RecordingTransport::new(PeersTransport, &path);
+        let recorded = crate::get_peers_with_transport(
+            &recorder,
+            JsonRpcId::from(1),
+            "http://localhost:7777",
+            Verbosity::Low,
+        )
+        .await
+        .unwrap();
+        assert_eq!(recorder.cassette().interactions().len(), 1);
+
+        let replayer = ReplayTransport::from_file(&path).unwrap();
+        let replayed = crate::get_peers_with_transport(
+            &replayer,
+            JsonRpcId::from("replayed".to_string()),
+            "http://localhost:7777",
+            Verbosity::Low,
+        )
+        .await
+        .unwrap();
+        assert_eq!(replayed.id, JsonRpcId::from("replayed".to_string()));
+        assert_eq!(replayed.result.peers, recorded.result.peers);
+
+        // The single recorded interaction has now been used up.
+        let error = crate::get_peers_with_transport(
+            &replayer,
+            JsonRpcId::from(2),
+            "http://localhost:7777",
+            Verbosity::Low,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::NoRecordedResponse {
+                rpc_method: "info_get_peers",
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_record_and_replay_batch() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let path = tempdir.path().join("cassette.json");
+
+        let recorder = RecordingTransport::new(BatchOnlyTransport, &path);
+        let (batch, first, _) = state_root_hash_batch(1, 2);
+        let mut response = batch
+            .send_with_transport(&recorder, "http://localhost:7777", Verbosity::Low)
+            .await
+            .unwrap();
+        assert_eq!(
+            response.take(first).unwrap().result.state_root_hash,
+            Some(Digest::from([1; Digest::LENGTH]))
+        );
+        assert_eq!(recorder.cassette().interactions().len(), 2);
+
+        // The responses are matched to the requests by their parameters, not their IDs.
+        let replayer = ReplayTransport::from_file(&path).unwrap();
+        let (batch, first, second) = state_root_hash_batch(20, 10);
+        let mut response = batch
+            .send_with_transport(&replayer, "http://localhost:7777", Verbosity::Low)
+            .await
+            .unwrap();
+        let first = response.take(first).unwrap();
+        assert_eq!(first.id, JsonRpcId::from(20));
+        assert_eq!(
+            first.result.state_root_hash,
+            Some(Digest::from([1; Digest::LENGTH]))
+        );
+        assert_eq!(
+            response.take(second).unwrap().result.state_root_hash,
+            Some(Digest::from([2; Digest::LENGTH]))
+        );
+
+        let (batch, ..) = state_root_hash_batch(1, 2);
+        let error = batch
+            .send_with_transport(&replayer, "http://localhost:7777", Verbosity::Low)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::NoRecordedResponse { .. }));
+    }
+
+    #[tokio::test]
+    async fn should_not_replay_response_to_different_request() {
+        let mut cassette = Cassette::new();
+        cassette.push(Interaction {
+            request: JsonRpc::request(&JsonRpcId::from(1), "info_get_peers"),
+            response: JsonRpc::success(&JsonRpcId::from(1), &json!({})),
+        });
+        let replayer = ReplayTransport::new(cassette);
+        let error = crate::get_node_status_with_transport(
+            &replayer,
+            JsonRpcId::from(1),
+            "http://localhost:7777",
+            Verbosity::Low,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(error, Error::NoRecordedResponse { .. }));
+    }
+}
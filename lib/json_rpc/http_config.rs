@@ -0,0 +1,128 @@
+use std::time::Duration;
+
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Certificate, Client, Proxy,
+};
+
+use crate::Error;
+
+/// Settings for the HTTP client used by an [`HttpTransport`](super::HttpTransport).
+///
+/// The default configuration matches that of `reqwest::Client::new()`, i.e. no timeouts, no extra
+/// headers, proxies configured via the system environment and TLS verification against the
+/// built-in root certificates.
+#[derive(Clone, Default, Debug)]
+pub struct HttpConfig {
+    request_timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    headers: Vec<(String, String)>,
+    proxy: Option<String>,
+    ca_bundle_pem: Option<Vec<u8>>,
+    accept_invalid_certs: bool,
+}
+
+impl HttpConfig {
+    /// Returns a new `HttpConfig` with default settings.
+    pub fn new() -> Self {
+        HttpConfig::default()
+    }
+
+    /// Sets the maximum duration of each request, from connecting until the response body has
+    /// been received.
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the maximum duration allowed for establishing a connection to the node.
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Adds a header to be sent with every request, e.g. `Authorization: Bearer <TOKEN>`.
+    ///
+    /// May be called several times to add several headers.
+    pub fn with_header<N: Into<String>, V: Into<String>>(mut self, name: N, value: V) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets the URL of a proxy through which all requests will be sent.
+    pub fn with_proxy<U: Into<String>>(mut self, proxy_url: U) -> Self {
+        self.proxy = Some(proxy_url.into());
+        self
+    }
+
+    /// Sets one or more PEM-encoded CA certificates to be trusted in addition to the built-in root
+    /// certificates.
+    pub fn with_ca_bundle_pem(mut self, ca_bundle_pem: Vec<u8>) -> Self {
+        self.ca_bundle_pem = Some(ca_bundle_pem);
+        self
+    }
+
+    /// Sets whether to accept invalid TLS certificates, including self-signed and expired ones.
+    ///
+    /// # Warning
+    ///
+    /// This disables TLS verification entirely, and should only be used against development
+    /// networks.
+    pub fn with_accept_invalid_certs(mut self, accept_invalid_certs: bool) -> Self {
+        self.accept_invalid_certs = accept_invalid_certs;
+        self
+    }
+
+    /// Returns a `reqwest::Client` configured with these settings.
+    pub fn build_client(&self) -> Result<Client, Error> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            let header_name = HeaderName::from_bytes(name.as_bytes()).map_err(|error| {
+                Error::InvalidHttpConfig {
+                    context: format!("header name '{}'", name),
+                    error: Box::new(error),
+                }
+            })?;
+            let header_value =
+                HeaderValue::from_str(value).map_err(|error| Error::InvalidHttpConfig {
+                    context: format!("value of header '{}'", name),
+                    error: Box::new(error),
+                })?;
+            headers.append(header_name, header_value);
+        }
+
+        let mut builder = Client::builder()
+            .default_headers(headers)
+            .danger_accept_invalid_certs(self.accept_invalid_certs);
+        if let Some(timeout) = self.request_timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(proxy_url) = &self.proxy {
+            let proxy =
+                Proxy::all(proxy_url.as_str()).map_err(|error| Error::InvalidHttpConfig {
+                    context: format!("proxy url '{}'", proxy_url),
+                    error: Box::new(error),
+                })?;
+            builder = builder.proxy(proxy);
+        }
+        if let Some(ca_bundle_pem) = &self.ca_bundle_pem {
+            let certificates = Certificate::from_pem_bundle(ca_bundle_pem).map_err(|error| {
+                Error::InvalidHttpConfig {
+                    context: "ca bundle".to_string(),
+                    error: Box::new(error),
+                }
+            })?;
+            for certificate in certificates {
+                builder = builder.add_root_certificate(certificate);
+            }
+        }
+
+        builder.build().map_err(|error| Error::InvalidHttpConfig {
+            context: "building http client".to_string(),
+            error: Box::new(error),
+        })
+    }
+}
@@ -0,0 +1,500 @@
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use jsonrpc_lite::JsonRpc;
+
+use super::{call, lock, BatchRequest, Transport};
+use crate::{
+    rpcs::results::{GetNodeStatusResult, ReactorState},
+    Error, JsonRpcId, Verbosity,
+};
+
+/// The JSON-RPC ID used for the `info_get_status` requests made when checking nodes' health.
+const HEALTH_CHECK_RPC_ID: &str = "node-pool-health-check";
+
+/// The health of a node in a [`NodePool`], as determined by its most recent status check or
+/// request.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum NodeHealth {
+    /// The node has not been checked yet.
+    Unchecked,
+    /// The node is running normally and is caught up with the rest of the pool.
+    Healthy {
+        /// The height of the last block added by the node.
+        block_height: u64,
+    },
+    /// The node should be avoided.
+    Unhealthy {
+        /// Why the node is considered unhealthy.
+        reason: String,
+    },
+}
+
+#[derive(Debug)]
+struct Node {
+    address: String,
+    url: String,
+    health: Mutex<NodeHealth>,
+}
+
+impl Node {
+    fn mark_unhealthy(&self, error: &Error) {
+        *lock(&self.health) = NodeHealth::Unhealthy {
+            reason: error.to_string(),
+        };
+    }
+}
+
+/// A [`Transport`] which spreads requests across a pool of nodes, sending each request via an
+/// inner transport to a healthy node and failing over to the next node if sending fails.
+///
+/// The health of every node is checked via `info_get_status` before the first request, and again
+/// before any request made once the health check interval has elapsed.  A node is healthy if:
+///   * its reactor is in the `KeepUp` or `Validate` state, and
+///   * it has made progress within the max progress age, and
+///   * its last added block is no more than the max block lag behind the highest block reported
+///     by any node in the pool.
+///
+/// Requests are sent to the healthy nodes in the order in which the nodes were provided.  If all
+/// of them fail, the remaining nodes are tried as a last resort.  A node which fails to handle a
+/// request is considered unhealthy until the next health check.  Only failing to connect, timing
+/// out, a custom transport failure or an HTTP 5xx status cause a failover: other errors, such as
+/// an HTTP 4xx status or a JSON-RPC error response, are the fault of the request rather than the
+/// node, and are returned to the caller.
+///
+/// Since the pool chooses the node for each request, the `node_address` passed alongside the pool
+/// to any RPC function is ignored.
+#[derive(Debug)]
+pub struct NodePool<T> {
+    nodes: Vec<Node>,
+    inner: T,
+    max_block_lag: u64,
+    max_progress_age: Duration,
+    health_check_interval: Duration,
+    last_health_check: Mutex<Option<Instant>>,
+}
+
+impl<T: Transport> NodePool<T> {
+    /// The default number of blocks a node may be behind the highest node in the pool while still
+    /// being considered healthy, i.e. `3`.
+    pub const DEFAULT_MAX_BLOCK_LAG: u64 = 3;
+    /// The default maximum time since a node last made progress for it to be considered healthy,
+    /// i.e. 5 minutes.
+    pub const DEFAULT_MAX_PROGRESS_AGE: Duration = Duration::from_secs(300);
+    /// The default interval between health checks of the pool's nodes, i.e. 30 seconds.
+    pub const DEFAULT_HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+    /// Constructs a new `NodePool` of the nodes at `node_addresses`, sending requests via
+    /// `inner`.
+    ///
+    /// Returns an error if `node_addresses` is empty.
+    pub fn new<I, S>(node_addresses: I, inner: T) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let nodes: Vec<Node> = node_addresses
+            .into_iter()
+            .map(|node_address| {
+                let address = node_address.into();
+                Node {
+                    url: call::rpc_url(&address),
+                    address,
+                    health: Mutex::new(NodeHealth::Unchecked),
+                }
+            })
+            .collect();
+        if nodes.is_empty() {
+            return Err(Error::EmptyNodePool);
+        }
+        Ok(NodePool {
+            nodes,
+            inner,
+            max_block_lag: Self::DEFAULT_MAX_BLOCK_LAG,
+            max_progress_age: Self::DEFAULT_MAX_PROGRESS_AGE,
+            health_check_interval: Self::DEFAULT_HEALTH_CHECK_INTERVAL,
+            last_health_check: Mutex::new(None),
+        })
+    }
+
+    /// Sets the number of blocks a node may be behind the highest node in the pool while still
+    /// being considered healthy.
+    ///
+    /// If not provided, [`DEFAULT_MAX_BLOCK_LAG`](Self::DEFAULT_MAX_BLOCK_LAG) is used.
+    pub fn with_max_block_lag(mut self, max_block_lag: u64) -> Self {
+        self.max_block_lag = max_block_lag;
+        self
+    }
+
+    /// Sets the maximum time since a node last made progress for it to be considered healthy.
+    ///
+    /// If not provided, [`DEFAULT_MAX_PROGRESS_AGE`](Self::DEFAULT_MAX_PROGRESS_AGE) is used.
+    pub fn with_max_progress_age(mut self, max_progress_age: Duration) -> Self {
+        self.max_progress_age = max_progress_age;
+        self
+    }
+
+    /// Sets the interval between health checks of the pool's nodes.
+    ///
+    /// If not provided, [`DEFAULT_HEALTH_CHECK_INTERVAL`](Self::DEFAULT_HEALTH_CHECK_INTERVAL) is
+    /// used.
+    pub fn with_health_check_interval(mut self, health_check_interval: Duration) -> Self {
+        self.health_check_interval = health_check_interval;
+        self
+    }
+
+    /// Returns the address and current health of each node in the pool.
+    pub fn node_health(&self) -> Vec<(String, NodeHealth)> {
+        self.nodes
+            .iter()
+            .map(|node| (node.address.clone(), lock(&node.health).clone()))
+            .collect()
+    }
+
+    /// Checks the health of every node in the pool via `info_get_status`.
+    ///
+    /// The nodes are checked one at a time, so a connect timeout should be configured on the
+    /// inner transport to avoid a single unresponsive node delaying the check indefinitely.
+    pub async fn check_health(&self) {
+        let mut statuses = Vec::with_capacity(self.nodes.len());
+        for node in &self.nodes {
+            let status = crate::get_node_status_with_transport(
+                &self.inner,
+                JsonRpcId::from(HEALTH_CHECK_RPC_ID.to_string()),
+                &node.address,
+                Verbosity::Low,
+            )
+            .await
+            .map(|response| response.result);
+            statuses.push(status);
+        }
+
+        let highest_block_height = statuses
+            .iter()
+            .filter_map(|status| status.as_ref().ok()?.last_added_block_info.as_ref())
+            .map(|block_info| block_info.height)
+            .max();
+
+        for (node, status) in self.nodes.iter().zip(statuses) {
+            let health = match status {
+                Ok(status) => self.assess(&status, highest_block_height),
+                Err(error) => NodeHealth::Unhealthy {
+                    reason: error.to_string(),
+                },
+            };
+            *lock(&node.health) = health;
+        }
+        *lock(&self.last_health_check) = Some(Instant::now());
+    }
+
+    fn assess(
+        &self,
+        status: &GetNodeStatusResult,
+        highest_block_height: Option<u64>,
+    ) -> NodeHealth {
+        if !matches!(
+            status.reactor_state,
+            ReactorState::KeepUp | ReactorState::Validate
+        ) {
+            return NodeHealth::Unhealthy {
+                reason: format!("reactor state is {:?}", status.reactor_state),
+            };
+        }
+
+        let progress_age = status.last_progress.elapsed();
+        if u128::from(progress_age.millis()) > self.max_progress_age.as_millis() {
+            return NodeHealth::Unhealthy {
+                reason: format!("no progress for {}", progress_age),
+            };
+        }
+
+        let block_height = match &status.last_added_block_info {
+            Some(block_info) => block_info.height,
+            None => {
+                return NodeHealth::Unhealthy {
+                    reason: "no blocks added".to_string(),
+                }
+            }
+        };
+        let lag = highest_block_height
+            .unwrap_or(block_height)
+            .saturating_sub(block_height);
+        if lag > self.max_block_lag {
+            return NodeHealth::Unhealthy {
+                reason: format!("{} blocks behind the highest node in the pool", lag),
+            };
+        }
+
+        NodeHealth::Healthy { block_height }
+    }
+
+    /// Returns the nodes to try for the next request, with the healthy ones first, checking the
+    /// health of every node first if due.
+    ///
+    /// The time of the check is recorded before it starts, so that concurrent requests don't each
+    /// run their own check.  Those requests use the nodes' health as of the previous check.
+    async fn candidates(&self) -> Vec<&Node> {
+        let health_check_due = {
+            let mut last_health_check = lock(&self.last_health_check);
+            let due = last_health_check.map_or(true, |last_health_check| {
+                last_health_check.elapsed() >= self.health_check_interval
+            });
+            if due {
+                *last_health_check = Some(Instant::now());
+            }
+            due
+        };
+        if health_check_due {
+            self.check_health().await;
+        }
+
+        let (mut healthy, others): (Vec<&Node>, Vec<&Node>) = self
+            .nodes
+            .iter()
+            .partition(|node| matches!(*lock(&node.health), NodeHealth::Healthy { .. }));
+        healthy.extend(others);
+        healthy
+    }
+}
+
+#[async_trait]
+impl<T: Transport> Transport for NodePool<T> {
+    async fn send(
+        &self,
+        _url: &str,
+        rpc_id: &JsonRpcId,
+        rpc_method: &'static str,
+        request: &JsonRpc,
+    ) -> Result<JsonRpc, Error> {
+        let mut last_error = None;
+        for node in self.candidates().await {
+            match self
+                .inner
+                .send(&node.url, rpc_id, rpc_method, request)
+                .await
+            {
+                Ok(response) => return Ok(response),
+                Err(error) if should_fail_over(&error) => {
+                    node.mark_unhealthy(&error);
+                    last_error = Some(error);
+                }
+                Err(error) => return Err(error),
+            }
+        }
+        Err(last_error.unwrap_or(Error::EmptyNodePool))
+    }
+
+    async fn send_batch(
+        &self,
+        _url: &str,
+        requests: &[BatchRequest],
+    ) -> Result<Vec<JsonRpc>, Error> {
+        let mut last_error = None;
+        for node in self.candidates().await {
+            match self.inner.send_batch(&node.url, requests).await {
+                Ok(responses) => return Ok(responses),
+                Err(error) if should_fail_over(&error) => {
+                    node.mark_unhealthy(&error);
+                    last_error = Some(error);
+                }
+                Err(error) => return Err(error),
+            }
+        }
+        Err(last_error.unwrap_or(Error::EmptyNodePool))
+    }
+}
+
+/// Returns `true` if `error` indicates a problem with the node rather than with the request, i.e.
+/// the node couldn't be reached, timed out or returned an HTTP 5xx status.
+fn should_fail_over(error: &Error) -> bool {
+    match error {
+        Error::FailedToGetResponse { error, .. } => {
+            error.is_connect() || error.is_timeout() || error.is_request()
+        }
+        Error::TransportFailure { .. } => true,
+        Error::ResponseIsHttpError { error, .. } => error
+            .status()
+            .map_or(true, |status| status.is_server_error()),
+        _ => false,
+    }
+}
+
+#[cfg(all(test, feature = "tokio"))]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::{rpcs::v1_6_0::get_node_status::GET_NODE_STATUS_METHOD, types::Timestamp};
+
+    const HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+    const CREATOR: &str = "015866666666666666666666666666666666666666666666666666666666666666";
+
+    /// A transport serving a canned `info_get_status` result for each node, and an empty result
+    /// for every other request unless the node is listed as failing or rejecting the request.
+    #[derive(Debug, Default)]
+    struct FakeTransport {
+        statuses: HashMap<&'static str, Value>,
+        failing: Vec<&'static str>,
+        rejecting: Vec<&'static str>,
+        handled_by: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl Transport for FakeTransport {
+        async fn send(
+            &self,
+            url: &str,
+            rpc_id: &JsonRpcId,
+            rpc_method: &'static str,
+            _request: &JsonRpc,
+        ) -> Result<JsonRpc, Error> {
+            let failure = || Error::TransportFailure {
+                rpc_id: rpc_id.clone(),
+                rpc_method,
+                error: format!("{} is unreachable", url).into(),
+            };
+            let status = self.statuses.get(url).ok_or_else(failure)?;
+            if rpc_method == GET_NODE_STATUS_METHOD {
+                return Ok(JsonRpc::success(rpc_id, status));
+            }
+            if self.failing.iter().any(|failing| *failing == url) {
+                return Err(failure());
+            }
+            if self.rejecting.iter().any(|rejecting| *rejecting == url) {
+                return Err(Error::ResponseIsRpcError {
+                    rpc_id: rpc_id.clone(),
+                    rpc_method,
+                    error: jsonrpc_lite::Error::invalid_params(),
+                });
+            }
+            lock(&self.handled_by).push(url.to_string());
+            Ok(JsonRpc::success(rpc_id, &json!({})))
+        }
+    }
+
+    fn status(reactor_state: &str, height: u64, last_progress: Timestamp) -> Value {
+        json!({
+            "api_version": "1.5.6",
+            "chainspec_name": "casper-test",
+            "starting_state_root_hash": HASH,
+            "peers": [],
+            "last_added_block_info": {
+                "hash": HASH,
+                "timestamp": last_progress.to_string(),
+                "era_id": 1,
+                "height": height,
+                "state_root_hash": HASH,
+                "creator": CREATOR
+            },
+            "our_public_signing_key": null,
+            "round_length": null,
+            "next_upgrade": null,
+            "build_version": "1.5.6",
+            "uptime": "1h",
+            "reactor_state": reactor_state,
+            "last_progress": last_progress.to_string(),
+            "available_block_range": { "low": 0, "high": height },
+            "block_sync": { "historical": null, "forward": null }
+        })
+    }
+
+    async fn send(pool: &NodePool<FakeTransport>) -> Result<JsonRpc, Error> {
+        let request = JsonRpc::request(&JsonRpcId::from(1), "info_get_peers");
+        pool.send("ignored", &JsonRpcId::from(1), "info_get_peers", &request)
+            .await
+    }
+
+    #[tokio::test]
+    async fn should_route_to_healthy_caught_up_node() {
+        let stalled = "2020-01-01T00:00:00.000Z".parse().unwrap();
+        let transport = FakeTransport {
+            statuses: HashMap::from([
+                ("http://a/rpc", status("CatchUp", 100, Timestamp::now())),
+                ("http://b/rpc", status("KeepUp", 100, stalled)),
+                ("http://c/rpc", status("KeepUp", 90, Timestamp::now())),
+                ("http://e/rpc", status("Validate", 99, Timestamp::now())),
+            ]),
+            ..FakeTransport::default()
+        };
+        let pool = NodePool::new(
+            ["http://a", "http://b", "http://c", "http://d", "http://e"],
+            transport,
+        )
+        .unwrap();
+
+        let _ = send(&pool).await.expect("should succeed");
+        assert_eq!(*lock(&pool.inner.handled_by), vec!["http://e/rpc"]);
+
+        let health: Vec<_> = pool.node_health().into_iter().map(|(_, h)| h).collect();
+        assert!(matches!(health[0], NodeHealth::Unhealthy { .. }));
+        assert!(matches!(health[1], NodeHealth::Unhealthy { .. }));
+        assert!(matches!(health[2], NodeHealth::Unhealthy { .. }));
+        assert!(matches!(health[3], NodeHealth::Unhealthy { .. }));
+        assert_eq!(health[4], NodeHealth::Healthy { block_height: 99 });
+    }
+
+    #[tokio::test]
+    async fn should_fail_over_to_next_node() {
+        let transport = FakeTransport {
+            statuses: HashMap::from([
+                ("http://a/rpc", status("KeepUp", 100, Timestamp::now())),
+                ("http://b/rpc", status("KeepUp", 100, Timestamp::now())),
+            ]),
+            failing: vec!["http://a/rpc"],
+            ..FakeTransport::default()
+        };
+        let pool = NodePool::new(["http://a", "http://b"], transport).unwrap();
+
+        let _ = send(&pool).await.expect("should succeed");
+        assert_eq!(*lock(&pool.inner.handled_by), vec!["http://b/rpc"]);
+        assert!(matches!(
+            pool.node_health()[0].1,
+            NodeHealth::Unhealthy { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_not_fail_over_if_request_is_rejected() {
+        let transport = FakeTransport {
+            statuses: HashMap::from([
+                ("http://a/rpc", status("KeepUp", 100, Timestamp::now())),
+                ("http://b/rpc", status("KeepUp", 100, Timestamp::now())),
+            ]),
+            rejecting: vec!["http://a/rpc"],
+            ..FakeTransport::default()
+        };
+        let pool = NodePool::new(["http://a", "http://b"], transport).unwrap();
+
+        assert!(matches!(
+            send(&pool).await,
+            Err(Error::ResponseIsRpcError { .. })
+        ));
+        assert!(lock(&pool.inner.handled_by).is_empty());
+        assert_eq!(
+            pool.node_health()[0].1,
+            NodeHealth::Healthy { block_height: 100 }
+        );
+    }
+
+    #[tokio::test]
+    async fn should_return_last_error_if_all_nodes_fail() {
+        let pool = NodePool::new(["http://a", "http://b"], FakeTransport::default()).unwrap();
+        match send(&pool).await.unwrap_err() {
+            Error::TransportFailure { error, .. } => {
+                assert_eq!(error.to_string(), "http://b/rpc is unreachable")
+            }
+            error => panic!("unexpected error: {}", error),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_construct_empty_pool() {
+        let result = NodePool::new(Vec::<String>::new(), FakeTransport::default());
+        assert!(matches!(result, Err(Error::EmptyNodePool)));
+    }
+}
@@ -0,0 +1,290 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use jsonrpc_lite::JsonRpc;
+use rand::Rng;
+use reqwest::StatusCode;
+
+use super::{BatchRequest, Transport};
+use crate::{rpcs::v1_6_0::put_deploy::PUT_DEPLOY_METHOD, Error, JsonRpcId};
+
+/// The policy governing whether and how failed requests are retried by a [`RetryingTransport`].
+///
+/// Only transient failures are retried, i.e. failing to get a response from the node, a custom
+/// transport failure, or an HTTP error status indicating a server-side or rate-limiting problem
+/// (e.g. a 502 from a load balancer).  JSON-RPC error responses from the node are never retried.
+///
+/// All read-only RPCs are retried.  `account_put_deploy` is only retried if enabled via
+/// [`with_put_deploy_retries`](Self::with_put_deploy_retries).
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+    retry_put_deploy: bool,
+}
+
+impl RetryPolicy {
+    /// The default maximum number of retries after the initial attempt, i.e. `3`.
+    pub const DEFAULT_MAX_RETRIES: u32 = 3;
+    /// The default delay before the first retry, i.e. 250 milliseconds.
+    pub const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(250);
+    /// The default upper limit of the delay between retries, i.e. 10 seconds.
+    pub const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(10);
+
+    /// Returns a new `RetryPolicy` with default settings.
+    pub fn new() -> Self {
+        RetryPolicy::default()
+    }
+
+    /// Sets the maximum number of retries after the initial attempt.
+    ///
+    /// If not provided, [`DEFAULT_MAX_RETRIES`](Self::DEFAULT_MAX_RETRIES) is used.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the delay before the first retry.  The delay doubles for each subsequent retry.
+    ///
+    /// If not provided, [`DEFAULT_INITIAL_BACKOFF`](Self::DEFAULT_INITIAL_BACKOFF) is used.
+    pub fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Sets the upper limit of the delay between retries.
+    ///
+    /// If not provided, [`DEFAULT_MAX_BACKOFF`](Self::DEFAULT_MAX_BACKOFF) is used.
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Sets whether each delay is randomly reduced by up to half, to avoid many clients retrying
+    /// in lockstep.
+    ///
+    /// If not provided, jitter is enabled.
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets whether `account_put_deploy` requests are retried.
+    ///
+    /// A retried request is byte-for-byte identical to the original, so it carries the same deploy
+    /// hash.  The node identifies deploys by their hash, meaning that a deploy which was received
+    /// by the node during a failed attempt will not be executed twice.
+    ///
+    /// If not provided, `account_put_deploy` requests are not retried.
+    pub fn with_put_deploy_retries(mut self, retry_put_deploy: bool) -> Self {
+        self.retry_put_deploy = retry_put_deploy;
+        self
+    }
+
+    /// Returns the maximum number of retries after the initial attempt.
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// Returns `true` if a request for `rpc_method` which failed with `error` should be retried.
+    pub fn should_retry(&self, rpc_method: &str, error: &Error) -> bool {
+        if rpc_method == PUT_DEPLOY_METHOD && !self.retry_put_deploy {
+            return false;
+        }
+        match error {
+            Error::FailedToGetResponse { .. } | Error::TransportFailure { .. } => true,
+            Error::ResponseIsHttpError { error, .. } => error.status().map_or(true, |status| {
+                status.is_server_error()
+                    || status == StatusCode::TOO_MANY_REQUESTS
+                    || status == StatusCode::REQUEST_TIMEOUT
+            }),
+            _ => false,
+        }
+    }
+
+    /// Returns the delay before the given retry, where `retry` is `1` for the first retry.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let exponent = retry.saturating_sub(1).min(31);
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(1 << exponent)
+            .min(self.max_backoff);
+        if self.jitter && !backoff.is_zero() {
+            let half = backoff / 2;
+            half + rand::thread_rng().gen_range(Duration::ZERO..=half)
+        } else {
+            backoff
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: Self::DEFAULT_MAX_RETRIES,
+            initial_backoff: Self::DEFAULT_INITIAL_BACKOFF,
+            max_backoff: Self::DEFAULT_MAX_BACKOFF,
+            jitter: true,
+            retry_put_deploy: false,
+        }
+    }
+}
+
+/// A [`Transport`] which retries failed requests sent via an inner transport according to a
+/// [`RetryPolicy`].
+#[derive(Debug)]
+pub struct RetryingTransport<T> {
+    inner: T,
+    policy: RetryPolicy,
+}
+
+impl<T: Transport> RetryingTransport<T> {
+    /// Constructs a new `RetryingTransport` which sends requests via `inner`, retrying according
+    /// to `policy`.
+    pub fn new(inner: T, policy: RetryPolicy) -> Self {
+        RetryingTransport { inner, policy }
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl<T: Transport> Transport for RetryingTransport<T> {
+    async fn send(
+        &self,
+        url: &str,
+        rpc_id: &JsonRpcId,
+        rpc_method: &'static str,
+        request: &JsonRpc,
+    ) -> Result<JsonRpc, Error> {
+        let mut retry = 0;
+        loop {
+            match self.inner.send(url, rpc_id, rpc_method, request).await {
+                Ok(response) => return Ok(response),
+                Err(error) => {
+                    if retry >= self.policy.max_retries
+                        || !self.policy.should_retry(rpc_method, &error)
+                    {
+                        return Err(error);
+                    }
+                    retry += 1;
+                    tokio::time::sleep(self.policy.backoff(retry)).await;
+                }
+            }
+        }
+    }
+
+    /// Retries the batch as a whole, and only if every request in it may be retried.
+    async fn send_batch(
+        &self,
+        url: &str,
+        requests: &[BatchRequest],
+    ) -> Result<Vec<JsonRpc>, Error> {
+        let mut retry = 0;
+        loop {
+            match self.inner.send_batch(url, requests).await {
+                Ok(responses) => return Ok(responses),
+                Err(error) => {
+                    if retry >= self.policy.max_retries
+                        || !requests.iter().all(|batch_request| {
+                            self.policy.should_retry(batch_request.rpc_method, &error)
+                        })
+                    {
+                        return Err(error);
+                    }
+                    retry += 1;
+                    tokio::time::sleep(self.policy.backoff(retry)).await;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct FailingTransport {
+        attempts: AtomicU32,
+    }
+
+    #[async_trait]
+    impl Transport for FailingTransport {
+        async fn send(
+            &self,
+            _url: &str,
+            rpc_id: &JsonRpcId,
+            rpc_method: &'static str,
+            _request: &JsonRpc,
+        ) -> Result<JsonRpc, Error> {
+            self.attempts.fetch_add(1, Ordering::SeqCst);
+            Err(Error::TransportFailure {
+                rpc_id: rpc_id.clone(),
+                rpc_method,
+                error: "connection reset".into(),
+            })
+        }
+    }
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy::new()
+            .with_max_retries(2)
+            .with_initial_backoff(Duration::from_millis(1))
+            .with_jitter(false)
+    }
+
+    #[test]
+    fn should_back_off_exponentially_up_to_max() {
+        let policy = RetryPolicy::new()
+            .with_initial_backoff(Duration::from_millis(100))
+            .with_max_backoff(Duration::from_millis(350))
+            .with_jitter(false);
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(350));
+        assert_eq!(policy.backoff(100), Duration::from_millis(350));
+
+        let policy = policy.with_jitter(true);
+        for retry in 1..5 {
+            let backoff = policy.backoff(retry);
+            assert!(backoff <= Duration::from_millis(350));
+            assert!(backoff >= Duration::from_millis(50));
+        }
+    }
+
+    #[tokio::test]
+    async fn should_retry_read_only_rpc() {
+        let transport = RetryingTransport::new(FailingTransport::default(), policy());
+        let request = JsonRpc::request(&JsonRpcId::from(1), "info_get_status");
+        let _ = transport
+            .send("url", &JsonRpcId::from(1), "info_get_status", &request)
+            .await
+            .unwrap_err();
+        assert_eq!(transport.inner.attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn should_not_retry_put_deploy_by_default() {
+        let transport = RetryingTransport::new(FailingTransport::default(), policy());
+        let request = JsonRpc::request(&JsonRpcId::from(1), PUT_DEPLOY_METHOD);
+        let _ = transport
+            .send("url", &JsonRpcId::from(1), PUT_DEPLOY_METHOD, &request)
+            .await
+            .unwrap_err();
+        assert_eq!(transport.inner.attempts.load(Ordering::SeqCst), 1);
+
+        let transport = RetryingTransport::new(
+            FailingTransport::default(),
+            policy().with_put_deploy_retries(true),
+        );
+        let _ = transport
+            .send("url", &JsonRpcId::from(1), PUT_DEPLOY_METHOD, &request)
+            .await
+            .unwrap_err();
+        assert_eq!(transport.inner.attempts.load(Ordering::SeqCst), 3);
+    }
+}
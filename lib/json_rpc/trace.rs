@@ -0,0 +1,109 @@
+use std::fmt::{self, Display, Formatter};
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+
+use jsonrpc_lite::JsonRpc;
+use serde::Serialize;
+use tracing::Span;
+
+use crate::{Error, Verbosity};
+
+/// The status recorded for a successful response, or for a batch which received a response.
+pub(crate) const SUCCESS_STATUS: &str = "success";
+/// The status recorded for a request which failed before a response was received.
+const FAILED_STATUS: &str = "failed";
+
+/// Returns a debug-level span covering a single JSON-RPC request (or batch of requests) sent to
+/// `node_address`.
+pub(crate) fn rpc_span(rpc_method: &'static str, rpc_id: &dyn Display, node_address: &str) -> Span {
+    tracing::debug_span!("rpc", rpc_method, rpc_id = %rpc_id, node = %node_address)
+}
+
+/// Emits a debug-level event with the JSON-encoded `payload`.
+///
+/// The payload is only encoded if the event is enabled.  For `Verbosity::High` it is encoded in
+/// full, otherwise long string fields are shortened to a string indicating the character count of
+/// the field.
+pub(crate) fn payload<T: ?Sized + Serialize>(
+    message: &'static str,
+    payload: &T,
+    verbosity: Verbosity,
+) {
+    let payload = Payload { payload, verbosity };
+    tracing::debug!(payload = %payload, "{}", message);
+}
+
+/// Emits a debug-level event recording the status of the response (or failure) and the time
+/// taken since `timer` was started.
+pub(crate) fn outcome(status: &'static str, timer: &Timer) {
+    tracing::debug!(
+        status,
+        latency_ms = timer.elapsed_millis(),
+        "request completed"
+    );
+}
+
+/// Emits a debug-level event recording the failure of a request.
+pub(crate) fn failure(error: &Error, timer: &Timer) {
+    tracing::debug!(
+        status = FAILED_STATUS,
+        latency_ms = timer.elapsed_millis(),
+        %error,
+        "request failed"
+    );
+}
+
+/// Returns the status to be recorded for the given response.
+pub(crate) fn status(response: &JsonRpc) -> &'static str {
+    match response {
+        JsonRpc::Success(_) => SUCCESS_STATUS,
+        JsonRpc::Error(_) => "rpc_error",
+        JsonRpc::Request(_) | JsonRpc::Notification(_) => "invalid",
+    }
+}
+
+/// Measures the latency of a request.
+///
+/// There is no monotonic clock available on `wasm32`, so latency is not recorded there.
+pub(crate) struct Timer {
+    #[cfg(not(target_arch = "wasm32"))]
+    start: Instant,
+}
+
+impl Timer {
+    pub(crate) fn start() -> Self {
+        Timer {
+            #[cfg(not(target_arch = "wasm32"))]
+            start: Instant::now(),
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn elapsed_millis(&self) -> Option<u64> {
+        Some(u64::try_from(self.start.elapsed().as_millis()).unwrap_or(u64::MAX))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn elapsed_millis(&self) -> Option<u64> {
+        None
+    }
+}
+
+/// A payload which is only JSON-encoded when displayed.
+struct Payload<'a, T: ?Sized> {
+    payload: &'a T,
+    verbosity: Verbosity,
+}
+
+impl<'a, T: ?Sized + Serialize> Display for Payload<'a, T> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        let encoded = match self.verbosity {
+            Verbosity::High => serde_json::to_string_pretty(self.payload),
+            Verbosity::Low | Verbosity::Medium => casper_types::json_pretty_print(self.payload),
+        };
+        match encoded {
+            Ok(encoded) => formatter.write_str(&encoded),
+            Err(error) => write!(formatter, "<failed to encode payload: {}>", error),
+        }
+    }
+}
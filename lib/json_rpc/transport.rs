@@ -0,0 +1,317 @@
+use std::{fmt::Debug, sync::Arc};
+
+use async_trait::async_trait;
+use jsonrpc_lite::JsonRpc;
+use once_cell::sync::OnceCell;
+use reqwest::Client;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+#[cfg(not(target_arch = "wasm32"))]
+use super::HttpConfig;
+use crate::{Error, JsonRpcId};
+
+/// Statically declared client used by the default [`HttpTransport`] when making HTTP requests so
+/// opened connections are pooled.
+static CLIENT: OnceCell<Client> = OnceCell::new();
+
+/// The method name used in errors relating to a batch as a whole rather than to one of its
+/// requests.
+pub(crate) const BATCH_RPC_METHOD: &str = "batch";
+
+/// A single request within a batch sent via [`Transport::send_batch`].
+#[derive(Clone, Debug)]
+pub struct BatchRequest {
+    /// The JSON-RPC ID of the request.
+    pub rpc_id: JsonRpcId,
+    /// The JSON-RPC method of the request.
+    pub rpc_method: &'static str,
+    /// The JSON-RPC request.
+    pub request: JsonRpc,
+}
+
+/// A means of delivering a JSON-RPC request to a node and receiving the node's response.
+///
+/// The default implementation is [`HttpTransport`], which POSTs the request to the node using
+/// `reqwest`.  Custom implementations can be used to provide a different HTTP stack, to add
+/// instrumentation, or to serve canned responses in tests without a running node.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait Transport: Debug + Send + Sync {
+    /// Sends `request` to the JSON-RPC endpoint at `url` and returns the node's response.
+    ///
+    /// `rpc_id` and `rpc_method` identify the request, and should be used to populate any
+    /// returned [`Error`].
+    async fn send(
+        &self,
+        url: &str,
+        rpc_id: &JsonRpcId,
+        rpc_method: &'static str,
+        request: &JsonRpc,
+    ) -> Result<JsonRpc, Error>;
+
+    /// Sends all of `requests` to the JSON-RPC endpoint at `url` and returns the node's responses,
+    /// in any order.
+    ///
+    /// The default implementation sends each request individually via [`send`](Self::send),
+    /// returning the first error encountered.  Implementations able to send a JSON-RPC batch in a
+    /// single round trip should override this.
+    async fn send_batch(
+        &self,
+        url: &str,
+        requests: &[BatchRequest],
+    ) -> Result<Vec<JsonRpc>, Error> {
+        let mut responses = Vec::with_capacity(requests.len());
+        for batch_request in requests {
+            let response = self
+                .send(
+                    url,
+                    &batch_request.rpc_id,
+                    batch_request.rpc_method,
+                    &batch_request.request,
+                )
+                .await?;
+            responses.push(response);
+        }
+        Ok(responses)
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl<T: Transport + ?Sized> Transport for Box<T> {
+    async fn send(
+        &self,
+        url: &str,
+        rpc_id: &JsonRpcId,
+        rpc_method: &'static str,
+        request: &JsonRpc,
+    ) -> Result<JsonRpc, Error> {
+        (**self).send(url, rpc_id, rpc_method, request).await
+    }
+
+    async fn send_batch(
+        &self,
+        url: &str,
+        requests: &[BatchRequest],
+    ) -> Result<Vec<JsonRpc>, Error> {
+        (**self).send_batch(url, requests).await
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl<T: Transport + ?Sized> Transport for Arc<T> {
+    async fn send(
+        &self,
+        url: &str,
+        rpc_id: &JsonRpcId,
+        rpc_method: &'static str,
+        request: &JsonRpc,
+    ) -> Result<JsonRpc, Error> {
+        (**self).send(url, rpc_id, rpc_method, request).await
+    }
+
+    async fn send_batch(
+        &self,
+        url: &str,
+        requests: &[BatchRequest],
+    ) -> Result<Vec<JsonRpc>, Error> {
+        (**self).send_batch(url, requests).await
+    }
+}
+
+/// The default [`Transport`], sending requests via HTTP using a `reqwest::Client`.
+#[derive(Clone, Debug)]
+pub struct HttpTransport {
+    client: Client,
+}
+
+impl HttpTransport {
+    /// Constructs a new `HttpTransport` which uses the given `client` to make HTTP requests.
+    pub fn new(client: Client) -> Self {
+        HttpTransport { client }
+    }
+
+    /// Constructs a new `HttpTransport` whose HTTP client is configured with the given `config`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_config(config: &HttpConfig) -> Result<Self, Error> {
+        Ok(HttpTransport::new(config.build_client()?))
+    }
+
+    /// POSTs `body` as JSON to `url` and parses the response body as JSON.
+    async fn post<B: Serialize + ?Sized, R: DeserializeOwned>(
+        &self,
+        url: &str,
+        rpc_id: &JsonRpcId,
+        rpc_method: &'static str,
+        body: &B,
+    ) -> Result<R, Error> {
+        let http_response = self
+            .client
+            .post(url)
+            .json(body)
+            .send()
+            .await
+            .map_err(|error| Error::FailedToGetResponse {
+                rpc_id: rpc_id.clone(),
+                rpc_method,
+                error,
+            })?;
+
+        if let Err(error) = http_response.error_for_status_ref() {
+            return Err(Error::ResponseIsHttpError {
+                rpc_id: rpc_id.clone(),
+                rpc_method,
+                error,
+            });
+        }
+
+        http_response
+            .json()
+            .await
+            .map_err(|error| Error::FailedToParseResponse {
+                rpc_id: rpc_id.clone(),
+                rpc_method,
+                error,
+            })
+    }
+}
+
+impl Default for HttpTransport {
+    /// Constructs an `HttpTransport` which shares a process-wide `reqwest::Client`.
+    fn default() -> Self {
+        HttpTransport::new(CLIENT.get_or_init(Client::new).clone())
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl Transport for HttpTransport {
+    async fn send(
+        &self,
+        url: &str,
+        rpc_id: &JsonRpcId,
+        rpc_method: &'static str,
+        request: &JsonRpc,
+    ) -> Result<JsonRpc, Error> {
+        self.post(url, rpc_id, rpc_method, request).await
+    }
+
+    async fn send_batch(
+        &self,
+        url: &str,
+        requests: &[BatchRequest],
+    ) -> Result<Vec<JsonRpc>, Error> {
+        let rpc_id = match requests.first() {
+            Some(batch_request) => &batch_request.rpc_id,
+            None => return Ok(vec![]),
+        };
+        let batch: Vec<&JsonRpc> = requests
+            .iter()
+            .map(|batch_request| &batch_request.request)
+            .collect();
+        let response: BatchResponseBody = self.post(url, rpc_id, BATCH_RPC_METHOD, &batch).await?;
+        match response {
+            BatchResponseBody::Batch(responses) => Ok(responses),
+            BatchResponseBody::Single(response) => Ok(vec![response]),
+        }
+    }
+}
+
+/// The body of the node's response to a batch, which is a single error response rather than an
+/// array if the batch as a whole was rejected.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BatchResponseBody {
+    Batch(Vec<JsonRpc>),
+    Single(JsonRpc),
+}
+
+#[cfg(all(test, feature = "tokio"))]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::Verbosity;
+
+    /// A transport which never touches the network, instead replying with a canned result.
+    #[derive(Debug)]
+    struct InMemoryTransport {
+        result: Value,
+    }
+
+    #[async_trait]
+    impl Transport for InMemoryTransport {
+        async fn send(
+            &self,
+            url: &str,
+            rpc_id: &JsonRpcId,
+            rpc_method: &'static str,
+            request: &JsonRpc,
+        ) -> Result<JsonRpc, Error> {
+            assert_eq!(url, "http://localhost:7777/rpc");
+            assert_eq!(request.get_method(), Some(rpc_method));
+            Ok(JsonRpc::success(rpc_id, &self.result))
+        }
+    }
+
+    #[tokio::test]
+    async fn should_send_via_custom_transport() {
+        let transport = InMemoryTransport {
+            result: json!({
+                "api_version": "1.5.0",
+                "peers": [{ "node_id": "tls:0102", "address": "127.0.0.1:22101" }]
+            }),
+        };
+        let response = crate::get_peers_with_transport(
+            &transport,
+            JsonRpcId::from(1),
+            "http://localhost:7777",
+            Verbosity::Low,
+        )
+        .await
+        .expect("should succeed");
+        assert_eq!(response.id, JsonRpcId::from(1));
+        assert_eq!(response.result.peers.len(), 1);
+        assert_eq!(response.result.peers[0].address, "127.0.0.1:22101");
+    }
+
+    #[tokio::test]
+    async fn should_return_transport_error() {
+        #[derive(Debug)]
+        struct FailingTransport;
+
+        #[async_trait]
+        impl Transport for FailingTransport {
+            async fn send(
+                &self,
+                _url: &str,
+                rpc_id: &JsonRpcId,
+                rpc_method: &'static str,
+                _request: &JsonRpc,
+            ) -> Result<JsonRpc, Error> {
+                Err(Error::TransportFailure {
+                    rpc_id: rpc_id.clone(),
+                    rpc_method,
+                    error: "unreachable".into(),
+                })
+            }
+        }
+
+        let error = crate::get_node_status_with_transport(
+            &FailingTransport,
+            JsonRpcId::from(2),
+            "http://localhost:7777",
+            Verbosity::Low,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::TransportFailure {
+                rpc_method: "info_get_status",
+                ..
+            }
+        ));
+    }
+}
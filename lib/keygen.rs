@@ -4,10 +4,12 @@ use std::{fs, path::Path};
 
 use casper_types::{AsymmetricType, PublicKey, SecretKey};
 
-use crate::Error;
+use crate::{keystore::Keystore, Error};
 
 /// Default filename for the PEM-encoded secret key file.
 pub const SECRET_KEY_PEM: &str = "secret_key.pem";
+/// Default filename for the password-encrypted secret key keystore file.
+pub const SECRET_KEY_KEYSTORE: &str = "secret_key.keystore";
 /// Default filename for the hex-encoded public key file.
 pub const PUBLIC_KEY_HEX: &str = "public_key_hex";
 /// Default filename for the PEM-encoded public key file.
@@ -16,6 +18,10 @@ pub const PUBLIC_KEY_PEM: &str = "public_key.pem";
 /// List of keygen related filenames: "secret_key.pem", "public_key.pem" and "public_key_hex".
 pub const FILES: [&str; 3] = [SECRET_KEY_PEM, PUBLIC_KEY_PEM, PUBLIC_KEY_HEX];
 
+/// List of keygen related filenames when the secret key is encrypted: "secret_key.keystore",
+/// "public_key.pem" and "public_key_hex".
+pub const ENCRYPTED_FILES: [&str; 3] = [SECRET_KEY_KEYSTORE, PUBLIC_KEY_PEM, PUBLIC_KEY_HEX];
+
 /// Name of Ed25519 algorithm.
 pub const ED25519: &str = "Ed25519";
 /// Name of secp256k1 algorithm.
@@ -31,6 +37,29 @@ pub const SECP256K1: &str = "secp256k1";
 /// If `force` is true, existing files will be overwritten. If `force` is false and any of the
 /// files exist, [`Error::FileAlreadyExists`] is returned and no files are written.
 pub fn generate_files(output_dir: &str, algorithm: &str, force: bool) -> Result<(), Error> {
+    generate(output_dir, algorithm, None, force)
+}
+
+/// Generates a new asymmetric key pair using the specified algorithm, and writes them to files in
+/// the specified directory, with the secret key encrypted under `password`.
+///
+/// As per [`generate_files`], except that the secret key is written as a
+/// [`Keystore`] to "secret_key.keystore" rather than in plaintext to "secret_key.pem".
+pub fn generate_encrypted_files(
+    output_dir: &str,
+    algorithm: &str,
+    password: &str,
+    force: bool,
+) -> Result<(), Error> {
+    generate(output_dir, algorithm, Some(password), force)
+}
+
+fn generate(
+    output_dir: &str,
+    algorithm: &str,
+    maybe_password: Option<&str>,
+    force: bool,
+) -> Result<(), Error> {
     if output_dir.is_empty() {
         return Err(Error::EmptyKeygenPath);
     }
@@ -45,8 +74,13 @@ pub fn generate_files(output_dir: &str, algorithm: &str, force: bool) -> Result<
             error,
         })?;
 
+    let files = if maybe_password.is_some() {
+        ENCRYPTED_FILES
+    } else {
+        FILES
+    };
     if !force {
-        for file in FILES.iter().map(|filename| output_dir.join(filename)) {
+        for file in files.iter().map(|filename| output_dir.join(filename)) {
             if file.exists() {
                 return Err(Error::FileAlreadyExists(file));
             }
@@ -72,13 +106,22 @@ pub fn generate_files(output_dir: &str, algorithm: &str, force: bool) -> Result<
         error,
     })?;
 
-    let secret_key_path = output_dir.join(SECRET_KEY_PEM);
-    secret_key
-        .to_file(secret_key_path)
-        .map_err(|error| Error::CryptoError {
-            context: "secret_key",
+    if let Some(password) = maybe_password {
+        let keystore_path = output_dir.join(SECRET_KEY_KEYSTORE);
+        let keystore = Keystore::encrypt(&secret_key, password)?;
+        fs::write(&keystore_path, keystore.to_json()?).map_err(|error| Error::IoError {
+            context: format!("unable to write keystore file at {:?}", keystore_path),
             error,
         })?;
+    } else {
+        let secret_key_path = output_dir.join(SECRET_KEY_PEM);
+        secret_key
+            .to_file(secret_key_path)
+            .map_err(|error| Error::CryptoError {
+                context: "secret_key",
+                error,
+            })?;
+    }
 
     let public_key_path = output_dir.join(PUBLIC_KEY_PEM);
     public_key
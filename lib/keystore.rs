@@ -0,0 +1,373 @@
+//! Password-encrypted keystore files for secret keys.
+//!
+//! A keystore is a JSON file holding a PEM-encoded secret key encrypted with ChaCha20-Poly1305,
+//! under a key derived from a password using scrypt.  Wherever the client reads a secret key file,
+//! it detects whether the file is a keystore or a plaintext PEM file; for a keystore, the password
+//! is read as per [`read_password`].
+
+use std::{env, fs, io::Write, path::Path};
+
+use chacha20poly1305::{
+    aead::{Aead, KeyInit},
+    ChaCha20Poly1305, Key, Nonce,
+};
+use hex_buffer_serde::{Hex, HexForm};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+use casper_types::SecretKey;
+
+use crate::{Error, OutputKind};
+
+/// Name of the environment variable from which the keystore password is read, if set.
+pub const PASSWORD_ENV_VAR: &str = "CASPER_CLIENT_KEYSTORE_PASSWORD";
+/// Name of the environment variable holding the path to a file from which the keystore password
+/// is read, if set.
+pub const PASSWORD_FILE_ENV_VAR: &str = "CASPER_CLIENT_KEYSTORE_PASSWORD_FILE";
+
+/// The current version of the keystore format.
+const VERSION: u8 = 1;
+/// The base-2 logarithm of the scrypt CPU/memory cost parameter used for new keystores.
+const SCRYPT_LOG_N: u8 = 15;
+/// The scrypt block size parameter used for new keystores.
+const SCRYPT_R: u32 = 8;
+/// The scrypt parallelization parameter used for new keystores.
+const SCRYPT_P: u32 = 1;
+/// The maximum base-2 logarithm of the scrypt CPU/memory cost parameter accepted from a keystore.
+///
+/// Together with the other maximums, this bounds the memory used to derive the key to 1 GiB, so
+/// that a crafted keystore can't exhaust the memory or CPU of the client.
+const MAX_SCRYPT_LOG_N: u8 = 20;
+/// The maximum scrypt block size parameter accepted from a keystore.
+const MAX_SCRYPT_R: u32 = 8;
+/// The maximum scrypt parallelization parameter accepted from a keystore.
+const MAX_SCRYPT_P: u32 = 1;
+const SALT_LENGTH: usize = 32;
+const NONCE_LENGTH: usize = 12;
+const KEY_LENGTH: usize = 32;
+
+/// The key derivation function used to derive the encryption key from the password.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+enum Kdf {
+    Scrypt {
+        log_n: u8,
+        r: u32,
+        p: u32,
+        #[serde(with = "HexForm")]
+        salt: Vec<u8>,
+    },
+}
+
+impl Kdf {
+    fn derive_key(&self, password: &str) -> Result<Zeroizing<[u8; KEY_LENGTH]>, Error> {
+        let Kdf::Scrypt { log_n, r, p, salt } = self;
+        if *log_n > MAX_SCRYPT_LOG_N || *r > MAX_SCRYPT_R || *p > MAX_SCRYPT_P {
+            return Err(Error::InvalidKeystore(format!(
+                "scrypt params exceed the maximum of log_n {}, r {} and p {}",
+                MAX_SCRYPT_LOG_N, MAX_SCRYPT_R, MAX_SCRYPT_P
+            )));
+        }
+        let params = scrypt::Params::new(*log_n, *r, *p, KEY_LENGTH)
+            .map_err(|error| Error::InvalidKeystore(format!("invalid scrypt params: {}", error)))?;
+        let mut key = Zeroizing::new([0; KEY_LENGTH]);
+        scrypt::scrypt(password.as_bytes(), salt, &params, key.as_mut())
+            .map_err(|error| Error::InvalidKeystore(format!("invalid scrypt params: {}", error)))?;
+        Ok(key)
+    }
+}
+
+/// A secret key encrypted under a password.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Keystore {
+    version: u8,
+    kdf: Kdf,
+    #[serde(with = "HexForm")]
+    nonce: Vec<u8>,
+    #[serde(with = "HexForm")]
+    ciphertext: Vec<u8>,
+}
+
+impl Keystore {
+    /// Encrypts `secret_key` under `password`, which must not be empty.
+    pub fn encrypt(secret_key: &SecretKey, password: &str) -> Result<Self, Error> {
+        if password.is_empty() {
+            return Err(Error::EmptyKeystorePassword);
+        }
+        let mut rng = rand::thread_rng();
+        let kdf = Kdf::Scrypt {
+            log_n: SCRYPT_LOG_N,
+            r: SCRYPT_R,
+            p: SCRYPT_P,
+            salt: rng.gen::<[u8; SALT_LENGTH]>().to_vec(),
+        };
+        let nonce = rng.gen::<[u8; NONCE_LENGTH]>().to_vec();
+
+        let plaintext =
+            Zeroizing::new(secret_key.to_pem().map_err(|error| Error::CryptoError {
+                context: "encrypting secret key",
+                error,
+            })?);
+        let cipher = ChaCha20Poly1305::new(Key::from_slice(kdf.derive_key(password)?.as_ref()));
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce), plaintext.as_bytes())
+            .map_err(|_| Error::InvalidKeystore("failed to encrypt secret key".to_string()))?;
+
+        Ok(Keystore {
+            version: VERSION,
+            kdf,
+            nonce,
+            ciphertext,
+        })
+    }
+
+    /// Decrypts the secret key using `password`.
+    ///
+    /// Returns [`Error::KeystoreDecryptionFailed`] if the password is wrong.
+    pub fn decrypt(&self, password: &str) -> Result<SecretKey, Error> {
+        if self.version != VERSION {
+            return Err(Error::InvalidKeystore(format!(
+                "unsupported version {}",
+                self.version
+            )));
+        }
+        if self.nonce.len() != NONCE_LENGTH {
+            return Err(Error::InvalidKeystore(format!(
+                "nonce must be {} bytes",
+                NONCE_LENGTH
+            )));
+        }
+        let key = self.kdf.derive_key(password)?;
+        let cipher = ChaCha20Poly1305::new(Key::from_slice(key.as_ref()));
+        let plaintext = Zeroizing::new(
+            cipher
+                .decrypt(Nonce::from_slice(&self.nonce), self.ciphertext.as_slice())
+                .map_err(|_| Error::KeystoreDecryptionFailed)?,
+        );
+        SecretKey::from_pem(plaintext.as_slice()).map_err(|error| Error::CryptoError {
+            context: "decrypted secret key",
+            error,
+        })
+    }
+
+    /// Returns the keystore as pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, Error> {
+        serde_json::to_string_pretty(self).map_err(|error| Error::FailedToEncodeToJson {
+            context: "writing keystore",
+            error,
+        })
+    }
+}
+
+/// Reads a secret key from a file which is either a [`Keystore`] or a plaintext PEM file.
+///
+/// If the file is a keystore, the password is read as per [`read_password`].
+pub fn read_secret_key_file<P: AsRef<Path>>(path: P) -> Result<SecretKey, Error> {
+    let path = path.as_ref();
+    let contents = Zeroizing::new(fs::read(path).map_err(|error| Error::IoError {
+        context: format!("unable to read secret key file at {}", path.display()),
+        error,
+    })?);
+    match serde_json::from_slice::<Keystore>(&contents) {
+        Ok(keystore) => {
+            let password = read_password(&format!(
+                "Enter password for keystore at {}: ",
+                path.display()
+            ))?;
+            keystore.decrypt(&password)
+        }
+        Err(_) => SecretKey::from_pem(contents.as_slice()).map_err(|error| Error::CryptoError {
+            context: "secret key",
+            error,
+        }),
+    }
+}
+
+/// Reads the password of a keystore.
+///
+/// The password is taken from the environment variable [`PASSWORD_ENV_VAR`] if set, otherwise
+/// from the file at the path given by [`PASSWORD_FILE_ENV_VAR`] if set (with any trailing newline
+/// removed), otherwise the user is prompted for it on the terminal using `prompt`.
+///
+/// The password is zeroized when dropped.
+pub fn read_password(prompt: &str) -> Result<Zeroizing<String>, Error> {
+    if let Some(password) = password_from_env()? {
+        return Ok(password);
+    }
+    rpassword::prompt_password(prompt)
+        .map(Zeroizing::new)
+        .map_err(|error| Error::IoError {
+            context: "unable to read keystore password".to_string(),
+            error,
+        })
+}
+
+/// Reads the password for a new keystore.
+///
+/// As per [`read_password`], except that if the user is prompted, they must enter the password
+/// twice.  Returns an error if the password is empty.
+pub fn read_new_password() -> Result<Zeroizing<String>, Error> {
+    let password = match password_from_env()? {
+        Some(password) => password,
+        None => {
+            let password = read_password("Enter password for new keystore: ")?;
+            if *read_password("Confirm password: ")? != *password {
+                return Err(Error::KeystorePasswordMismatch);
+            }
+            password
+        }
+    };
+    if password.is_empty() {
+        return Err(Error::EmptyKeystorePassword);
+    }
+    Ok(password)
+}
+
+fn password_from_env() -> Result<Option<Zeroizing<String>>, Error> {
+    if let Ok(password) = env::var(PASSWORD_ENV_VAR) {
+        return Ok(Some(Zeroizing::new(password)));
+    }
+    if let Ok(path) = env::var(PASSWORD_FILE_ENV_VAR) {
+        let contents =
+            Zeroizing::new(fs::read_to_string(&path).map_err(|error| Error::IoError {
+                context: format!("unable to read keystore password file at '{}'", path),
+                error,
+            })?);
+        return Ok(Some(Zeroizing::new(
+            contents.trim_end_matches(['\r', '\n']).to_string(),
+        )));
+    }
+    Ok(None)
+}
+
+/// Reads a plaintext PEM-encoded secret key from a file, encrypts it under `password`, and
+/// outputs the resulting [`Keystore`] to a file or stdout.
+///
+/// `output` specifies the output file and corresponding overwrite behaviour, or if
+/// `OutputKind::Stdout`, causes the keystore to be printed `stdout`.
+pub fn encrypt_secret_key_file<P: AsRef<Path>>(
+    input_path: P,
+    password: &str,
+    output: OutputKind,
+) -> Result<(), Error> {
+    let secret_key = SecretKey::from_file(input_path).map_err(|error| Error::CryptoError {
+        context: "secret key",
+        error,
+    })?;
+    let keystore = Keystore::encrypt(&secret_key, password)?;
+    write_output(keystore.to_json()?.as_bytes(), output)
+}
+
+/// Reads a [`Keystore`] from a file, decrypts it using `password`, and outputs the PEM-encoded
+/// secret key to a file or stdout.
+///
+/// `output` specifies the output file and corresponding overwrite behaviour, or if
+/// `OutputKind::Stdout`, causes the secret key to be printed `stdout`.
+pub fn decrypt_keystore_file<P: AsRef<Path>>(
+    input_path: P,
+    password: &str,
+    output: OutputKind,
+) -> Result<(), Error> {
+    let input_path = input_path.as_ref();
+    let contents = fs::read(input_path).map_err(|error| Error::IoError {
+        context: format!("unable to read keystore file at {}", input_path.display()),
+        error,
+    })?;
+    let keystore: Keystore = serde_json::from_slice(&contents)
+        .map_err(|error| Error::InvalidKeystore(format!("{}: {}", input_path.display(), error)))?;
+    let secret_key = keystore.decrypt(password)?;
+    let pem = Zeroizing::new(secret_key.to_pem().map_err(|error| Error::CryptoError {
+        context: "secret key",
+        error,
+    })?);
+    write_output(pem.as_bytes(), output)
+}
+
+fn write_output(content: &[u8], output: OutputKind) -> Result<(), Error> {
+    output
+        .get()?
+        .write_all(content)
+        .map_err(|error| Error::IoError {
+            context: "unable to write output".to_string(),
+            error,
+        })?;
+    output.commit()
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::PublicKey;
+
+    use super::*;
+
+    #[test]
+    fn should_encrypt_and_decrypt_secret_key() {
+        let secret_key = SecretKey::ed25519_from_bytes([1; 32]).unwrap();
+        let keystore = Keystore::encrypt(&secret_key, "correct horse").unwrap();
+
+        let json = keystore.to_json().unwrap();
+        let keystore: Keystore = serde_json::from_str(&json).unwrap();
+        let decrypted = keystore.decrypt("correct horse").unwrap();
+        assert_eq!(PublicKey::from(&decrypted), PublicKey::from(&secret_key));
+
+        assert!(matches!(
+            keystore.decrypt("battery staple"),
+            Err(Error::KeystoreDecryptionFailed)
+        ));
+        assert!(matches!(
+            Keystore::encrypt(&secret_key, ""),
+            Err(Error::EmptyKeystorePassword)
+        ));
+    }
+
+    #[test]
+    fn should_reject_excessive_scrypt_params() {
+        let secret_key = SecretKey::ed25519_from_bytes([1; 32]).unwrap();
+        let keystore = Keystore::encrypt(&secret_key, "correct horse").unwrap();
+
+        for (log_n, r, p) in [
+            (MAX_SCRYPT_LOG_N + 1, SCRYPT_R, SCRYPT_P),
+            (SCRYPT_LOG_N, MAX_SCRYPT_R + 1, SCRYPT_P),
+            (SCRYPT_LOG_N, SCRYPT_R, MAX_SCRYPT_P + 1),
+        ] {
+            let Kdf::Scrypt { salt, .. } = keystore.kdf.clone();
+            let keystore = Keystore {
+                kdf: Kdf::Scrypt { log_n, r, p, salt },
+                ..keystore.clone()
+            };
+            assert!(matches!(
+                keystore.decrypt("correct horse"),
+                Err(Error::InvalidKeystore(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn should_encrypt_and_decrypt_secret_key_files() {
+        let secret_key = SecretKey::generate_secp256k1().unwrap();
+        let tempdir = tempfile::tempdir().unwrap();
+        let pem_path = tempdir.path().join("secret_key.pem");
+        let keystore_path = tempdir.path().join("secret_key.keystore");
+        let decrypted_path = tempdir.path().join("decrypted.pem");
+        secret_key.to_file(&pem_path).unwrap();
+
+        encrypt_secret_key_file(
+            &pem_path,
+            "password",
+            OutputKind::file(&keystore_path, false),
+        )
+        .unwrap();
+        assert!(SecretKey::from_file(&keystore_path).is_err());
+
+        decrypt_keystore_file(
+            &keystore_path,
+            "password",
+            OutputKind::file(&decrypted_path, false),
+        )
+        .unwrap();
+        let decrypted = read_secret_key_file(&decrypted_path).unwrap();
+        assert_eq!(PublicKey::from(&decrypted), PublicKey::from(&secret_key));
+    }
+}
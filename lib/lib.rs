@@ -13,14 +13,26 @@
 //!   applied to the request and returned in the response.
 //! * <code>node_address: &<a href="https://doc.rust-lang.org/std/primitive.str.html">str</a></code> -
 //!   The hostname or IP and port of the server, e.g. `http://127.0.0.1:7777`.
-//! * <code>verbosity: <a href="enum.Verbosity.html">Verbosity</a></code> - When `Low`, nothing is
-//!   printed to stdout.  For `Medium`, the request and response are printed to `stdout` with long
-//!   string fields (e.g. hex-formatted raw Wasm bytes) shortened to a string indicating the char
-//!   count of the field.  `High` verbosity is the same as `Medium` except without abbreviation of
-//!   long fields.
+//! * <code>verbosity: <a href="enum.Verbosity.html">Verbosity</a></code> - The level of detail of
+//!   the request and response payloads emitted as debug-level `tracing` events (see
+//!   [Tracing](#tracing)).  For `Low` and `Medium`, long string fields (e.g. hex-formatted raw
+//!   Wasm bytes) are shortened to a string indicating the char count of the field.  `High`
+//!   verbosity is the same except without abbreviation of long fields.
 //! * <code>maybe_block_identifier: <a href="https://doc.rust-lang.org/core/option/enum.Option.html">Option</a><<a href="rpcs/common/enum.BlockIdentifier.html">BlockIdentifier</a>></code> -
 //!   The identifier of the [`Block`] to use, either block height or block hash.  If `None`, the
 //!   latest `Block` known on the server will be used.
+//! * <code>transport: &dyn <a href="trait.Transport.html">Transport</a></code> - The means by which
+//!   the request is delivered to the node.  Only the `_with_transport` variants of the functions
+//!   take this parameter; the others use the default [`HttpTransport`].
+//!
+//! # Tracing
+//!
+//! Nothing is printed to stdout by the RPC functions.  Instead, each RPC (or [`Batch`]) is sent
+//! within a debug-level [`tracing`](https://docs.rs/tracing) span named `rpc`, which records the
+//! `rpc_method`, `rpc_id` and `node` of the request.  Within that span, debug-level events are
+//! emitted carrying the request and response as a `payload` field, and on completion the `status`
+//! of the response and the `latency_ms` of the round trip (not recorded on `wasm32`).  Install a
+//! `tracing` subscriber to collect them.
 
 #![doc(
     html_root_url = "https://docs.rs/casper-client/2.0.0",
@@ -36,18 +48,36 @@
 )]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 
+mod approvals_check;
+mod batch;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+mod chainspec_diff;
 pub mod cli;
+mod client;
+#[cfg(feature = "std-fs-io")]
+mod deploy_format;
+mod deploy_inspection;
 mod error;
+#[cfg(feature = "tokio")]
+pub mod event_stream;
 mod json_rpc;
 #[cfg(feature = "std-fs-io")]
 pub mod keygen;
 #[cfg(feature = "std-fs-io")]
+pub mod keystore;
+mod network_limits;
+#[cfg(feature = "std-fs-io")]
 mod output_kind;
 pub mod rpcs;
+mod signer;
 mod transfer_target;
 pub mod types;
 mod validation;
 mod verbosity;
+#[cfg(feature = "tokio")]
+mod wait_for_deploy;
+mod wasm;
 
 #[cfg(feature = "std-fs-io")]
 use std::{
@@ -61,16 +91,43 @@ use serde::Serialize;
 
 use casper_hashing::Digest;
 #[cfg(feature = "std-fs-io")]
-use casper_types::SecretKey;
+use casper_types::bytesrepr::{self, ToBytes};
 #[cfg(doc)]
 use casper_types::Transfer;
 use casper_types::{Key, URef};
 
+pub use approvals_check::{
+    check_approvals, check_approvals_with_transport, ApprovalsCheck, SignerWeight,
+};
+pub use batch::{Batch, BatchHandle, BatchResponse};
+pub use chainspec_diff::{
+    diff_chainspecs, diff_chainspecs_with_transport, ChainspecDiff, ChainspecDifference,
+};
+pub use client::{CasperClient, CasperClientBuilder, RpcIdStrategy};
+#[cfg(feature = "std-fs-io")]
+pub use deploy_format::DeployFormat;
+pub use deploy_inspection::{
+    DeployInspection, InspectedApproval, InspectedArg, InspectedDeployItem,
+};
 pub use error::Error;
 use json_rpc::JsonRpcCall;
-pub use json_rpc::{JsonRpcId, SuccessResponse};
+#[cfg(feature = "std-fs-io")]
+pub use json_rpc::RecordingTransport;
+pub use json_rpc::{
+    BatchRequest, Cassette, HttpTransport, Interaction, JsonRpcId, ReplayTransport,
+    SuccessResponse, Transport,
+};
+#[cfg(not(target_arch = "wasm32"))]
+pub use json_rpc::{HttpConfig, NodeHealth, NodePool};
+#[cfg(feature = "tokio")]
+pub use json_rpc::{RetryPolicy, RetryingTransport};
+pub use network_limits::{
+    discover_network_limits, discover_network_limits_with_transport, NetworkLimits,
+};
 #[cfg(feature = "std-fs-io")]
 pub use output_kind::OutputKind;
+#[cfg(feature = "std-fs-io")]
+use rpcs::results::ChainspecRawBytes;
 use rpcs::{
     common::{BlockIdentifier, GlobalStateIdentifier},
     results::{
@@ -103,14 +160,28 @@ use rpcs::{
     },
     DictionaryItemIdentifier,
 };
+pub use signer::{ExternalSigner, Signer};
 pub use transfer_target::TransferTarget;
-#[cfg(feature = "std-fs-io")]
-use types::MAX_SERIALIZED_SIZE_OF_DEPLOY;
 #[cfg(doc)]
-use types::{Account, Block, StoredValue};
+use types::{Account, Block, DeployBuilder, StoredValue};
 use types::{Deploy, DeployHash};
+#[cfg(feature = "std-fs-io")]
+use types::{TimeDiff, Timestamp, MAX_SERIALIZED_SIZE_OF_DEPLOY};
 pub use validation::ValidateResponseError;
 pub use verbosity::Verbosity;
+#[cfg(feature = "tokio")]
+pub use wait_for_deploy::{wait_for_deploy, wait_for_deploy_with_transport, DeployOutcome};
+pub use wasm::{
+    strip_wasm_debug_sections, validate_wasm, WasmCustomSection, WasmExport, WasmExternalKind,
+    WasmImport, WasmInspection, WasmLimits, WasmMemory,
+};
+
+#[cfg(feature = "std-fs-io")]
+const CHAINSPEC_FILE_NAME: &str = "chainspec.toml";
+#[cfg(feature = "std-fs-io")]
+const GENESIS_ACCOUNTS_FILE_NAME: &str = "accounts.toml";
+#[cfg(feature = "std-fs-io")]
+const GLOBAL_STATE_FILE_NAME: &str = "global_state.toml";
 
 /// Puts a [`Deploy`] to the network for execution.
 ///
@@ -123,7 +194,25 @@ pub async fn put_deploy(
     verbosity: Verbosity,
     deploy: Deploy,
 ) -> Result<SuccessResponse<PutDeployResult>, Error> {
-    JsonRpcCall::new(rpc_id, node_address, verbosity)
+    put_deploy_with_transport(
+        &HttpTransport::default(),
+        rpc_id,
+        node_address,
+        verbosity,
+        deploy,
+    )
+    .await
+}
+
+/// Same as [`put_deploy`], but sends the request via the given `transport`.
+pub async fn put_deploy_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    deploy: Deploy,
+) -> Result<SuccessResponse<PutDeployResult>, Error> {
+    JsonRpcCall::new(transport, rpc_id, node_address, verbosity)
         .send_request(PUT_DEPLOY_METHOD, Some(PutDeployParams::new(deploy)))
         .await
 }
@@ -140,7 +229,27 @@ pub async fn speculative_exec(
     verbosity: Verbosity,
     deploy: Deploy,
 ) -> Result<SuccessResponse<SpeculativeExecResult>, Error> {
-    JsonRpcCall::new(rpc_id, node_address, verbosity)
+    speculative_exec_with_transport(
+        &HttpTransport::default(),
+        rpc_id,
+        node_address,
+        block_identifier,
+        verbosity,
+        deploy,
+    )
+    .await
+}
+
+/// Same as [`speculative_exec`], but sends the request via the given `transport`.
+pub async fn speculative_exec_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    block_identifier: Option<BlockIdentifier>,
+    verbosity: Verbosity,
+    deploy: Deploy,
+) -> Result<SuccessResponse<SpeculativeExecResult>, Error> {
+    JsonRpcCall::new(transport, rpc_id, node_address, verbosity)
         .send_request(
             SPECULATIVE_EXEC_METHOD,
             Some(SpeculativeExecParams::new(block_identifier, deploy)),
@@ -154,15 +263,24 @@ pub async fn speculative_exec(
 /// and then read and sent to the network for execution using [`read_deploy_file`] and
 /// [`put_deploy`] respectively.
 ///
+/// `format` specifies whether the `Deploy` is written as JSON or in its binary `bytesrepr`
+/// encoding.
+///
 /// `output` specifies the output file and corresponding overwrite behaviour, or if
 /// `OutputKind::Stdout`, causes the `Deploy` to be printed `stdout`.
 #[cfg(feature = "std-fs-io")]
-pub fn output_deploy(output: OutputKind, deploy: &Deploy) -> Result<(), Error> {
-    write_deploy(deploy, output.get()?)?;
+pub fn output_deploy(
+    output: OutputKind,
+    format: DeployFormat,
+    deploy: &Deploy,
+) -> Result<(), Error> {
+    write_deploy(deploy, format, output.get()?)?;
     output.commit()
 }
 
 /// Reads a previously-saved [`Deploy`] from a file.
+///
+/// The file may be in either [`DeployFormat`], which is detected automatically.
 #[cfg(feature = "std-fs-io")]
 pub fn read_deploy_file<P: AsRef<Path>>(deploy_path: P) -> Result<Deploy, Error> {
     let input = fs::read(deploy_path.as_ref()).map_err(|error| Error::IoError {
@@ -175,8 +293,11 @@ pub fn read_deploy_file<P: AsRef<Path>>(deploy_path: P) -> Result<Deploy, Error>
     read_deploy(Cursor::new(input))
 }
 
-/// Reads a previously-saved [`Deploy`] from a file, cryptographically signs it, and outputs it
-/// to a file or stdout.
+/// Reads a previously-saved [`Deploy`] from a file, cryptographically signs it using `signer`,
+/// and outputs it to a file or stdout.
+///
+/// `format` specifies whether the signed `Deploy` is written as JSON or in its binary `bytesrepr`
+/// encoding, regardless of the format of the input file.
 ///
 /// `output` specifies the output file and corresponding overwrite behaviour, or if
 /// `OutputKind::Stdout`, causes the `Deploy` to be printed `stdout`.
@@ -186,15 +307,88 @@ pub fn read_deploy_file<P: AsRef<Path>>(deploy_path: P) -> Result<Deploy, Error>
 #[cfg(feature = "std-fs-io")]
 pub fn sign_deploy_file<P: AsRef<Path>>(
     input_path: P,
-    secret_key: &SecretKey,
+    signer: &dyn Signer,
+    format: DeployFormat,
     output: OutputKind,
 ) -> Result<(), Error> {
     let mut deploy = read_deploy_file(input_path)?;
 
-    deploy.sign(secret_key);
+    deploy.sign(signer)?;
+    deploy.is_valid_size(MAX_SERIALIZED_SIZE_OF_DEPLOY)?;
+
+    write_deploy(&deploy, format, output.get()?)?;
+    output.commit()
+}
+
+/// Reads a previously-saved [`Deploy`] from a file, re-stamps it with a new timestamp, and
+/// optionally a new TTL, chain name and gas price, cryptographically signs it using each of
+/// `signers`, and outputs it to a file or stdout.
+///
+/// The approvals of the original deploy are dropped, since they are not valid for the re-stamped
+/// deploy's hash.  For details, see [`Deploy::restamp`].
+///
+/// `format` specifies whether the re-stamped `Deploy` is written as JSON or in its binary
+/// `bytesrepr` encoding, regardless of the format of the input file.
+///
+/// `output` specifies the output file and corresponding overwrite behaviour, or if
+/// `OutputKind::Stdout`, causes the `Deploy` to be printed `stdout`.
+///
+/// The same path can be specified for input and output, and if the operation fails, the original
+/// input file will be left unmodified.
+#[cfg(feature = "std-fs-io")]
+#[allow(clippy::too_many_arguments)]
+pub fn restamp_deploy_file<P: AsRef<Path>>(
+    input_path: P,
+    timestamp: Timestamp,
+    maybe_ttl: Option<TimeDiff>,
+    maybe_chain_name: Option<String>,
+    maybe_gas_price: Option<u64>,
+    signers: &[&dyn Signer],
+    format: DeployFormat,
+    output: OutputKind,
+) -> Result<(), Error> {
+    let mut deploy = read_deploy_file(input_path)?.restamp(
+        timestamp,
+        maybe_ttl,
+        maybe_chain_name,
+        maybe_gas_price,
+    );
+    for signer in signers {
+        deploy.sign(*signer)?;
+    }
+    deploy.is_valid_size(MAX_SERIALIZED_SIZE_OF_DEPLOY)?;
+
+    write_deploy(&deploy, format, output.get()?)?;
+    output.commit()
+}
+
+/// Reads several previously-saved copies of the same [`Deploy`] from files, merges their approvals
+/// into a single deploy, and outputs it to a file or stdout.
+///
+/// `format` specifies whether the merged `Deploy` is written as JSON or in its binary `bytesrepr`
+/// encoding, regardless of the formats of the input files.
+///
+/// `output` specifies the output file and corresponding overwrite behaviour, or if
+/// `OutputKind::Stdout`, causes the `Deploy` to be printed `stdout`.
+///
+/// Returns [`Error::MismatchedDeploys`] if the deploys do not all have the same hash, or an error
+/// if any of them has an invalid body hash, deploy hash or approval, in which case nothing is
+/// output.
+#[cfg(feature = "std-fs-io")]
+pub fn merge_deploy_files<P: AsRef<Path>>(
+    input_paths: &[P],
+    format: DeployFormat,
+    output: OutputKind,
+) -> Result<(), Error> {
+    let (first_path, other_paths) = input_paths.split_first().ok_or(Error::NoDeploysToMerge)?;
+    let mut deploy = read_deploy_file(first_path)?;
+    deploy.verify_hashes_and_approvals()?;
+    for path in other_paths {
+        deploy.merge_approvals(read_deploy_file(path)?)?;
+    }
     deploy.is_valid_size(MAX_SERIALIZED_SIZE_OF_DEPLOY)?;
 
-    write_deploy(&deploy, output.get()?)?;
+    write_deploy(&deploy, format, output.get()?)?;
     output.commit()
 }
 
@@ -213,7 +407,27 @@ pub async fn get_deploy(
     deploy_hash: DeployHash,
     finalized_approvals: bool,
 ) -> Result<SuccessResponse<GetDeployResult>, Error> {
-    JsonRpcCall::new(rpc_id, node_address, verbosity)
+    get_deploy_with_transport(
+        &HttpTransport::default(),
+        rpc_id,
+        node_address,
+        verbosity,
+        deploy_hash,
+        finalized_approvals,
+    )
+    .await
+}
+
+/// Same as [`get_deploy`], but sends the request via the given `transport`.
+pub async fn get_deploy_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    deploy_hash: DeployHash,
+    finalized_approvals: bool,
+) -> Result<SuccessResponse<GetDeployResult>, Error> {
+    JsonRpcCall::new(transport, rpc_id, node_address, verbosity)
         .send_request(
             GET_DEPLOY_METHOD,
             Some(GetDeployParams::new(deploy_hash, finalized_approvals)),
@@ -231,9 +445,27 @@ pub async fn get_block(
     node_address: &str,
     verbosity: Verbosity,
     maybe_block_identifier: Option<BlockIdentifier>,
+) -> Result<SuccessResponse<GetBlockResult>, Error> {
+    get_block_with_transport(
+        &HttpTransport::default(),
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_identifier,
+    )
+    .await
+}
+
+/// Same as [`get_block`], but sends the request via the given `transport`.
+pub async fn get_block_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_block_identifier: Option<BlockIdentifier>,
 ) -> Result<SuccessResponse<GetBlockResult>, Error> {
     let params = maybe_block_identifier.map(GetBlockParams::new);
-    let success_response = JsonRpcCall::new(rpc_id, node_address, verbosity)
+    let success_response = JsonRpcCall::new(transport, rpc_id, node_address, verbosity)
         .send_request(GET_BLOCK_METHOD, params)
         .await?;
     validation::validate_get_block_result(maybe_block_identifier, &success_response.result)?;
@@ -250,9 +482,27 @@ pub async fn get_block_transfers(
     node_address: &str,
     verbosity: Verbosity,
     maybe_block_identifier: Option<BlockIdentifier>,
+) -> Result<SuccessResponse<GetBlockTransfersResult>, Error> {
+    get_block_transfers_with_transport(
+        &HttpTransport::default(),
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_identifier,
+    )
+    .await
+}
+
+/// Same as [`get_block_transfers`], but sends the request via the given `transport`.
+pub async fn get_block_transfers_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_block_identifier: Option<BlockIdentifier>,
 ) -> Result<SuccessResponse<GetBlockTransfersResult>, Error> {
     let params = maybe_block_identifier.map(GetBlockTransfersParams::new);
-    JsonRpcCall::new(rpc_id, node_address, verbosity)
+    JsonRpcCall::new(transport, rpc_id, node_address, verbosity)
         .send_request(GET_BLOCK_TRANSFERS_METHOD, params)
         .await
 }
@@ -267,9 +517,27 @@ pub async fn get_state_root_hash(
     node_address: &str,
     verbosity: Verbosity,
     maybe_block_identifier: Option<BlockIdentifier>,
+) -> Result<SuccessResponse<GetStateRootHashResult>, Error> {
+    get_state_root_hash_with_transport(
+        &HttpTransport::default(),
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_identifier,
+    )
+    .await
+}
+
+/// Same as [`get_state_root_hash`], but sends the request via the given `transport`.
+pub async fn get_state_root_hash_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_block_identifier: Option<BlockIdentifier>,
 ) -> Result<SuccessResponse<GetStateRootHashResult>, Error> {
     let params = maybe_block_identifier.map(GetStateRootHashParams::new);
-    JsonRpcCall::new(rpc_id, node_address, verbosity)
+    JsonRpcCall::new(transport, rpc_id, node_address, verbosity)
         .send_request(GET_STATE_ROOT_HASH_METHOD, params)
         .await
 }
@@ -284,9 +552,27 @@ pub async fn get_era_summary(
     node_address: &str,
     verbosity: Verbosity,
     maybe_block_identifier: Option<BlockIdentifier>,
+) -> Result<SuccessResponse<GetEraSummaryResult>, Error> {
+    get_era_summary_with_transport(
+        &HttpTransport::default(),
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_identifier,
+    )
+    .await
+}
+
+/// Same as [`get_era_summary`], but sends the request via the given `transport`.
+pub async fn get_era_summary_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_block_identifier: Option<BlockIdentifier>,
 ) -> Result<SuccessResponse<GetEraSummaryResult>, Error> {
     let params = maybe_block_identifier.map(GetEraSummaryParams::new);
-    JsonRpcCall::new(rpc_id, node_address, verbosity)
+    JsonRpcCall::new(transport, rpc_id, node_address, verbosity)
         .send_request(GET_ERA_SUMMARY_METHOD, params)
         .await
 }
@@ -312,9 +598,31 @@ pub async fn query_global_state(
     global_state_identifier: Option<GlobalStateIdentifier>,
     key: Key,
     path: Vec<String>,
+) -> Result<SuccessResponse<QueryGlobalStateResult>, Error> {
+    query_global_state_with_transport(
+        &HttpTransport::default(),
+        rpc_id,
+        node_address,
+        verbosity,
+        global_state_identifier,
+        key,
+        path,
+    )
+    .await
+}
+
+/// Same as [`query_global_state`], but sends the request via the given `transport`.
+pub async fn query_global_state_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    global_state_identifier: Option<GlobalStateIdentifier>,
+    key: Key,
+    path: Vec<String>,
 ) -> Result<SuccessResponse<QueryGlobalStateResult>, Error> {
     let params = QueryGlobalStateParams::new(global_state_identifier, key, path);
-    JsonRpcCall::new(rpc_id, node_address, verbosity)
+    JsonRpcCall::new(transport, rpc_id, node_address, verbosity)
         .send_request(QUERY_GLOBAL_STATE_METHOD, Some(params))
         .await
 }
@@ -330,9 +638,29 @@ pub async fn query_balance(
     verbosity: Verbosity,
     maybe_global_state_identifier: Option<GlobalStateIdentifier>,
     purse_identifier: PurseIdentifier,
+) -> Result<SuccessResponse<QueryBalanceResult>, Error> {
+    query_balance_with_transport(
+        &HttpTransport::default(),
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_global_state_identifier,
+        purse_identifier,
+    )
+    .await
+}
+
+/// Same as [`query_balance`], but sends the request via the given `transport`.
+pub async fn query_balance_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_global_state_identifier: Option<GlobalStateIdentifier>,
+    purse_identifier: PurseIdentifier,
 ) -> Result<SuccessResponse<QueryBalanceResult>, Error> {
     let params = QueryBalanceParams::new(maybe_global_state_identifier, purse_identifier);
-    JsonRpcCall::new(rpc_id, node_address, verbosity)
+    JsonRpcCall::new(transport, rpc_id, node_address, verbosity)
         .send_request(QUERY_BALANCE_METHOD, Some(params))
         .await
 }
@@ -348,9 +676,29 @@ pub async fn get_dictionary_item(
     verbosity: Verbosity,
     state_root_hash: Digest,
     dictionary_item_identifier: DictionaryItemIdentifier,
+) -> Result<SuccessResponse<GetDictionaryItemResult>, Error> {
+    get_dictionary_item_with_transport(
+        &HttpTransport::default(),
+        rpc_id,
+        node_address,
+        verbosity,
+        state_root_hash,
+        dictionary_item_identifier,
+    )
+    .await
+}
+
+/// Same as [`get_dictionary_item`], but sends the request via the given `transport`.
+pub async fn get_dictionary_item_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    state_root_hash: Digest,
+    dictionary_item_identifier: DictionaryItemIdentifier,
 ) -> Result<SuccessResponse<GetDictionaryItemResult>, Error> {
     let params = GetDictionaryItemParams::new(state_root_hash, dictionary_item_identifier);
-    JsonRpcCall::new(rpc_id, node_address, verbosity)
+    JsonRpcCall::new(transport, rpc_id, node_address, verbosity)
         .send_request(GET_DICTIONARY_ITEM_METHOD, Some(params))
         .await
 }
@@ -366,9 +714,29 @@ pub async fn get_balance(
     verbosity: Verbosity,
     state_root_hash: Digest,
     purse: URef,
+) -> Result<SuccessResponse<GetBalanceResult>, Error> {
+    get_balance_with_transport(
+        &HttpTransport::default(),
+        rpc_id,
+        node_address,
+        verbosity,
+        state_root_hash,
+        purse,
+    )
+    .await
+}
+
+/// Same as [`get_balance`], but sends the request via the given `transport`.
+pub async fn get_balance_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    state_root_hash: Digest,
+    purse: URef,
 ) -> Result<SuccessResponse<GetBalanceResult>, Error> {
     let params = GetBalanceParams::new(state_root_hash, purse);
-    JsonRpcCall::new(rpc_id, node_address, verbosity)
+    JsonRpcCall::new(transport, rpc_id, node_address, verbosity)
         .send_request(GET_BALANCE_METHOD, Some(params))
         .await
 }
@@ -384,9 +752,29 @@ pub async fn get_account(
     verbosity: Verbosity,
     maybe_block_identifier: Option<BlockIdentifier>,
     account_identifier: AccountIdentifier,
+) -> Result<SuccessResponse<GetAccountResult>, Error> {
+    get_account_with_transport(
+        &HttpTransport::default(),
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_identifier,
+        account_identifier,
+    )
+    .await
+}
+
+/// Same as [`get_account`], but sends the request via the given `transport`.
+pub async fn get_account_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_block_identifier: Option<BlockIdentifier>,
+    account_identifier: AccountIdentifier,
 ) -> Result<SuccessResponse<GetAccountResult>, Error> {
     let params = GetAccountParams::new(account_identifier, maybe_block_identifier);
-    JsonRpcCall::new(rpc_id, node_address, verbosity)
+    JsonRpcCall::new(transport, rpc_id, node_address, verbosity)
         .send_request(GET_ACCOUNT_METHOD, Some(params))
         .await
 }
@@ -401,9 +789,27 @@ pub async fn get_auction_info(
     node_address: &str,
     verbosity: Verbosity,
     maybe_block_identifier: Option<BlockIdentifier>,
+) -> Result<SuccessResponse<GetAuctionInfoResult>, Error> {
+    get_auction_info_with_transport(
+        &HttpTransport::default(),
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_identifier,
+    )
+    .await
+}
+
+/// Same as [`get_auction_info`], but sends the request via the given `transport`.
+pub async fn get_auction_info_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_block_identifier: Option<BlockIdentifier>,
 ) -> Result<SuccessResponse<GetAuctionInfoResult>, Error> {
     let params = maybe_block_identifier.map(GetAuctionInfoParams::new);
-    JsonRpcCall::new(rpc_id, node_address, verbosity)
+    JsonRpcCall::new(transport, rpc_id, node_address, verbosity)
         .send_request(GET_AUCTION_INFO_METHOD, params)
         .await
 }
@@ -418,7 +824,18 @@ pub async fn get_validator_changes(
     node_address: &str,
     verbosity: Verbosity,
 ) -> Result<SuccessResponse<GetValidatorChangesResult>, Error> {
-    JsonRpcCall::new(rpc_id, node_address, verbosity)
+    get_validator_changes_with_transport(&HttpTransport::default(), rpc_id, node_address, verbosity)
+        .await
+}
+
+/// Same as [`get_validator_changes`], but sends the request via the given `transport`.
+pub async fn get_validator_changes_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+) -> Result<SuccessResponse<GetValidatorChangesResult>, Error> {
+    JsonRpcCall::new(transport, rpc_id, node_address, verbosity)
         .send_request::<(), _>(GET_VALIDATOR_CHANGES_METHOD, None)
         .await
 }
@@ -433,7 +850,17 @@ pub async fn get_peers(
     node_address: &str,
     verbosity: Verbosity,
 ) -> Result<SuccessResponse<GetPeersResult>, Error> {
-    JsonRpcCall::new(rpc_id, node_address, verbosity)
+    get_peers_with_transport(&HttpTransport::default(), rpc_id, node_address, verbosity).await
+}
+
+/// Same as [`get_peers`], but sends the request via the given `transport`.
+pub async fn get_peers_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+) -> Result<SuccessResponse<GetPeersResult>, Error> {
+    JsonRpcCall::new(transport, rpc_id, node_address, verbosity)
         .send_request::<(), _>(GET_PEERS_METHOD, None)
         .await
 }
@@ -448,7 +875,17 @@ pub async fn get_node_status(
     node_address: &str,
     verbosity: Verbosity,
 ) -> Result<SuccessResponse<GetNodeStatusResult>, Error> {
-    JsonRpcCall::new(rpc_id, node_address, verbosity)
+    get_node_status_with_transport(&HttpTransport::default(), rpc_id, node_address, verbosity).await
+}
+
+/// Same as [`get_node_status`], but sends the request via the given `transport`.
+pub async fn get_node_status_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+) -> Result<SuccessResponse<GetNodeStatusResult>, Error> {
+    JsonRpcCall::new(transport, rpc_id, node_address, verbosity)
         .send_request::<(), _>(GET_NODE_STATUS_METHOD, None)
         .await
 }
@@ -463,11 +900,112 @@ pub async fn get_chainspec(
     node_address: &str,
     verbosity: Verbosity,
 ) -> Result<SuccessResponse<GetChainspecResult>, Error> {
-    JsonRpcCall::new(rpc_id, node_address, verbosity)
+    get_chainspec_with_transport(&HttpTransport::default(), rpc_id, node_address, verbosity).await
+}
+
+/// Same as [`get_chainspec`], but sends the request via the given `transport`.
+pub async fn get_chainspec_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+) -> Result<SuccessResponse<GetChainspecResult>, Error> {
+    JsonRpcCall::new(transport, rpc_id, node_address, verbosity)
         .send_request::<(), _>(GET_CHAINSPEC_METHOD, None)
         .await
 }
 
+/// Writes the files of a network's chainspec, as retrieved by [`get_chainspec`], to the directory
+/// at `dir_path`, creating it if necessary.
+///
+/// The chainspec is written to "chainspec.toml", and the genesis accounts and global state, if
+/// provided, to "accounts.toml" and "global_state.toml" respectively.  If `force` is false and any
+/// of these files already exists, [`Error::FileAlreadyExists`] is returned and no file will be
+/// written.
+#[cfg(feature = "std-fs-io")]
+pub fn save_chainspec_files<P: AsRef<Path>>(
+    chainspec_bytes: &ChainspecRawBytes,
+    dir_path: P,
+    force: bool,
+) -> Result<(), Error> {
+    let dir_path = dir_path.as_ref();
+    let mut files = vec![(
+        dir_path.join(CHAINSPEC_FILE_NAME),
+        &chainspec_bytes.chainspec_bytes,
+    )];
+    if let Some(bytes) = &chainspec_bytes.maybe_genesis_accounts_bytes {
+        files.push((dir_path.join(GENESIS_ACCOUNTS_FILE_NAME), bytes));
+    }
+    if let Some(bytes) = &chainspec_bytes.maybe_global_state_bytes {
+        files.push((dir_path.join(GLOBAL_STATE_FILE_NAME), bytes));
+    }
+    if !force {
+        if let Some((path, _)) = files.iter().find(|(path, _)| path.exists()) {
+            return Err(Error::FileAlreadyExists(path.clone()));
+        }
+    }
+
+    fs::create_dir_all(dir_path).map_err(|error| Error::IoError {
+        context: format!("unable to create directory at '{}'", dir_path.display()),
+        error,
+    })?;
+    for (path, bytes) in &files {
+        let output = OutputKind::file(path, force);
+        output
+            .get()?
+            .write_all(bytes)
+            .map_err(|error| Error::IoError {
+                context: format!("unable to write '{}'", path.display()),
+                error,
+            })?;
+        output.commit()?;
+    }
+    Ok(())
+}
+
+/// Reads a Wasm module from a file and returns a summary of its contents.
+///
+/// For details of the summary, see [`WasmInspection`].
+#[cfg(feature = "std-fs-io")]
+pub fn inspect_wasm_file<P: AsRef<Path>>(wasm_path: P) -> Result<WasmInspection, Error> {
+    WasmInspection::new(&read_wasm_file(wasm_path)?)
+}
+
+/// Reads a Wasm module from a file, removes its debug info as per [`strip_wasm_debug_sections`],
+/// and outputs it to a file or stdout.
+///
+/// `output` specifies the output file and corresponding overwrite behaviour, or if
+/// `OutputKind::Stdout`, causes the module to be written to `stdout`.
+///
+/// The same path can be specified for input and output, and if the operation fails, the original
+/// input file will be left unmodified.
+#[cfg(feature = "std-fs-io")]
+pub fn strip_wasm_debug_sections_file<P: AsRef<Path>>(
+    input_path: P,
+    output: OutputKind,
+) -> Result<(), Error> {
+    let stripped = strip_wasm_debug_sections(&read_wasm_file(input_path)?)?;
+    output
+        .get()?
+        .write_all(&stripped)
+        .map_err(|error| Error::IoError {
+            context: "unable to write Wasm module".to_string(),
+            error,
+        })?;
+    output.commit()
+}
+
+#[cfg(feature = "std-fs-io")]
+fn read_wasm_file<P: AsRef<Path>>(wasm_path: P) -> Result<Vec<u8>, Error> {
+    fs::read(wasm_path.as_ref()).map_err(|error| Error::IoError {
+        context: format!(
+            "unable to read Wasm file at '{}'",
+            wasm_path.as_ref().display()
+        ),
+        error,
+    })
+}
+
 /// Retrieves the interface description (the schema including examples in OpenRPC format) of the
 /// JSON-RPC server's API.
 ///
@@ -479,7 +1017,17 @@ pub async fn list_rpcs(
     node_address: &str,
     verbosity: Verbosity,
 ) -> Result<SuccessResponse<ListRpcsResult>, Error> {
-    JsonRpcCall::new(rpc_id, node_address, verbosity)
+    list_rpcs_with_transport(&HttpTransport::default(), rpc_id, node_address, verbosity).await
+}
+
+/// Same as [`list_rpcs`], but sends the request via the given `transport`.
+pub async fn list_rpcs_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+) -> Result<SuccessResponse<ListRpcsResult>, Error> {
+    JsonRpcCall::new(transport, rpc_id, node_address, verbosity)
         .send_request::<(), _>(LIST_RPCS_METHOD, None)
         .await
 }
@@ -508,27 +1056,49 @@ pub(crate) fn json_pretty_print<T: ?Sized + Serialize>(
 }
 
 #[cfg(feature = "std-fs-io")]
-fn write_deploy<W: Write>(deploy: &Deploy, mut output: W) -> Result<(), Error> {
-    let content =
-        serde_json::to_string_pretty(deploy).map_err(|error| Error::FailedToEncodeToJson {
-            context: "writing deploy",
-            error,
-        })?;
-    output
-        .write_all(content.as_bytes())
-        .map_err(|error| Error::IoError {
-            context: "unable to write deploy".to_owned(),
-            error,
-        })
+fn write_deploy<W: Write>(
+    deploy: &Deploy,
+    format: DeployFormat,
+    mut output: W,
+) -> Result<(), Error> {
+    let content = match format {
+        DeployFormat::Json => {
+            serde_json::to_vec_pretty(deploy).map_err(|error| Error::FailedToEncodeToJson {
+                context: "writing deploy",
+                error,
+            })?
+        }
+        DeployFormat::Binary => deploy.to_bytes()?,
+    };
+    output.write_all(&content).map_err(|error| Error::IoError {
+        context: "unable to write deploy".to_owned(),
+        error,
+    })
 }
 
 #[cfg(feature = "std-fs-io")]
-fn read_deploy<R: Read>(input: R) -> Result<Deploy, Error> {
-    let deploy: Deploy =
-        serde_json::from_reader(input).map_err(|error| Error::FailedToDecodeFromJson {
-            context: "reading deploy",
+fn read_deploy<R: Read>(mut input: R) -> Result<Deploy, Error> {
+    let mut bytes = vec![];
+    input
+        .read_to_end(&mut bytes)
+        .map_err(|error| Error::IoError {
+            context: "unable to read deploy".to_owned(),
             error,
         })?;
+    let deploy: Deploy = match DeployFormat::detect(&bytes) {
+        DeployFormat::Json => {
+            serde_json::from_slice(&bytes).map_err(|error| Error::FailedToDecodeFromJson {
+                context: "reading deploy",
+                error,
+            })?
+        }
+        DeployFormat::Binary => {
+            bytesrepr::deserialize(bytes).map_err(|error| Error::FailedToDecodeFromBytes {
+                context: "reading deploy",
+                error,
+            })?
+        }
+    };
     deploy.is_valid_size(MAX_SERIALIZED_SIZE_OF_DEPLOY)?;
     Ok(deploy)
 }
@@ -548,9 +1118,32 @@ pub async fn get_era_info(
     node_address: &str,
     verbosity: Verbosity,
     maybe_block_identifier: Option<BlockIdentifier>,
+) -> Result<SuccessResponse<GetEraInfoResult>, Error> {
+    #[allow(deprecated)]
+    get_era_info_with_transport(
+        &HttpTransport::default(),
+        rpc_id,
+        node_address,
+        verbosity,
+        maybe_block_identifier,
+    )
+    .await
+}
+
+/// Same as [`get_era_info`], but sends the request via the given `transport`.
+#[deprecated(
+    since = "2.0.0",
+    note = "prefer 'get_era_summary' as it doesn't require a switch block"
+)]
+pub async fn get_era_info_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    maybe_block_identifier: Option<BlockIdentifier>,
 ) -> Result<SuccessResponse<GetEraInfoResult>, Error> {
     let params = maybe_block_identifier.map(GetEraInfoParams::new);
-    JsonRpcCall::new(rpc_id, node_address, verbosity)
+    JsonRpcCall::new(transport, rpc_id, node_address, verbosity)
         .send_request(GET_ERA_INFO_METHOD, params)
         .await
 }
@@ -0,0 +1,343 @@
+use serde::Serialize;
+
+use casper_types::U512;
+
+#[cfg(doc)]
+use crate::types::DeployBuilder;
+use crate::{
+    types::{Chainspec, Deploy, ExecutableDeployItem, TimeDiff},
+    Error, HttpTransport, JsonRpcId, Transport, Verbosity, WasmLimits,
+};
+
+const STANDARD_PAYMENT_ARG_AMOUNT: &str = "amount";
+
+/// The name of a network and the limits it places on [`Deploy`]s, as configured in its chainspec.
+#[derive(Clone, PartialEq, Eq, Serialize, Debug)]
+pub struct NetworkLimits {
+    /// The name of the chain.
+    pub chain_name: String,
+    /// The maximum time-to-live of a deploy.
+    pub max_ttl: TimeDiff,
+    /// The maximum serialized size of a deploy in bytes.
+    pub max_deploy_size: u32,
+    /// The maximum number of dependencies of a deploy.
+    pub max_dependencies: u8,
+    /// The maximum amount of gas which can be used by all the deploys in a block, and hence by a
+    /// single deploy.
+    pub block_gas_limit: u64,
+    /// The limits placed on the session and payment Wasm of a deploy.
+    pub wasm_limits: WasmLimits,
+}
+
+impl NetworkLimits {
+    /// Parses the limits from the `[deploys]` and `[wasm]` sections of `chainspec_bytes`, the
+    /// contents of a chainspec.toml file, for the chain named `chain_name`.
+    pub fn from_chainspec<C: Into<String>>(
+        chain_name: C,
+        chainspec_bytes: &[u8],
+    ) -> Result<Self, Error> {
+        let chainspec = Chainspec::from_toml(chainspec_bytes)?;
+        let wasm_limits = WasmLimits::from_chainspec(&chainspec);
+        let deploys = chainspec.deploys.ok_or_else(|| {
+            Error::InvalidChainspec("missing or invalid [deploys] section".to_string())
+        })?;
+        Ok(NetworkLimits {
+            chain_name: chain_name.into(),
+            max_ttl: deploys.max_ttl,
+            max_deploy_size: deploys.max_deploy_size,
+            max_dependencies: deploys.max_dependencies,
+            block_gas_limit: deploys.block_gas_limit,
+            wasm_limits,
+        })
+    }
+
+    /// Returns `Ok` if `deploy` is valid for this network.
+    ///
+    /// Returns an error as per [`Deploy::verify`], or if
+    ///   * the deploy has more than `max_dependencies` dependencies
+    ///   * the deploy has a standard payment whose amount buys more gas than `block_gas_limit` at
+    ///     the deploy's gas price
+    ///   * the deploy's session or payment Wasm is invalid as per [`crate::validate_wasm`]
+    ///   * the serialized size of the deploy exceeds `max_deploy_size`
+    pub fn check(&self, deploy: &Deploy) -> Result<(), Error> {
+        deploy.verify(&self.chain_name, self.max_ttl)?;
+
+        let header = deploy.header();
+        let dependency_count = header.dependencies().count();
+        if dependency_count > usize::from(self.max_dependencies) {
+            return Err(Error::DeployExcessiveDependencies {
+                deploy_hash: *deploy.id(),
+                max_dependencies: self.max_dependencies,
+                dependency_count,
+            });
+        }
+
+        if let Some(gas) = standard_payment_amount(deploy.payment())
+            .and_then(|amount| amount.checked_div(U512::from(header.gas_price())))
+        {
+            if gas > U512::from(self.block_gas_limit) {
+                return Err(Error::DeployExceedsBlockGasLimit {
+                    deploy_hash: *deploy.id(),
+                    block_gas_limit: self.block_gas_limit,
+                    gas,
+                });
+            }
+        }
+
+        for item in [deploy.payment(), deploy.session()] {
+            if let ExecutableDeployItem::ModuleBytes { module_bytes, .. } = item {
+                if !module_bytes.is_empty() {
+                    crate::validate_wasm(module_bytes, &self.wasm_limits)?;
+                }
+            }
+        }
+
+        deploy.is_valid_size(self.max_deploy_size)
+    }
+}
+
+/// Returns the amount of `payment` if it is a standard payment, i.e. `ModuleBytes` with no Wasm.
+fn standard_payment_amount(payment: &ExecutableDeployItem) -> Option<U512> {
+    match payment {
+        ExecutableDeployItem::ModuleBytes { module_bytes, args } if module_bytes.is_empty() => args
+            .get(STANDARD_PAYMENT_ARG_AMOUNT)
+            .and_then(|amount| amount.clone().into_t().ok()),
+        _ => None,
+    }
+}
+
+/// Discovers the name of the network and the limits it places on deploys.
+///
+/// Sends a JSON-RPC `info_get_status` request to the specified node to retrieve the chain name,
+/// and a JSON-RPC `info_get_chainspec` request to retrieve the limits.  The returned limits can be
+/// passed to [`DeployBuilder::with_network_limits`] so that deploys are checked against them
+/// before being signed.
+///
+/// For details of the parameters, see [the module docs](crate#common-parameters).
+pub async fn discover_network_limits(
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+) -> Result<NetworkLimits, Error> {
+    discover_network_limits_with_transport(
+        &HttpTransport::default(),
+        rpc_id,
+        node_address,
+        verbosity,
+    )
+    .await
+}
+
+/// Same as [`discover_network_limits`], but sends the requests via the given `transport`.
+pub async fn discover_network_limits_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+) -> Result<NetworkLimits, Error> {
+    let chain_name =
+        crate::get_node_status_with_transport(transport, rpc_id.clone(), node_address, verbosity)
+            .await?
+            .result
+            .chainspec_name;
+    let chainspec_bytes =
+        crate::get_chainspec_with_transport(transport, rpc_id, node_address, verbosity)
+            .await?
+            .result
+            .chainspec_bytes
+            .chainspec_bytes;
+    NetworkLimits::from_chainspec(chain_name, &chainspec_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "tokio")]
+    use async_trait::async_trait;
+    #[cfg(feature = "tokio")]
+    use jsonrpc_lite::JsonRpc;
+    #[cfg(feature = "tokio")]
+    use serde_json::json;
+
+    use casper_types::SecretKey;
+
+    use super::*;
+    #[cfg(feature = "tokio")]
+    use crate::rpcs::v1_6_0::{
+        get_chainspec::GET_CHAINSPEC_METHOD, get_node_status::GET_NODE_STATUS_METHOD,
+    };
+    use crate::{
+        types::{DeployBuilder, DeployHash},
+        TransferTarget,
+    };
+
+    const CHAINSPEC: &str = r#"
+[protocol]
+version = '1.5.6'
+
+[network]
+name = 'casper-test'
+
+[deploys]
+max_payment_cost = '0'
+max_ttl = '18hours'
+max_dependencies = 10
+max_block_size = 10_485_760
+max_deploy_size = 1_048_576
+block_max_deploy_count = 50
+block_max_transfer_count = 1250
+block_max_approval_count = 2600
+block_gas_limit = 4_000_000_000_000
+payment_args_max_length = 1024
+session_args_max_length = 1024
+native_transfer_minimum_motes = 2_500_000_000
+"#;
+
+    /// A transport which responds to `info_get_status` and `info_get_chainspec` as a v1.5.x node
+    /// of the "casper-test" network.
+    #[cfg(feature = "tokio")]
+    #[derive(Debug)]
+    struct NodeTransport;
+
+    #[cfg(feature = "tokio")]
+    #[async_trait]
+    impl Transport for NodeTransport {
+        async fn send(
+            &self,
+            _url: &str,
+            rpc_id: &JsonRpcId,
+            rpc_method: &'static str,
+            _request: &JsonRpc,
+        ) -> Result<JsonRpc, Error> {
+            let result = match rpc_method {
+                GET_NODE_STATUS_METHOD => json!({
+                    "api_version": "1.5.6",
+                    "chainspec_name": "casper-test",
+                    "starting_state_root_hash": "00".repeat(32),
+                    "peers": [],
+                    "last_added_block_info": null,
+                    "our_public_signing_key": null,
+                    "round_length": null,
+                    "next_upgrade": null,
+                    "build_version": "1.5.6-a7f6a648d",
+                    "uptime": "1h",
+                    "reactor_state": "KeepUp",
+                    "last_progress": "2024-01-01T00:00:00.000Z",
+                    "available_block_range": { "low": 0, "high": 10 },
+                    "block_sync": { "historical": null, "forward": null }
+                }),
+                GET_CHAINSPEC_METHOD => json!({
+                    "api_version": "1.5.6",
+                    "chainspec_bytes": {
+                        "chainspec_bytes": base16::encode_lower(CHAINSPEC.as_bytes()),
+                        "maybe_genesis_accounts_bytes": null,
+                        "maybe_global_state_bytes": null
+                    }
+                }),
+                _ => panic!("unexpected request {}", rpc_method),
+            };
+            Ok(JsonRpc::success(rpc_id, &result))
+        }
+    }
+
+    fn limits() -> NetworkLimits {
+        NetworkLimits::from_chainspec("casper-test", CHAINSPEC.as_bytes()).unwrap()
+    }
+
+    fn builder(secret_key: &SecretKey) -> DeployBuilder<'_> {
+        DeployBuilder::new_transfer(
+            "casper-test",
+            2_500_000_000_u64,
+            None,
+            TransferTarget::PublicKey(casper_types::PublicKey::from(secret_key)),
+            None,
+        )
+        .with_standard_payment(100_000_000_u64)
+        .with_secret_key(secret_key)
+    }
+
+    #[test]
+    fn should_parse_limits_from_chainspec() {
+        assert_eq!(
+            limits(),
+            NetworkLimits {
+                chain_name: "casper-test".to_string(),
+                max_ttl: "18hours".parse().unwrap(),
+                max_deploy_size: 1_048_576,
+                max_dependencies: 10,
+                block_gas_limit: 4_000_000_000_000,
+                wasm_limits: WasmLimits {
+                    max_module_size: 1_048_576,
+                    max_memory_pages: 64,
+                },
+            }
+        );
+        assert!(matches!(
+            NetworkLimits::from_chainspec("casper-test", b"[network]\nname = 'casper-test'"),
+            Err(Error::InvalidChainspec(_))
+        ));
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn should_discover_network_limits() {
+        let limits = discover_network_limits_with_transport(
+            &NodeTransport,
+            JsonRpcId::from(1),
+            "http://localhost:7777",
+            Verbosity::Low,
+        )
+        .await
+        .unwrap();
+        assert_eq!(limits, self::limits());
+    }
+
+    #[test]
+    fn should_check_deploy_against_limits() {
+        let secret_key = SecretKey::ed25519_from_bytes([1; 32]).unwrap();
+        let limits = limits();
+        assert!(limits.check(&builder(&secret_key).build().unwrap()).is_ok());
+
+        let deploy = builder(&secret_key)
+            .with_ttl("1day".parse().unwrap())
+            .build()
+            .unwrap();
+        assert!(matches!(
+            limits.check(&deploy),
+            Err(Error::DeployExcessiveTtl { .. })
+        ));
+
+        let dependencies = (0..11_u8)
+            .map(|byte| DeployHash::new(casper_hashing::Digest::hash([byte; 32])))
+            .collect();
+        let deploy = builder(&secret_key)
+            .with_dependencies(dependencies)
+            .build()
+            .unwrap();
+        assert!(matches!(
+            limits.check(&deploy),
+            Err(Error::DeployExcessiveDependencies {
+                dependency_count: 11,
+                ..
+            })
+        ));
+
+        let deploy = builder(&secret_key)
+            .with_standard_payment(8_000_000_000_002_u64)
+            .with_gas_price(2)
+            .build()
+            .unwrap();
+        assert!(matches!(
+            limits.check(&deploy),
+            Err(Error::DeployExceedsBlockGasLimit { .. })
+        ));
+
+        let limits = NetworkLimits {
+            chain_name: "casper".to_string(),
+            ..limits
+        };
+        assert!(matches!(
+            limits.check(&builder(&secret_key).build().unwrap()),
+            Err(Error::DeployChainNameMismatch { .. })
+        ));
+    }
+}
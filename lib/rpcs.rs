@@ -1,5 +1,6 @@
 //! The JSON-RPC request and response types.
 
+mod api_version;
 pub mod common;
 pub mod results;
 /// RPCs provided by the v1.4.5 node.
@@ -9,6 +10,7 @@ pub(crate) mod v1_5_0;
 /// RPCs provided by the v1.6.0 node.
 pub(crate) mod v1_6_0;
 
+pub use api_version::RpcApiVersion;
 pub use v1_5_0::{
     get_dictionary_item::DictionaryItemIdentifier, query_balance::PurseIdentifier,
     query_global_state::GlobalStateIdentifier,
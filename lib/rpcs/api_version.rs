@@ -0,0 +1,131 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde_json::Value;
+
+use casper_types::ProtocolVersion;
+
+use super::v1_5_0::{
+    get_chainspec::GET_CHAINSPEC_METHOD, get_era_summary::GET_ERA_SUMMARY_METHOD,
+    query_balance::QUERY_BALANCE_METHOD, speculative_exec::SPECULATIVE_EXEC_METHOD,
+};
+use crate::{Error, JsonRpcId};
+
+/// The RPCs introduced at v1.5.0 of casper-node.
+const ADDED_AT_V1_5_0: [&str; 4] = [
+    GET_CHAINSPEC_METHOD,
+    GET_ERA_SUMMARY_METHOD,
+    QUERY_BALANCE_METHOD,
+    SPECULATIVE_EXEC_METHOD,
+];
+
+/// A version of the casper-node JSON-RPC API supported by this client.
+///
+/// Each variant covers all nodes whose `api_version` has the same major and minor version as the
+/// variant.
+#[derive(Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
+pub enum RpcApiVersion {
+    /// The JSON-RPC API of casper-node v1.4.x.
+    V1_4_5,
+    /// The JSON-RPC API of casper-node v1.5.x.
+    V1_5_0,
+    /// The JSON-RPC API of casper-node v1.6.x.
+    V1_6_0,
+}
+
+impl RpcApiVersion {
+    /// Returns the `RpcApiVersion` matching the `api_version` reported by a node.
+    ///
+    /// Returns [`Error::UnsupportedNodeVersion`] if the node's version is not supported.
+    pub fn from_api_version(api_version: ProtocolVersion) -> Result<Self, Error> {
+        let semver = api_version.value();
+        match (semver.major, semver.minor) {
+            (1, 4) => Ok(RpcApiVersion::V1_4_5),
+            (1, 5) => Ok(RpcApiVersion::V1_5_0),
+            (1, 6) => Ok(RpcApiVersion::V1_6_0),
+            _ => Err(Error::UnsupportedNodeVersion { api_version }),
+        }
+    }
+
+    /// Returns whether nodes at this version provide the RPC named `rpc_method`.
+    pub fn supports(&self, rpc_method: &str) -> bool {
+        *self >= RpcApiVersion::V1_5_0 || !ADDED_AT_V1_5_0.contains(&rpc_method)
+    }
+
+    /// Returns an error if nodes at this version don't provide the RPC named `rpc_method`.
+    pub(crate) fn ensure_supports(&self, rpc_method: &'static str) -> Result<(), Error> {
+        if self.supports(rpc_method) {
+            Ok(())
+        } else {
+            Err(Error::UnsupportedByNodeVersion {
+                rpc_method,
+                rpc_api_version: *self,
+                context: "method not provided",
+            })
+        }
+    }
+
+    /// Returns the `RpcApiVersion` matching the `api_version` field of the `result` of a
+    /// successful JSON-RPC response.
+    pub(crate) fn from_result(
+        rpc_id: &JsonRpcId,
+        rpc_method: &'static str,
+        result: &Value,
+    ) -> Result<Self, Error> {
+        let invalid_response = |source: Option<serde_json::Error>| Error::InvalidRpcResponse {
+            rpc_id: rpc_id.clone(),
+            rpc_method,
+            response_kind: "Success",
+            response: result.clone(),
+            source,
+        };
+        let api_version = result
+            .get("api_version")
+            .cloned()
+            .ok_or_else(|| invalid_response(None))?;
+        let api_version =
+            serde_json::from_value(api_version).map_err(|error| invalid_response(Some(error)))?;
+        RpcApiVersion::from_api_version(api_version)
+    }
+}
+
+impl Display for RpcApiVersion {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            RpcApiVersion::V1_4_5 => write!(formatter, "1.4.5"),
+            RpcApiVersion::V1_5_0 => write!(formatter, "1.5.0"),
+            RpcApiVersion::V1_6_0 => write!(formatter, "1.6.0"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rpcs::v1_6_0::get_node_status::GET_NODE_STATUS_METHOD;
+
+    #[test]
+    fn should_map_api_version() {
+        let version = |major, minor, patch| {
+            RpcApiVersion::from_api_version(ProtocolVersion::from_parts(major, minor, patch))
+        };
+        assert_eq!(version(1, 4, 15).unwrap(), RpcApiVersion::V1_4_5);
+        assert_eq!(version(1, 5, 2).unwrap(), RpcApiVersion::V1_5_0);
+        assert_eq!(version(1, 6, 0).unwrap(), RpcApiVersion::V1_6_0);
+        assert!(matches!(
+            version(2, 0, 0),
+            Err(Error::UnsupportedNodeVersion { .. })
+        ));
+        assert!(matches!(
+            version(1, 3, 0),
+            Err(Error::UnsupportedNodeVersion { .. })
+        ));
+    }
+
+    #[test]
+    fn should_report_supported_methods() {
+        assert!(!RpcApiVersion::V1_4_5.supports(GET_CHAINSPEC_METHOD));
+        assert!(RpcApiVersion::V1_4_5.supports(GET_NODE_STATUS_METHOD));
+        assert!(RpcApiVersion::V1_5_0.supports(GET_CHAINSPEC_METHOD));
+        assert!(RpcApiVersion::V1_6_0.supports(QUERY_BALANCE_METHOD));
+    }
+}
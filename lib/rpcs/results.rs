@@ -1,5 +1,9 @@
 //! The various types constituting the `result` field of a successful JSON-RPC response.
 
+use serde::Serialize;
+
+use casper_types::ProtocolVersion;
+
 pub use super::v1_6_0::get_account::GetAccountResult;
 pub use super::v1_6_0::get_auction_info::GetAuctionInfoResult;
 pub use super::v1_6_0::get_balance::GetBalanceResult;
@@ -28,3 +32,46 @@ pub use super::v1_6_0::put_deploy::PutDeployResult;
 pub use super::v1_6_0::query_balance::QueryBalanceResult;
 pub use super::v1_6_0::query_global_state::QueryGlobalStateResult;
 pub use super::v1_6_0::speculative_exec::SpeculativeExecResult;
+
+/// The types constituting the `result` field of successful JSON-RPC responses from v1.4.x nodes,
+/// where these differ from the current types.
+pub mod v1_4_5 {
+    pub use crate::rpcs::v1_4_5::get_node_status::GetNodeStatusResult;
+}
+
+/// The `result` field of a successful JSON-RPC response to a `info_get_status` request, according
+/// to the node's JSON-RPC API version.
+#[derive(Clone, Serialize, Debug)]
+#[serde(untagged)]
+pub enum VersionedGetNodeStatusResult {
+    /// The status of a v1.4.x node.
+    V1_4_5(v1_4_5::GetNodeStatusResult),
+    /// The status of a v1.5.x or v1.6.x node.
+    V1_5_0(GetNodeStatusResult),
+}
+
+impl VersionedGetNodeStatusResult {
+    /// Returns the JSON-RPC server version.
+    pub fn api_version(&self) -> ProtocolVersion {
+        match self {
+            VersionedGetNodeStatusResult::V1_4_5(result) => result.api_version,
+            VersionedGetNodeStatusResult::V1_5_0(result) => result.api_version,
+        }
+    }
+
+    /// Returns the chainspec name.
+    pub fn chainspec_name(&self) -> &str {
+        match self {
+            VersionedGetNodeStatusResult::V1_4_5(result) => &result.chainspec_name,
+            VersionedGetNodeStatusResult::V1_5_0(result) => &result.chainspec_name,
+        }
+    }
+
+    /// Returns the minimal info of the last block from the linear chain.
+    pub fn last_added_block_info(&self) -> Option<&MinimalBlockInfo> {
+        match self {
+            VersionedGetNodeStatusResult::V1_4_5(result) => result.last_added_block_info.as_ref(),
+            VersionedGetNodeStatusResult::V1_5_0(result) => result.last_added_block_info.as_ref(),
+        }
+    }
+}
@@ -21,8 +21,6 @@ pub(crate) struct QueryGlobalStateParams {
 }
 
 impl QueryGlobalStateParams {
-    //This clippy lint should be re-enabled once the client is updated to handle multiple different node versions.
-    #[allow(dead_code)]
     pub(crate) fn new(
         state_identifier: GlobalStateIdentifier,
         key: Key,
@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 
 use casper_types::{bytesrepr::Bytes, ProtocolVersion};
 
+use crate::{types::Chainspec, Error};
+
 pub(crate) const GET_CHAINSPEC_METHOD: &str = "info_get_chainspec";
 
 /// The raw bytes of the chainspec.toml, genesis accounts.toml, and global_state.toml files.
@@ -17,6 +19,13 @@ pub struct ChainspecRawBytes {
     pub maybe_global_state_bytes: Option<Bytes>,
 }
 
+impl ChainspecRawBytes {
+    /// Parses the chainspec.toml file as a [`Chainspec`].
+    pub fn chainspec(&self) -> Result<Chainspec, Error> {
+        Chainspec::from_toml(&self.chainspec_bytes)
+    }
+}
+
 impl Display for ChainspecRawBytes {
     fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
         write!(
@@ -0,0 +1,213 @@
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+use serde::{Deserialize, Serialize};
+
+use casper_types::{crypto, PublicKey, SecretKey, Signature};
+
+#[cfg(doc)]
+use crate::types::{Deploy, DeployBuilder};
+use crate::{types::DeployHash, Error};
+
+/// A means of signing a [`Deploy`], used by [`Deploy::sign`], [`DeployBuilder::with_signer`] and
+/// [`sign_deploy_file`](crate::sign_deploy_file).
+///
+/// It is implemented for [`SecretKey`], for signing with a key held in memory, and for
+/// [`ExternalSigner`], for signing with a key held by an external process such as a signing
+/// service.  It is required to be `Send` and `Sync` so that a signer can be held across the
+/// requests made by [`DeployBuilder::build_with_auto_payment`].
+pub trait Signer: Send + Sync {
+    /// Returns the public key of the signer, if it is known without signing.
+    ///
+    /// If `None`, a [`DeployBuilder`] using this signer must also be given the deploy's account via
+    /// [`DeployBuilder::with_account`].
+    fn public_key(&self) -> Option<PublicKey>;
+
+    /// Signs the given deploy hash, returning the signer's public key and the signature.
+    fn sign(&self, deploy_hash: &DeployHash) -> Result<(PublicKey, Signature), Error>;
+}
+
+impl Signer for SecretKey {
+    fn public_key(&self) -> Option<PublicKey> {
+        Some(PublicKey::from(self))
+    }
+
+    fn sign(&self, deploy_hash: &DeployHash) -> Result<(PublicKey, Signature), Error> {
+        let public_key = PublicKey::from(self);
+        let signature = crypto::sign(deploy_hash, self, &public_key);
+        Ok((public_key, signature))
+    }
+}
+
+/// The request written as JSON to the stdin of an [`ExternalSigner`]'s process.
+#[derive(Serialize)]
+struct SignRequest<'a> {
+    deploy_hash: &'a DeployHash,
+}
+
+/// The response read as JSON from the stdout of an [`ExternalSigner`]'s process.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SignResponse {
+    public_key: PublicKey,
+    signature: Signature,
+}
+
+/// A [`Signer`] which delegates signing to an external process.
+///
+/// For each signature, the process is run and a JSON object of the form
+/// `{"deploy_hash":"<HEX STRING>"}` is written to its stdin, which is then closed.  The process
+/// must write a JSON object of the form `{"public_key":"<HEX STRING>","signature":"<HEX STRING>"}`
+/// to its stdout and exit successfully.  The returned signature is verified before being used.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ExternalSigner {
+    program: String,
+    args: Vec<String>,
+}
+
+impl ExternalSigner {
+    /// Returns a new `ExternalSigner` which runs `program` with the given `args`.
+    pub fn new<P: Into<String>>(program: P, args: Vec<String>) -> Self {
+        ExternalSigner {
+            program: program.into(),
+            args,
+        }
+    }
+
+    fn command_line(&self) -> String {
+        let mut command_line = self.program.clone();
+        for arg in &self.args {
+            command_line.push(' ');
+            command_line.push_str(arg);
+        }
+        command_line
+    }
+
+    fn run(&self, request: &[u8]) -> Result<Vec<u8>, Error> {
+        let command_line = self.command_line();
+        let mut child = Command::new(&self.program)
+            .args(&self.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|error| Error::FailedToRunSignerCommand {
+                command: command_line.clone(),
+                error,
+            })?;
+
+        // Dropping stdin after writing closes it, signalling the end of the request.
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(request)
+                .map_err(|error| Error::FailedToRunSignerCommand {
+                    command: command_line.clone(),
+                    error,
+                })?;
+        }
+
+        let output = child
+            .wait_with_output()
+            .map_err(|error| Error::FailedToRunSignerCommand {
+                command: command_line.clone(),
+                error,
+            })?;
+        if !output.status.success() {
+            return Err(Error::SignerCommandFailed {
+                command: command_line,
+                error: format!(
+                    "{}: {}",
+                    output.status,
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ),
+            });
+        }
+        Ok(output.stdout)
+    }
+}
+
+impl Signer for ExternalSigner {
+    fn public_key(&self) -> Option<PublicKey> {
+        None
+    }
+
+    fn sign(&self, deploy_hash: &DeployHash) -> Result<(PublicKey, Signature), Error> {
+        let request = serde_json::to_vec(&SignRequest { deploy_hash }).map_err(|error| {
+            Error::FailedToEncodeToJson {
+                context: "external signer request",
+                error,
+            }
+        })?;
+        let output = self.run(&request)?;
+        let response: SignResponse =
+            serde_json::from_slice(&output).map_err(|error| Error::SignerCommandFailed {
+                command: self.command_line(),
+                error: format!("invalid response: {}", error),
+            })?;
+        crypto::verify(deploy_hash, &response.signature, &response.public_key).map_err(
+            |error| Error::DeployInvalidApproval {
+                deploy_hash: *deploy_hash,
+                signer: response.public_key.clone(),
+                error,
+            },
+        )?;
+        Ok((response.public_key, response.signature))
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use serde_json::json;
+
+    use casper_hashing::Digest;
+
+    use super::*;
+
+    /// Returns an `ExternalSigner` which ignores its input and responds with `response`.
+    fn canned_signer(response: &str) -> ExternalSigner {
+        ExternalSigner::new(
+            "sh",
+            vec![
+                "-c".to_string(),
+                format!("cat > /dev/null; echo '{}'", response),
+            ],
+        )
+    }
+
+    #[test]
+    fn should_sign_via_external_process() {
+        let secret_key = SecretKey::ed25519_from_bytes([1; 32]).unwrap();
+        let deploy_hash = DeployHash::new(Digest::hash([2; 32]));
+        let (public_key, signature) = Signer::sign(&secret_key, &deploy_hash).unwrap();
+
+        let response = json!({ "public_key": public_key, "signature": signature }).to_string();
+        let signer = canned_signer(&response);
+        assert_eq!(signer.sign(&deploy_hash).unwrap(), (public_key, signature));
+
+        let other_deploy_hash = DeployHash::new(Digest::hash([3; 32]));
+        assert!(matches!(
+            signer.sign(&other_deploy_hash),
+            Err(Error::DeployInvalidApproval { .. })
+        ));
+    }
+
+    #[test]
+    fn should_fail_to_sign_via_failing_external_process() {
+        let deploy_hash = DeployHash::new(Digest::hash([2; 32]));
+        assert!(matches!(
+            canned_signer("not json").sign(&deploy_hash),
+            Err(Error::SignerCommandFailed { .. })
+        ));
+
+        let signer = ExternalSigner::new(
+            "sh",
+            vec!["-c".to_string(), "cat > /dev/null; exit 1".to_string()],
+        );
+        assert!(matches!(
+            signer.sign(&deploy_hash),
+            Err(Error::SignerCommandFailed { .. })
+        ));
+    }
+}
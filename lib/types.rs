@@ -3,6 +3,7 @@
 mod account;
 pub mod auction_state;
 mod block;
+mod chainspec;
 mod contract;
 mod contract_package;
 mod deploy;
@@ -22,6 +23,10 @@ pub use block::{
     v2::validate_hashes as validate_block_hashes_v2, Block, BlockBody, BlockHash,
     BlockHashAndHeight, BlockHeader,
 };
+pub use chainspec::{
+    Chainspec, CoreConfig, DeployConfig, HighwayConfig, HostFunctionCost, NetworkConfig,
+    ProtocolConfig, StorageCosts, SystemConfig, WasmConfig,
+};
 pub use contract::Contract;
 pub use contract_package::ContractPackage;
 pub use deploy::{
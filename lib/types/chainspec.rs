@@ -0,0 +1,355 @@
+use std::{collections::BTreeMap, str};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use casper_types::{ProtocolVersion, U512};
+
+use crate::{rpcs::results::ActivationPoint, types::TimeDiff, Error};
+
+/// The `[protocol]` section of a chainspec.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct ProtocolConfig {
+    /// The protocol version.
+    pub version: ProtocolVersion,
+    /// Whether the upgrade to this version resets the network to the state of its last switch
+    /// block.
+    pub hard_reset: bool,
+    /// The point at which this protocol version becomes active.
+    pub activation_point: ActivationPoint,
+}
+
+/// The `[network]` section of a chainspec.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct NetworkConfig {
+    /// The name of the network, used as the chain name of deploys.
+    pub name: String,
+    /// The maximum size in bytes of a message sent between nodes.
+    pub maximum_net_message_size: u32,
+}
+
+/// The `[core]` section of a chainspec.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct CoreConfig {
+    /// The minimum duration of an era.
+    pub era_duration: TimeDiff,
+    /// The minimum number of blocks in an era.
+    pub minimum_era_height: u64,
+    /// The minimum time between blocks.
+    pub minimum_block_time: TimeDiff,
+    /// The number of slots available in the validator auction.
+    pub validator_slots: u32,
+    /// The fraction of validator weight, as a numerator and denominator, which may be faulty
+    /// without compromising finality.
+    pub finality_threshold_fraction: [u64; 2],
+    /// The number of eras before the auction's results take effect.
+    pub auction_delay: u64,
+    /// The period after genesis during which the genesis validators' bids are locked.
+    pub locked_funds_period: TimeDiff,
+    /// The period over which locked funds are released after the locked funds period.
+    pub vesting_schedule_period: TimeDiff,
+    /// The number of eras after which an unbonding request is processed.
+    pub unbonding_delay: u64,
+    /// The rate of round seigniorage, as a numerator and denominator.
+    pub round_seigniorage_rate: [u64; 2],
+    /// The maximum number of keys which can be associated with an account.
+    pub max_associated_keys: u32,
+    /// The maximum depth of nested contract calls.
+    pub max_runtime_call_stack_height: u32,
+    /// The minimum amount in motes of a delegation.
+    pub minimum_delegation_amount: u64,
+    /// Whether the runtime args of contract calls are checked against the entry point's
+    /// parameters.
+    pub strict_argument_checking: bool,
+    /// The consensus protocol, e.g. "Highway".
+    pub consensus_protocol: String,
+}
+
+/// The `[highway]` section of a chainspec.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct HighwayConfig {
+    /// The maximum length of a round.
+    pub maximum_round_length: TimeDiff,
+    /// The multiplier, as a numerator and denominator, applied to the rewards of validators whose
+    /// blocks are not finalized in their round.
+    pub reduced_reward_multiplier: [u64; 2],
+}
+
+/// The `[deploys]` section of a chainspec.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct DeployConfig {
+    /// The maximum payment amount in motes for custom payment code.
+    pub max_payment_cost: U512,
+    /// The maximum time-to-live of a deploy.
+    pub max_ttl: TimeDiff,
+    /// The maximum number of dependencies of a deploy.
+    pub max_dependencies: u8,
+    /// The maximum serialized size of a block in bytes.
+    pub max_block_size: u32,
+    /// The maximum serialized size of a deploy in bytes.
+    pub max_deploy_size: u32,
+    /// The maximum number of non-transfer deploys in a block.
+    pub block_max_deploy_count: u32,
+    /// The maximum number of transfers in a block.
+    pub block_max_transfer_count: u32,
+    /// The maximum number of approvals across all deploys in a block.
+    pub block_max_approval_count: u32,
+    /// The maximum amount of gas which can be used by all the deploys in a block.
+    pub block_gas_limit: u64,
+    /// The maximum serialized length in bytes of a deploy's payment args.
+    pub payment_args_max_length: u32,
+    /// The maximum serialized length in bytes of a deploy's session args.
+    pub session_args_max_length: u32,
+    /// The minimum amount in motes of a native transfer.
+    pub native_transfer_minimum_motes: u64,
+}
+
+/// The cost of a host function, which may depend on the values of its arguments.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct HostFunctionCost {
+    /// The base cost of calling the function.
+    pub cost: u32,
+    /// The additional cost per unit of each argument's value.
+    pub arguments: Vec<u32>,
+}
+
+/// The cost of storing data in global state.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct StorageCosts {
+    /// The gas cost per byte stored.
+    pub gas_per_byte: u32,
+}
+
+/// The `[wasm]` section of a chainspec, including its cost tables.
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug)]
+pub struct WasmConfig {
+    /// The maximum number of 64 KiB memory pages available to a Wasm module.
+    pub max_memory: u32,
+    /// The maximum stack height of a Wasm module.
+    pub max_stack_height: u32,
+    /// The costs of storing data.
+    pub storage_costs: StorageCosts,
+    /// The costs of Wasm opcodes, keyed by opcode, including the nested `control_flow` table.
+    pub opcode_costs: toml::Table,
+    /// The costs of host functions, keyed by function name.
+    pub host_function_costs: BTreeMap<String, HostFunctionCost>,
+}
+
+/// The `[system_costs]` section of a chainspec, holding the costs of calls to system contracts.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct SystemConfig {
+    /// The cost of a native transfer.
+    pub wasmless_transfer_cost: u32,
+    /// The costs of the auction contract's entry points.
+    pub auction_costs: BTreeMap<String, u32>,
+    /// The costs of the mint contract's entry points.
+    pub mint_costs: BTreeMap<String, u32>,
+    /// The costs of the handle payment contract's entry points.
+    pub handle_payment_costs: BTreeMap<String, u32>,
+    /// The costs of the standard payment contract's entry points.
+    pub standard_payment_costs: BTreeMap<String, u32>,
+}
+
+/// A network's chainspec, parsed from the contents of its chainspec.toml file.
+///
+/// Each section is parsed independently: a section which is absent, or which can't be parsed as its
+/// typed equivalent (e.g. since it is from a different version of the node), is `None`.  Sections
+/// which are unknown or couldn't be parsed are retained as TOML in `other_sections`.
+#[derive(Clone, PartialEq, Serialize, Debug)]
+pub struct Chainspec {
+    /// The `[protocol]` section.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<ProtocolConfig>,
+    /// The `[network]` section.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<NetworkConfig>,
+    /// The `[core]` section.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub core: Option<CoreConfig>,
+    /// The `[highway]` section.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highway: Option<HighwayConfig>,
+    /// The `[deploys]` section.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deploys: Option<DeployConfig>,
+    /// The `[wasm]` section.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wasm: Option<WasmConfig>,
+    /// The `[system_costs]` section.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_costs: Option<SystemConfig>,
+    /// The sections which are unknown or couldn't be parsed, keyed by section name.
+    pub other_sections: toml::Table,
+}
+
+impl Chainspec {
+    /// Parses `chainspec_bytes`, the contents of a chainspec.toml file.
+    ///
+    /// Returns an error only if the bytes are not a valid TOML document.
+    pub fn from_toml(chainspec_bytes: &[u8]) -> Result<Self, Error> {
+        let chainspec = str::from_utf8(chainspec_bytes)
+            .map_err(|error| Error::InvalidChainspec(error.to_string()))?;
+        let mut sections: toml::Table = toml::from_str(chainspec)
+            .map_err(|error| Error::InvalidChainspec(error.to_string()))?;
+        Ok(Chainspec {
+            protocol: take_section(&mut sections, "protocol"),
+            network: take_section(&mut sections, "network"),
+            core: take_section(&mut sections, "core"),
+            highway: take_section(&mut sections, "highway"),
+            deploys: take_section(&mut sections, "deploys"),
+            wasm: take_section(&mut sections, "wasm"),
+            system_costs: take_section(&mut sections, "system_costs"),
+            other_sections: sections,
+        })
+    }
+}
+
+/// Removes and parses the section called `name` from `sections`, putting it back if it can't be
+/// parsed as a `T`.
+fn take_section<T: DeserializeOwned>(sections: &mut toml::Table, name: &str) -> Option<T> {
+    let section = sections.remove(name)?;
+    match section.clone().try_into() {
+        Ok(parsed) => Some(parsed),
+        Err(_) => {
+            let _ = sections.insert(name.to_string(), section);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHAINSPEC: &str = r#"
+[protocol]
+version = '1.5.6'
+hard_reset = true
+activation_point = 11682
+
+[network]
+name = 'casper-test'
+maximum_net_message_size = 25_165_824
+
+[core]
+era_duration = '120 minutes'
+minimum_era_height = 100
+minimum_block_time = '16384 ms'
+validator_slots = 100
+finality_threshold_fraction = [1, 3]
+auction_delay = 1
+locked_funds_period = '90 days'
+vesting_schedule_period = '13 weeks'
+unbonding_delay = 7
+round_seigniorage_rate = [7, 175070816]
+max_associated_keys = 100
+max_runtime_call_stack_height = 12
+minimum_delegation_amount = 500_000_000_000
+strict_argument_checking = false
+consensus_protocol = 'Highway'
+
+[highway]
+maximum_round_length = '66 seconds'
+reduced_reward_multiplier = [1, 5]
+
+[deploys]
+max_payment_cost = '0'
+max_ttl = '18 hours'
+max_dependencies = 10
+max_block_size = 10_485_760
+max_deploy_size = 1_048_576
+block_max_deploy_count = 50
+block_max_transfer_count = 1250
+block_max_approval_count = 2600
+block_gas_limit = 4_000_000_000_000
+payment_args_max_length = 1024
+session_args_max_length = 1024
+native_transfer_minimum_motes = 2_500_000_000
+
+[wasm]
+max_memory = 64
+max_stack_height = 500
+
+[wasm.storage_costs]
+gas_per_byte = 630_000
+
+[wasm.opcode_costs]
+bit = 300
+add = 210
+
+[wasm.opcode_costs.control_flow]
+block = 8_440
+br_table = { cost = 35_000, size_multiplier = 100 }
+
+[wasm.host_function_costs]
+add = { cost = 5_800, arguments = [0, 0, 0, 0] }
+
+[system_costs]
+wasmless_transfer_cost = 100_000_000
+
+[system_costs.auction_costs]
+get_era_validators = 10_000
+
+[system_costs.mint_costs]
+mint = 2_500_000_000
+
+[system_costs.handle_payment_costs]
+get_payment_purse = 10_000
+
+[system_costs.standard_payment_costs]
+pay = 10_000
+
+[upcoming_feature]
+enabled = true
+"#;
+
+    #[test]
+    fn should_parse_chainspec() {
+        let chainspec = Chainspec::from_toml(CHAINSPEC.as_bytes()).unwrap();
+
+        let protocol = chainspec.protocol.unwrap();
+        assert_eq!(protocol.version, ProtocolVersion::from_parts(1, 5, 6));
+        assert!(matches!(
+            protocol.activation_point,
+            ActivationPoint::EraId(era_id) if era_id.value() == 11682
+        ));
+        assert_eq!(chainspec.network.unwrap().name, "casper-test");
+        assert_eq!(chainspec.core.unwrap().finality_threshold_fraction, [1, 3]);
+        assert_eq!(
+            chainspec.highway.unwrap().maximum_round_length,
+            TimeDiff::from_millis(66_000)
+        );
+
+        let deploys = chainspec.deploys.unwrap();
+        assert_eq!(deploys.max_ttl, TimeDiff::from_millis(18 * 60 * 60 * 1_000));
+        assert_eq!(deploys.block_gas_limit, 4_000_000_000_000);
+
+        let wasm = chainspec.wasm.unwrap();
+        assert_eq!(wasm.storage_costs.gas_per_byte, 630_000);
+        assert_eq!(wasm.host_function_costs["add"].arguments, vec![0; 4]);
+        assert!(wasm.opcode_costs["control_flow"].get("br_table").is_some());
+        assert_eq!(
+            chainspec.system_costs.unwrap().mint_costs["mint"],
+            2_500_000_000
+        );
+
+        assert_eq!(chainspec.other_sections.len(), 1);
+        assert!(chainspec.other_sections.contains_key("upcoming_feature"));
+    }
+
+    #[test]
+    fn should_retain_unparseable_sections() {
+        let chainspec =
+            Chainspec::from_toml(b"[network]\nname = 'casper-test'\nmaximum_net_message_size = -1")
+                .unwrap();
+        assert!(chainspec.network.is_none());
+        assert_eq!(
+            chainspec.other_sections["network"]["name"].as_str(),
+            Some("casper-test")
+        );
+
+        assert!(matches!(
+            Chainspec::from_toml(b"[network"),
+            Err(Error::InvalidChainspec(_))
+        ));
+    }
+}
@@ -5,13 +5,13 @@ use serde::{Deserialize, Serialize};
 
 use casper_hashing::Digest;
 use casper_types::{
-    bytesrepr::{self, ToBytes},
+    bytesrepr::{self, FromBytes, ToBytes},
     crypto, PublicKey, SecretKey, Signature, URef, U512,
 };
 
 use crate::{
     types::{ExecutableDeployItem, TimeDiff, Timestamp},
-    Error, TransferTarget,
+    Error, HttpTransport, JsonRpcId, NetworkLimits, Signer, TransferTarget, Transport, Verbosity,
 };
 
 /// The maximum permissible size in bytes of a Deploy when serialized via `ToBytes`.
@@ -65,6 +65,12 @@ impl ToBytes for DeployHash {
     }
 }
 
+impl FromBytes for DeployHash {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        Digest::from_bytes(bytes).map(|(inner, remainder)| (DeployHash(inner), remainder))
+    }
+}
+
 /// The header portion of a [`Deploy`].
 #[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Serialize, Deserialize, Debug)]
 #[serde(deny_unknown_fields)]
@@ -94,6 +100,11 @@ impl DeployHeader {
         self.ttl
     }
 
+    /// Returns the timestamp after which the deploy can no longer be included in a block.
+    pub fn expires(&self) -> Timestamp {
+        self.timestamp + self.ttl
+    }
+
     /// Returns the price per gas unit for this deploy.
     pub fn gas_price(&self) -> u64 {
         self.gas_price
@@ -153,6 +164,28 @@ impl ToBytes for DeployHeader {
     }
 }
 
+impl FromBytes for DeployHeader {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (account, remainder) = PublicKey::from_bytes(bytes)?;
+        let (timestamp, remainder) = Timestamp::from_bytes(remainder)?;
+        let (ttl, remainder) = TimeDiff::from_bytes(remainder)?;
+        let (gas_price, remainder) = u64::from_bytes(remainder)?;
+        let (body_hash, remainder) = Digest::from_bytes(remainder)?;
+        let (dependencies, remainder) = Vec::<DeployHash>::from_bytes(remainder)?;
+        let (chain_name, remainder) = String::from_bytes(remainder)?;
+        let header = DeployHeader {
+            account,
+            timestamp,
+            ttl,
+            gas_price,
+            body_hash,
+            dependencies,
+            chain_name,
+        };
+        Ok((header, remainder))
+    }
+}
+
 /// The signature of a deploy and the public key of the signer.
 #[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Serialize, Deserialize, Debug)]
 #[serde(deny_unknown_fields)]
@@ -190,6 +223,14 @@ impl ToBytes for Approval {
     }
 }
 
+impl FromBytes for Approval {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (signer, remainder) = PublicKey::from_bytes(bytes)?;
+        let (signature, remainder) = Signature::from_bytes(remainder)?;
+        Ok((Approval { signer, signature }, remainder))
+    }
+}
+
 /// A signed item sent to the network used to request execution of Wasm.
 ///
 /// Note that constructing a `Deploy` is done via the [`DeployBuilder`].
@@ -212,51 +253,13 @@ pub struct Deploy {
     pub approvals: Vec<Approval>,
 }
 
-/// Used when constructing a `Deploy`.
-#[derive(Debug)]
-pub enum AccountAndSecretKey<'a> {
-    /// Provides both the account and the secret key (not necessarily for the same account) used to
-    /// sign the `Deploy`.
-    Both {
-        /// The public key of the account.
-        account: PublicKey,
-        /// The secret key used to sign the `Deploy`.
-        secret_key: &'a SecretKey,
-    },
-    /// The public key of the account.  The `Deploy` will be created unsigned as no secret key is
-    /// provided.
-    Account(PublicKey),
-    /// The account will be derived from the provided secret key, and the `Deploy` will be signed by
-    /// the same secret key.
-    SecretKey(&'a SecretKey),
-}
-
-impl<'a> AccountAndSecretKey<'a> {
-    fn account(&self) -> PublicKey {
-        match self {
-            AccountAndSecretKey::Both { account, .. } | AccountAndSecretKey::Account(account) => {
-                account.clone()
-            }
-            AccountAndSecretKey::SecretKey(secret_key) => PublicKey::from(*secret_key),
-        }
-    }
-
-    fn secret_key(&self) -> Option<&SecretKey> {
-        match self {
-            AccountAndSecretKey::Both { secret_key, .. }
-            | AccountAndSecretKey::SecretKey(secret_key) => Some(secret_key),
-            AccountAndSecretKey::Account(_) => None,
-        }
-    }
-}
-
 impl Deploy {
     /// The default time-to-live for `Deploy`s, i.e. 30 minutes.
     pub const DEFAULT_TTL: TimeDiff = TimeDiff::from_millis(30 * 60 * 1_000);
     /// The default gas price for `Deploy`s, i.e. `1`.
     pub const DEFAULT_GAS_PRICE: u64 = 1;
 
-    /// Constructs a new signed `Deploy`.
+    /// Constructs a new unsigned `Deploy`.
     #[allow(clippy::too_many_arguments)]
     fn new(
         timestamp: Timestamp,
@@ -266,13 +269,11 @@ impl Deploy {
         chain_name: String,
         payment: ExecutableDeployItem,
         session: ExecutableDeployItem,
-        account_and_secret_key: AccountAndSecretKey,
+        account: PublicKey,
     ) -> Deploy {
         let serialized_body = serialize_body(&payment, &session);
         let body_hash = Digest::hash(serialized_body);
 
-        let account = account_and_secret_key.account();
-
         // Remove duplicates.
         let dependencies = dependencies.into_iter().unique().collect();
         let header = DeployHeader {
@@ -289,35 +290,72 @@ impl Deploy {
             .unwrap_or_else(|error| panic!("should serialize deploy header: {}", error));
         let hash = DeployHash(Digest::hash(serialized_header));
 
-        let mut deploy = Deploy {
+        Deploy {
             hash,
             header,
             payment,
             session,
             approvals: vec![],
-        };
-
-        if let Some(secret_key) = account_and_secret_key.secret_key() {
-            deploy.sign(secret_key);
         }
-        deploy
     }
 
-    /// Adds a signature of this deploy's hash to its approvals.
-    pub fn sign(&mut self, secret_key: &SecretKey) {
-        let signer = PublicKey::from(secret_key);
-        let signature = crypto::sign(self.hash.0, secret_key, &signer);
+    /// Adds a signature of this deploy's hash, provided by `signer`, to its approvals.
+    pub fn sign(&mut self, signer: &dyn Signer) -> Result<(), Error> {
+        let (signer, signature) = signer.sign(&self.hash)?;
         let approval = Approval { signer, signature };
         self.approvals.push(approval);
+        Ok(())
+    }
+
+    /// Returns an unsigned copy of this deploy with the given timestamp, and optionally a new TTL,
+    /// chain name and gas price.
+    ///
+    /// Since the copy has a different hash, the approvals of this deploy are not valid for it and
+    /// are dropped; the copy must be signed again.
+    pub fn restamp(
+        &self,
+        timestamp: Timestamp,
+        maybe_ttl: Option<TimeDiff>,
+        maybe_chain_name: Option<String>,
+        maybe_gas_price: Option<u64>,
+    ) -> Deploy {
+        Deploy::new(
+            timestamp,
+            maybe_ttl.unwrap_or(self.header.ttl),
+            maybe_gas_price.unwrap_or(self.header.gas_price),
+            self.header.dependencies.clone(),
+            maybe_chain_name.unwrap_or_else(|| self.header.chain_name.clone()),
+            self.payment.clone(),
+            self.session.clone(),
+            self.header.account.clone(),
+        )
+    }
+
+    /// Adds the approvals of `other`, a copy of this deploy, to this deploy's approvals.
+    ///
+    /// Approvals already held by this deploy are not duplicated.  Returns an error if `other` has
+    /// a different hash to this deploy, or if either deploy fails the hash or approval checks of
+    /// [`verify`](Self::verify).
+    pub fn merge_approvals(&mut self, other: Deploy) -> Result<(), Error> {
+        if other.hash != self.hash {
+            return Err(Error::MismatchedDeploys {
+                deploy_hash: self.hash,
+                other_deploy_hash: other.hash,
+            });
+        }
+        self.verify_hashes_and_approvals()?;
+        other.verify_hashes_and_approvals()?;
+        for approval in other.approvals {
+            if !self.approvals.contains(&approval) {
+                self.approvals.push(approval);
+            }
+        }
+        Ok(())
     }
 
     /// Returns `Ok` if the serialized size of the deploy is not greater than `max_deploy_size`.
     pub fn is_valid_size(&self, max_deploy_size: u32) -> Result<(), Error> {
-        let deploy_size = self.header.serialized_length()
-            + self.hash.serialized_length()
-            + self.payment.serialized_length()
-            + self.session.serialized_length()
-            + self.approvals.serialized_length();
+        let deploy_size = self.serialized_length();
         if deploy_size > max_deploy_size as usize {
             return Err(Error::DeploySizeTooLarge {
                 max_deploy_size,
@@ -327,6 +365,69 @@ impl Deploy {
         Ok(())
     }
 
+    /// Returns `Ok` if the deploy is internally consistent and valid for the given chain.
+    ///
+    /// Returns an error if
+    ///   * the body hash in the header is not the hash of the payment and session code
+    ///   * the deploy hash is not the hash of the header
+    ///   * the signature of any approval is not a valid signature of the deploy hash by its signer
+    ///   * the chain name in the header is not `chain_name`
+    ///   * the TTL in the header exceeds `max_ttl`
+    pub fn verify(&self, chain_name: &str, max_ttl: TimeDiff) -> Result<(), Error> {
+        self.verify_hashes_and_approvals()?;
+
+        if self.header.chain_name != chain_name {
+            return Err(Error::DeployChainNameMismatch {
+                deploy_hash: self.hash,
+                expected_chain_name: chain_name.to_string(),
+                actual_chain_name: self.header.chain_name.clone(),
+            });
+        }
+
+        if self.header.ttl > max_ttl {
+            return Err(Error::DeployExcessiveTtl {
+                deploy_hash: self.hash,
+                max_ttl,
+                ttl: self.header.ttl,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns `Ok` if the body hash, deploy hash and approvals are valid, as per the first three
+    /// checks of [`verify`](Self::verify).
+    pub(crate) fn verify_hashes_and_approvals(&self) -> Result<(), Error> {
+        let actual_body_hash = Digest::hash(serialize_body(&self.payment, &self.session));
+        if self.header.body_hash != actual_body_hash {
+            return Err(Error::DeployBodyHashMismatch {
+                deploy_hash: self.hash,
+                body_hash: self.header.body_hash,
+                actual_body_hash,
+            });
+        }
+
+        let actual_deploy_hash = DeployHash(Digest::hash(self.header.to_bytes()?));
+        if self.hash != actual_deploy_hash {
+            return Err(Error::DeployHashMismatch {
+                deploy_hash: self.hash,
+                actual_deploy_hash,
+            });
+        }
+
+        for approval in &self.approvals {
+            crypto::verify(self.hash.0, &approval.signature, &approval.signer).map_err(
+                |error| Error::DeployInvalidApproval {
+                    deploy_hash: self.hash,
+                    signer: approval.signer.clone(),
+                    error,
+                },
+            )?;
+        }
+
+        Ok(())
+    }
+
     /// Returns the hash uniquely identifying this deploy.
     pub fn id(&self) -> &DeployHash {
         &self.hash
@@ -368,6 +469,48 @@ impl Display for Deploy {
     }
 }
 
+impl ToBytes for Deploy {
+    fn write_bytes(&self, buffer: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
+        self.header.write_bytes(buffer)?;
+        self.hash.write_bytes(buffer)?;
+        self.payment.write_bytes(buffer)?;
+        self.session.write_bytes(buffer)?;
+        self.approvals.write_bytes(buffer)
+    }
+
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = vec![];
+        self.write_bytes(&mut buffer)?;
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.header.serialized_length()
+            + self.hash.serialized_length()
+            + self.payment.serialized_length()
+            + self.session.serialized_length()
+            + self.approvals.serialized_length()
+    }
+}
+
+impl FromBytes for Deploy {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (header, remainder) = DeployHeader::from_bytes(bytes)?;
+        let (hash, remainder) = DeployHash::from_bytes(remainder)?;
+        let (payment, remainder) = ExecutableDeployItem::from_bytes(remainder)?;
+        let (session, remainder) = ExecutableDeployItem::from_bytes(remainder)?;
+        let (approvals, remainder) = Vec::<Approval>::from_bytes(remainder)?;
+        let deploy = Deploy {
+            hash,
+            header,
+            payment,
+            session,
+            approvals,
+        };
+        Ok((deploy, remainder))
+    }
+}
+
 fn serialize_body(payment: &ExecutableDeployItem, session: &ExecutableDeployItem) -> Vec<u8> {
     let mut buffer = payment
         .to_bytes()
@@ -381,9 +524,10 @@ fn serialize_body(payment: &ExecutableDeployItem, session: &ExecutableDeployItem
 }
 
 /// A builder for constructing a [`Deploy`].
+#[derive(Clone)]
 pub struct DeployBuilder<'a> {
     account: Option<PublicKey>,
-    secret_key: Option<&'a SecretKey>,
+    signer: Option<&'a dyn Signer>,
     timestamp: Option<Timestamp>,
     ttl: TimeDiff,
     gas_price: u64,
@@ -391,6 +535,7 @@ pub struct DeployBuilder<'a> {
     chain_name: String,
     payment: Option<ExecutableDeployItem>,
     session: ExecutableDeployItem,
+    network_limits: Option<&'a NetworkLimits>,
 }
 
 impl<'a> DeployBuilder<'a> {
@@ -399,8 +544,9 @@ impl<'a> DeployBuilder<'a> {
     /// # Note
     ///
     /// Before calling [`build`](Self::build), you must ensure
-    ///   * that an account is provided by either calling [`with_account`](Self::with_account) or
-    ///     [`with_secret_key`](Self::with_secret_key)
+    ///   * that an account is provided by either calling [`with_account`](Self::with_account),
+    ///     [`with_secret_key`](Self::with_secret_key) or [`with_signer`](Self::with_signer) with a
+    ///     signer whose public key is known
     ///   * that payment code is provided by either calling
     ///     [`with_standard_payment`](Self::with_standard_payment) or
     ///     [`with_payment`](Self::with_payment)
@@ -415,7 +561,7 @@ impl<'a> DeployBuilder<'a> {
 
         DeployBuilder {
             account: None,
-            secret_key: None,
+            signer: None,
             timestamp,
             ttl: Deploy::DEFAULT_TTL,
             gas_price: Deploy::DEFAULT_GAS_PRICE,
@@ -423,6 +569,7 @@ impl<'a> DeployBuilder<'a> {
             chain_name: chain_name.into(),
             payment: None,
             session,
+            network_limits: None,
         }
     }
 
@@ -433,8 +580,9 @@ impl<'a> DeployBuilder<'a> {
     /// # Note
     ///
     /// Before calling [`build`](Self::build), you must ensure
-    ///   * that an account is provided by either calling [`with_account`](Self::with_account) or
-    ///     [`with_secret_key`](Self::with_secret_key)
+    ///   * that an account is provided by either calling [`with_account`](Self::with_account),
+    ///     [`with_secret_key`](Self::with_secret_key) or [`with_signer`](Self::with_signer) with a
+    ///     signer whose public key is known
     ///   * that payment code is provided by either calling
     ///     [`with_standard_payment`](Self::with_standard_payment) or
     ///     [`with_payment`](Self::with_payment)
@@ -454,19 +602,30 @@ impl<'a> DeployBuilder<'a> {
 
     /// Sets the `account` in the `Deploy`.
     ///
-    /// If not provided, the public key derived from the secret key used in the `DeployBuilder` will
+    /// If not provided, the public key of the secret key or signer used in the `DeployBuilder` will
     /// be used as the `account` in the `Deploy`.
     pub fn with_account(mut self, account: PublicKey) -> Self {
         self.account = Some(account);
         self
     }
 
-    /// Sets the secret key used to sign the `Deploy` on calling [`build`](Self::build).
+    /// Sets the secret key used to sign the `Deploy` on calling [`build`](Self::build), replacing
+    /// any signer previously provided.
     ///
     /// If not provided, the `Deploy` can still be built, but will be unsigned and will be invalid
     /// until subsequently signed.
     pub fn with_secret_key(mut self, secret_key: &'a SecretKey) -> Self {
-        self.secret_key = Some(secret_key);
+        self.signer = Some(secret_key);
+        self
+    }
+
+    /// Sets the signer used to sign the `Deploy` on calling [`build`](Self::build), replacing any
+    /// secret key previously provided.
+    ///
+    /// If not provided, the `Deploy` can still be built, but will be unsigned and will be invalid
+    /// until subsequently signed.
+    pub fn with_signer(mut self, signer: &'a dyn Signer) -> Self {
+        self.signer = Some(signer);
         self
     }
 
@@ -501,27 +660,52 @@ impl<'a> DeployBuilder<'a> {
         self
     }
 
+    /// Sets the `gas_price` (price per gas unit, in motes) in the `Deploy`.
+    ///
+    /// If not provided, the gas price will be set to [`Deploy::DEFAULT_GAS_PRICE`].
+    pub fn with_gas_price(mut self, gas_price: u64) -> Self {
+        self.gas_price = gas_price;
+        self
+    }
+
+    /// Sets the `dependencies` in the `Deploy`, i.e. the hashes of deploys which must be executed
+    /// before this one.
+    ///
+    /// Duplicate hashes are removed.  If not provided, the `Deploy` will have no dependencies.
+    pub fn with_dependencies(mut self, dependencies: Vec<DeployHash>) -> Self {
+        self.dependencies = dependencies;
+        self
+    }
+
+    /// Sets the limits of the network for which the `Deploy` is intended, e.g. as returned by
+    /// [`discover_network_limits`](crate::discover_network_limits).
+    ///
+    /// If provided, [`build`](Self::build) checks the `Deploy` against the limits before signing
+    /// it, and checks its size again once signed.
+    pub fn with_network_limits(mut self, network_limits: &'a NetworkLimits) -> Self {
+        self.network_limits = Some(network_limits);
+        self
+    }
+
     /// Returns the new `Deploy`.
     ///
     /// Returns an error if
+    ///   * no account was provided and none could be derived from the secret key or signer
     ///   * neither [`with_standard_payment`](Self::with_standard_payment) nor
     ///     [`with_payment`](Self::with_payment) were previously called
     ///   * if feature `std-fs-io` was not enabled (it is enabled by default) and
     ///     [`with_timestamp`](Self::with_timestamp) was not previously called
+    ///   * network limits were provided and the `Deploy` is invalid as per [`NetworkLimits::check`]
+    ///   * the signer failed to sign the `Deploy`
     pub fn build(self) -> Result<Deploy, Error> {
-        let account_and_secret_key = match (self.account, self.secret_key) {
-            (Some(account), Some(secret_key)) => AccountAndSecretKey::Both {
-                account,
-                secret_key,
-            },
-            (Some(account), None) => AccountAndSecretKey::Account(account),
-            (None, Some(secret_key)) => AccountAndSecretKey::SecretKey(secret_key),
-            (None, None) => return Err(Error::DeployMissingSessionAccount),
-        };
+        let account = self
+            .account
+            .or_else(|| self.signer.and_then(|signer| signer.public_key()))
+            .ok_or(Error::DeployMissingSessionAccount)?;
 
         let timestamp = self.timestamp.ok_or(Error::DeployMissingTimestamp)?;
         let payment = self.payment.ok_or(Error::DeployMissingPaymentCode)?;
-        let deploy = Deploy::new(
+        let mut deploy = Deploy::new(
             timestamp,
             self.ttl,
             self.gas_price,
@@ -529,8 +713,253 @@ impl<'a> DeployBuilder<'a> {
             self.chain_name,
             payment,
             self.session,
-            account_and_secret_key,
+            account,
         );
+
+        if let Some(network_limits) = self.network_limits {
+            network_limits.check(&deploy)?;
+        }
+        if let Some(signer) = self.signer {
+            deploy.sign(signer)?;
+            // The approval adds to the size of the deploy.
+            if let Some(network_limits) = self.network_limits {
+                deploy.is_valid_size(network_limits.max_deploy_size)?;
+            }
+        }
         Ok(deploy)
     }
+
+    /// Returns the new `Deploy`, with a standard payment of the cost of executing it plus a margin.
+    ///
+    /// A trial `Deploy` is built and signed with the payment code provided via
+    /// [`with_standard_payment`](Self::with_standard_payment) or
+    /// [`with_payment`](Self::with_payment), which must cover the gas used by the session code,
+    /// and is sent to the specified node via a JSON-RPC `speculative_exec` request to be executed
+    /// on top of the latest block.  The returned `Deploy` is then built and signed with a standard
+    /// payment of the reported cost plus `margin_percent` percent of it, so the secret key or
+    /// signer is used twice.
+    ///
+    /// Returns [`Error::SpeculativeExecutionFailed`] if the trial `Deploy` failed to execute,
+    /// otherwise returns an error as per [`build`](Self::build) or if the request fails.
+    ///
+    /// For details of the other parameters, see [the module docs](crate#common-parameters).
+    pub async fn build_with_auto_payment(
+        self,
+        rpc_id: JsonRpcId,
+        node_address: &str,
+        verbosity: Verbosity,
+        margin_percent: u32,
+    ) -> Result<Deploy, Error> {
+        self.build_with_auto_payment_with_transport(
+            &HttpTransport::default(),
+            rpc_id,
+            node_address,
+            verbosity,
+            margin_percent,
+        )
+        .await
+    }
+
+    /// Same as [`build_with_auto_payment`](Self::build_with_auto_payment), but sends the request
+    /// via the given `transport`.
+    pub async fn build_with_auto_payment_with_transport(
+        self,
+        transport: &dyn Transport,
+        rpc_id: JsonRpcId,
+        node_address: &str,
+        verbosity: Verbosity,
+        margin_percent: u32,
+    ) -> Result<Deploy, Error> {
+        let trial_deploy = self.clone().build()?;
+        let deploy_hash = *trial_deploy.id();
+        let execution_result = crate::speculative_exec_with_transport(
+            transport,
+            rpc_id,
+            node_address,
+            None,
+            verbosity,
+            trial_deploy,
+        )
+        .await?
+        .result
+        .execution_result;
+        let cost = match execution_result {
+            casper_types::ExecutionResult::Success { cost, .. } => cost,
+            casper_types::ExecutionResult::Failure { error_message, .. } => {
+                return Err(Error::SpeculativeExecutionFailed {
+                    deploy_hash,
+                    error_message,
+                })
+            }
+        };
+        let margin = cost * U512::from(margin_percent) / 100;
+        self.with_standard_payment(cost + margin).build()
+    }
+}
+
+#[cfg(all(test, feature = "tokio"))]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use async_trait::async_trait;
+    use jsonrpc_lite::JsonRpc;
+
+    use casper_types::{ExecutionEffect, ProtocolVersion};
+
+    use super::*;
+    use crate::{rpcs::results::SpeculativeExecResult, types::BlockHash};
+
+    /// A transport which responds to every `speculative_exec` request with the same execution
+    /// result, or with an error if the deploy has no approvals, as a node does.
+    #[derive(Debug)]
+    struct SpeculativeExecTransport {
+        execution_result: casper_types::ExecutionResult,
+    }
+
+    #[async_trait]
+    impl Transport for SpeculativeExecTransport {
+        async fn send(
+            &self,
+            _url: &str,
+            rpc_id: &JsonRpcId,
+            _rpc_method: &'static str,
+            request: &JsonRpc,
+        ) -> Result<JsonRpc, Error> {
+            let request = serde_json::to_value(request).unwrap();
+            if request["params"]["deploy"]["approvals"]
+                .as_array()
+                .map_or(true, Vec::is_empty)
+            {
+                let error = jsonrpc_lite::Error {
+                    code: -32016,
+                    message: "deploy has no approvals".to_string(),
+                    data: None,
+                };
+                return Ok(JsonRpc::error(rpc_id, error));
+            }
+            let result = SpeculativeExecResult {
+                api_version: ProtocolVersion::from_parts(1, 5, 0),
+                block_hash: BlockHash::new(Digest::hash([2; 32])),
+                execution_result: self.execution_result.clone(),
+            };
+            Ok(JsonRpc::success(
+                rpc_id,
+                &serde_json::to_value(&result).unwrap(),
+            ))
+        }
+    }
+
+    /// A signer which counts the deploys it signs.
+    struct CountingSigner {
+        secret_key: SecretKey,
+        count: AtomicUsize,
+    }
+
+    impl Signer for CountingSigner {
+        fn public_key(&self) -> Option<PublicKey> {
+            Signer::public_key(&self.secret_key)
+        }
+
+        fn sign(&self, deploy_hash: &DeployHash) -> Result<(PublicKey, Signature), Error> {
+            self.count.fetch_add(1, Ordering::SeqCst);
+            Signer::sign(&self.secret_key, deploy_hash)
+        }
+    }
+
+    fn builder(signer: &dyn Signer) -> DeployBuilder<'_> {
+        DeployBuilder::new_transfer(
+            "casper-test",
+            2_500_000_000_u64,
+            None,
+            TransferTarget::PublicKey(signer.public_key().unwrap()),
+            None,
+        )
+        .with_standard_payment(100_000_000_000_u64)
+        .with_signer(signer)
+    }
+
+    async fn build(
+        deploy_builder: DeployBuilder<'_>,
+        execution_result: casper_types::ExecutionResult,
+    ) -> Result<Deploy, Error> {
+        deploy_builder
+            .build_with_auto_payment_with_transport(
+                &SpeculativeExecTransport { execution_result },
+                JsonRpcId::from(1),
+                "http://localhost:7777",
+                Verbosity::Low,
+                20,
+            )
+            .await
+    }
+
+    #[tokio::test]
+    async fn should_set_payment_amount_from_speculative_execution_cost() {
+        let secret_key = SecretKey::ed25519_from_bytes([1; 32]).unwrap();
+        let execution_result = casper_types::ExecutionResult::Success {
+            effect: ExecutionEffect::default(),
+            transfers: vec![],
+            cost: U512::from(100_000_000),
+        };
+
+        let deploy = build(builder(&secret_key), execution_result).await.unwrap();
+        let expected_payment = ExecutableDeployItem::new_standard_payment(120_000_000_u64);
+        assert_eq!(*deploy.payment(), expected_payment);
+        assert!(deploy.verify("casper-test", Deploy::DEFAULT_TTL).is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_sign_trial_and_returned_deploys() {
+        let signer = CountingSigner {
+            secret_key: SecretKey::ed25519_from_bytes([1; 32]).unwrap(),
+            count: AtomicUsize::new(0),
+        };
+        let execution_result = casper_types::ExecutionResult::Success {
+            effect: ExecutionEffect::default(),
+            transfers: vec![],
+            cost: U512::from(100_000_000),
+        };
+
+        let deploy = build(builder(&signer), execution_result.clone())
+            .await
+            .unwrap();
+        assert_eq!(signer.count.load(Ordering::SeqCst), 2);
+        assert_eq!(deploy.approvals().len(), 1);
+
+        // Without a signer, the node rejects the trial deploy.
+        let account = signer.public_key().unwrap();
+        let unsigned_builder = DeployBuilder::new_transfer(
+            "casper-test",
+            2_500_000_000_u64,
+            None,
+            TransferTarget::PublicKey(account.clone()),
+            None,
+        )
+        .with_standard_payment(100_000_000_000_u64)
+        .with_account(account);
+        assert!(matches!(
+            build(unsigned_builder, execution_result).await,
+            Err(Error::ResponseIsRpcError { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_fail_if_speculative_execution_fails() {
+        let secret_key = SecretKey::ed25519_from_bytes([1; 32]).unwrap();
+        let execution_result = casper_types::ExecutionResult::Failure {
+            effect: ExecutionEffect::default(),
+            transfers: vec![],
+            cost: U512::from(100_000_000),
+            error_message: "Out of gas error".to_string(),
+        };
+
+        let error = build(builder(&secret_key), execution_result)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::SpeculativeExecutionFailed { error_message, .. }
+                if error_message == "Out of gas error"
+        ));
+    }
 }
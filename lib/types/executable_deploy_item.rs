@@ -2,7 +2,7 @@ use hex_buffer_serde::{Hex, HexForm};
 use serde::{Deserialize, Serialize};
 
 use casper_types::{
-    bytesrepr::{self, Bytes, ToBytes},
+    bytesrepr::{self, Bytes, FromBytes, ToBytes},
     runtime_args, ContractHash, ContractPackageHash, ContractVersion, RuntimeArgs, URef, U512,
 };
 
@@ -325,3 +325,134 @@ impl ToBytes for ExecutableDeployItem {
             }
     }
 }
+
+impl FromBytes for ExecutableDeployItem {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        match tag {
+            MODULE_BYTES_TAG => {
+                let (module_bytes, remainder) = Bytes::from_bytes(remainder)?;
+                let (args, remainder) = RuntimeArgs::from_bytes(remainder)?;
+                Ok((
+                    ExecutableDeployItem::ModuleBytes { module_bytes, args },
+                    remainder,
+                ))
+            }
+            STORED_CONTRACT_BY_HASH_TAG => {
+                let (hash, remainder) = ContractHash::from_bytes(remainder)?;
+                let (entry_point, remainder) = String::from_bytes(remainder)?;
+                let (args, remainder) = RuntimeArgs::from_bytes(remainder)?;
+                Ok((
+                    ExecutableDeployItem::StoredContractByHash {
+                        hash,
+                        entry_point,
+                        args,
+                    },
+                    remainder,
+                ))
+            }
+            STORED_CONTRACT_BY_NAME_TAG => {
+                let (name, remainder) = String::from_bytes(remainder)?;
+                let (entry_point, remainder) = String::from_bytes(remainder)?;
+                let (args, remainder) = RuntimeArgs::from_bytes(remainder)?;
+                Ok((
+                    ExecutableDeployItem::StoredContractByName {
+                        name,
+                        entry_point,
+                        args,
+                    },
+                    remainder,
+                ))
+            }
+            STORED_VERSIONED_CONTRACT_BY_HASH_TAG => {
+                let (hash, remainder) = ContractPackageHash::from_bytes(remainder)?;
+                let (version, remainder) = Option::<ContractVersion>::from_bytes(remainder)?;
+                let (entry_point, remainder) = String::from_bytes(remainder)?;
+                let (args, remainder) = RuntimeArgs::from_bytes(remainder)?;
+                Ok((
+                    ExecutableDeployItem::StoredVersionedContractByHash {
+                        hash,
+                        version,
+                        entry_point,
+                        args,
+                    },
+                    remainder,
+                ))
+            }
+            STORED_VERSIONED_CONTRACT_BY_NAME_TAG => {
+                let (name, remainder) = String::from_bytes(remainder)?;
+                let (version, remainder) = Option::<ContractVersion>::from_bytes(remainder)?;
+                let (entry_point, remainder) = String::from_bytes(remainder)?;
+                let (args, remainder) = RuntimeArgs::from_bytes(remainder)?;
+                Ok((
+                    ExecutableDeployItem::StoredVersionedContractByName {
+                        name,
+                        version,
+                        entry_point,
+                        args,
+                    },
+                    remainder,
+                ))
+            }
+            TRANSFER_TAG => {
+                let (args, remainder) = RuntimeArgs::from_bytes(remainder)?;
+                Ok((ExecutableDeployItem::Transfer { args }, remainder))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::account::AccountHash;
+
+    use super::*;
+
+    #[test]
+    fn should_round_trip_via_bytesrepr() {
+        let args = runtime_args! { "a" => 1_u64, "b" => "two" };
+        let items = vec![
+            ExecutableDeployItem::ModuleBytes {
+                module_bytes: Bytes::from(vec![0, 97, 115, 109]),
+                args: args.clone(),
+            },
+            ExecutableDeployItem::StoredContractByHash {
+                hash: ContractHash::new([1; 32]),
+                entry_point: "call".to_string(),
+                args: args.clone(),
+            },
+            ExecutableDeployItem::StoredContractByName {
+                name: "contract".to_string(),
+                entry_point: "call".to_string(),
+                args: args.clone(),
+            },
+            ExecutableDeployItem::StoredVersionedContractByHash {
+                hash: ContractPackageHash::new([2; 32]),
+                version: Some(3),
+                entry_point: "call".to_string(),
+                args: args.clone(),
+            },
+            ExecutableDeployItem::StoredVersionedContractByName {
+                name: "package".to_string(),
+                version: None,
+                entry_point: "call".to_string(),
+                args,
+            },
+            ExecutableDeployItem::new_transfer(
+                10_u64,
+                None,
+                TransferTarget::AccountHash(AccountHash::new([4; 32])),
+                Some(5),
+            ),
+        ];
+        for item in items {
+            let bytes = item.to_bytes().unwrap();
+            assert_eq!(bytes.len(), item.serialized_length());
+            assert_eq!(
+                bytesrepr::deserialize::<ExecutableDeployItem>(bytes).unwrap(),
+                item
+            );
+        }
+    }
+}
@@ -7,7 +7,7 @@ use std::{
 use humantime::DurationError;
 use serde::{de::Error as SerdeError, Deserialize, Deserializer, Serialize, Serializer};
 
-use casper_types::bytesrepr::{self, ToBytes};
+use casper_types::bytesrepr::{self, FromBytes, ToBytes};
 
 /// A time difference between two timestamps.
 #[derive(Copy, Clone, Default, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
@@ -18,6 +18,11 @@ impl TimeDiff {
     pub const fn from_millis(millis: u64) -> Self {
         TimeDiff(millis)
     }
+
+    /// Returns the time difference as a number of milliseconds.
+    pub const fn millis(&self) -> u64 {
+        self.0
+    }
 }
 
 impl Display for TimeDiff {
@@ -74,3 +79,9 @@ impl ToBytes for TimeDiff {
         self.0.serialized_length()
     }
 }
+
+impl FromBytes for TimeDiff {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        u64::from_bytes(bytes).map(|(inner, remainder)| (TimeDiff(inner), remainder))
+    }
+}
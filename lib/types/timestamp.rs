@@ -1,5 +1,6 @@
 use std::{
     fmt::{self, Display, Formatter},
+    ops::Add,
     str::FromStr,
     time::{Duration, SystemTime},
 };
@@ -7,7 +8,9 @@ use std::{
 use humantime::TimestampError;
 use serde::{de::Error as SerdeError, Deserialize, Deserializer, Serialize, Serializer};
 
-use casper_types::bytesrepr::{self, ToBytes};
+use casper_types::bytesrepr::{self, FromBytes, ToBytes};
+
+use super::TimeDiff;
 
 /// A timestamp newtype, representing a specific moment in time.
 #[derive(Copy, Clone, Default, PartialOrd, Ord, PartialEq, Eq, Hash, Debug)]
@@ -22,6 +25,11 @@ impl Timestamp {
             .as_millis() as u64;
         Timestamp(millis)
     }
+
+    /// Returns the time that has elapsed since this timestamp, or zero if it is in the future.
+    pub fn elapsed(&self) -> TimeDiff {
+        TimeDiff::from_millis(Timestamp::now().0.saturating_sub(self.0))
+    }
 }
 
 impl Display for Timestamp {
@@ -41,6 +49,14 @@ impl Display for Timestamp {
     }
 }
 
+impl Add<TimeDiff> for Timestamp {
+    type Output = Timestamp;
+
+    fn add(self, diff: TimeDiff) -> Timestamp {
+        Timestamp(self.0.saturating_add(diff.millis()))
+    }
+}
+
 impl FromStr for Timestamp {
     type Err = TimestampError;
 
@@ -89,3 +105,9 @@ impl ToBytes for Timestamp {
         self.0.serialized_length()
     }
 }
+
+impl FromBytes for Timestamp {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        u64::from_bytes(bytes).map(|(inner, remainder)| (Timestamp(inner), remainder))
+    }
+}
@@ -1,7 +1,7 @@
-/// The level of verbosity of the output to stdout.
+/// The level of detail of the request and response payloads emitted as `tracing` events.
 #[derive(Clone, Copy, Eq, PartialEq, Debug)]
 pub enum Verbosity {
-    /// Minimal output.
+    /// Minimal output.  Payloads are abbreviated as for `Medium`.
     Low = 0,
     /// Moderate amount of output, with any long hex strings shortened to a string indicating the
     /// character count of the string.
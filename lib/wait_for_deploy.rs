@@ -0,0 +1,312 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+use casper_types::U512;
+
+#[cfg(doc)]
+use crate::types::Deploy;
+use crate::{
+    types::{BlockHash, DeployHash, ExecutionResult, TimeDiff, Timestamp},
+    Error, HttpTransport, JsonRpcId, Transport, Verbosity,
+};
+
+/// The interval between successive `info_get_deploy` requests while waiting for a deploy.
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+/// The time after a deploy's expiry during which it is still polled for.
+///
+/// A deploy can be included in a block proposed up until it expires, so its execution result may
+/// only become available shortly after expiry.
+const EXPIRY_GRACE_PERIOD: TimeDiff = TimeDiff::from_millis(60 * 1_000);
+/// The time for which a deploy unknown to the node is polled for, if no timeout is given.
+///
+/// A deploy sent to one node may take a while to be gossiped to the node being polled, e.g. when
+/// requests are spread across a [`NodePool`](crate::NodePool).
+const UNKNOWN_DEPLOY_GRACE_PERIOD: TimeDiff = TimeDiff::from_millis(5 * 60 * 1_000);
+/// The JSON-RPC error code with which the node responds to `info_get_deploy` for an unknown deploy.
+const NO_SUCH_DEPLOY_ERROR_CODE: i64 = -32000;
+
+/// The outcome of the execution of a [`Deploy`].
+#[derive(Clone, PartialEq, Eq, Serialize, Debug)]
+pub struct DeployOutcome {
+    /// The hash of the deploy.
+    pub deploy_hash: DeployHash,
+    /// The hash of the block in which the deploy was executed.
+    pub block_hash: BlockHash,
+    /// The cost of executing the deploy.
+    pub cost: U512,
+    /// The error message if execution failed, or `None` if it succeeded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+}
+
+impl DeployOutcome {
+    fn new(deploy_hash: DeployHash, execution_result: ExecutionResult) -> Self {
+        let (cost, error_message) = match execution_result.result {
+            casper_types::ExecutionResult::Success { cost, .. } => (cost, None),
+            casper_types::ExecutionResult::Failure {
+                cost,
+                error_message,
+                ..
+            } => (cost, Some(error_message)),
+        };
+        DeployOutcome {
+            deploy_hash,
+            block_hash: execution_result.block_hash,
+            cost,
+            error_message,
+        }
+    }
+
+    /// Returns `true` if the deploy was executed successfully.
+    pub fn is_success(&self) -> bool {
+        self.error_message.is_none()
+    }
+}
+
+/// Waits for the [`Deploy`] with the given hash to be executed.
+///
+/// Sends a JSON-RPC `info_get_deploy` request to the specified node every two seconds until the
+/// node reports an execution result for the deploy.  A deploy which fails during execution is still
+/// included in a block, so yields `Ok` with a [`DeployOutcome`] for which
+/// [`is_success`](DeployOutcome::is_success) is `false`.
+///
+/// Stops waiting and returns [`Error::TimedOutWaitingForDeploy`] once `maybe_timeout` (if `Some`)
+/// has elapsed, or [`Error::DeployExpired`] a minute after the deploy's time-to-live has expired.
+///
+/// A response that the deploy is not known to the node is treated as the deploy not having been
+/// executed yet, since the node may not have received it yet.  If `maybe_timeout` is `None` and the
+/// node still doesn't know the deploy after five minutes, that error is returned.  Any other error
+/// from an `info_get_deploy` request is returned immediately.
+///
+/// For details of the other parameters, see [the module docs](crate#common-parameters).
+pub async fn wait_for_deploy(
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    deploy_hash: DeployHash,
+    maybe_timeout: Option<TimeDiff>,
+) -> Result<DeployOutcome, Error> {
+    wait_for_deploy_with_transport(
+        &HttpTransport::default(),
+        rpc_id,
+        node_address,
+        verbosity,
+        deploy_hash,
+        maybe_timeout,
+    )
+    .await
+}
+
+/// Same as [`wait_for_deploy`], but sends the requests via the given `transport`.
+pub async fn wait_for_deploy_with_transport(
+    transport: &dyn Transport,
+    rpc_id: JsonRpcId,
+    node_address: &str,
+    verbosity: Verbosity,
+    deploy_hash: DeployHash,
+    maybe_timeout: Option<TimeDiff>,
+) -> Result<DeployOutcome, Error> {
+    let start = Timestamp::now();
+    loop {
+        let maybe_result = match crate::get_deploy_with_transport(
+            transport,
+            rpc_id.clone(),
+            node_address,
+            verbosity,
+            deploy_hash,
+            false,
+        )
+        .await
+        {
+            Ok(response) => Some(response.result),
+            Err(Error::ResponseIsRpcError { error, .. })
+                if error.code == NO_SUCH_DEPLOY_ERROR_CODE
+                    && (maybe_timeout.is_some()
+                        || start.elapsed() < UNKNOWN_DEPLOY_GRACE_PERIOD) =>
+            {
+                None
+            }
+            Err(error) => return Err(error),
+        };
+
+        if let Some(execution_result) = maybe_result
+            .as_ref()
+            .and_then(|result| result.execution_results.first())
+        {
+            return Ok(DeployOutcome::new(deploy_hash, execution_result.clone()));
+        }
+
+        if let Some(timeout) = maybe_timeout {
+            if start.elapsed() >= timeout {
+                return Err(Error::TimedOutWaitingForDeploy {
+                    deploy_hash,
+                    timeout,
+                });
+            }
+        }
+
+        if let Some(result) = maybe_result {
+            let expires = result.deploy.header().expires();
+            if Timestamp::now() > expires + EXPIRY_GRACE_PERIOD {
+                return Err(Error::DeployExpired {
+                    deploy_hash,
+                    expires,
+                });
+            }
+        }
+
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{str::FromStr, sync::Mutex};
+
+    use async_trait::async_trait;
+    use jsonrpc_lite::JsonRpc;
+
+    use casper_hashing::Digest;
+    use casper_types::{ExecutionEffect, ProtocolVersion, PublicKey, SecretKey};
+
+    use super::*;
+    use crate::{rpcs::results::GetDeployResult, types::DeployBuilder, TransferTarget};
+
+    /// A transport which responds to every `info_get_deploy` request with the same result, after
+    /// first responding to a given number of requests that the deploy is unknown.
+    #[derive(Debug)]
+    struct GetDeployTransport {
+        result: GetDeployResult,
+        unknown_responses: Mutex<u32>,
+    }
+
+    impl GetDeployTransport {
+        fn new(timestamp: Timestamp, execution_results: Vec<ExecutionResult>) -> Self {
+            let secret_key = SecretKey::ed25519_from_bytes([1; 32]).unwrap();
+            let deploy = DeployBuilder::new_transfer(
+                "casper-test",
+                2_500_000_000_u64,
+                None,
+                TransferTarget::PublicKey(PublicKey::from(&secret_key)),
+                None,
+            )
+            .with_standard_payment(100_000_000_u64)
+            .with_secret_key(&secret_key)
+            .with_timestamp(timestamp)
+            .with_ttl(TimeDiff::from_millis(1_000))
+            .build()
+            .unwrap();
+            let result = GetDeployResult {
+                api_version: ProtocolVersion::from_parts(1, 5, 0),
+                deploy,
+                execution_results,
+                block_hash_and_height: None,
+            };
+            GetDeployTransport {
+                result,
+                unknown_responses: Mutex::new(0),
+            }
+        }
+
+        fn deploy_hash(&self) -> DeployHash {
+            *self.result.deploy.id()
+        }
+    }
+
+    #[async_trait]
+    impl Transport for GetDeployTransport {
+        async fn send(
+            &self,
+            _url: &str,
+            rpc_id: &JsonRpcId,
+            _rpc_method: &'static str,
+            _request: &JsonRpc,
+        ) -> Result<JsonRpc, Error> {
+            let mut unknown_responses = self.unknown_responses.lock().unwrap();
+            if *unknown_responses > 0 {
+                *unknown_responses -= 1;
+                let error = jsonrpc_lite::Error {
+                    code: NO_SUCH_DEPLOY_ERROR_CODE,
+                    message: "no such deploy".to_string(),
+                    data: None,
+                };
+                return Ok(JsonRpc::error(rpc_id, error));
+            }
+            Ok(JsonRpc::success(
+                rpc_id,
+                &serde_json::to_value(&self.result).unwrap(),
+            ))
+        }
+    }
+
+    async fn wait(
+        transport: &GetDeployTransport,
+        maybe_timeout: Option<TimeDiff>,
+    ) -> Result<DeployOutcome, Error> {
+        wait_for_deploy_with_transport(
+            transport,
+            JsonRpcId::from(1),
+            "http://localhost:7777",
+            Verbosity::Low,
+            transport.deploy_hash(),
+            maybe_timeout,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn should_report_failed_execution() {
+        let block_hash = BlockHash::new(Digest::hash([2; 32]));
+        let execution_result = ExecutionResult {
+            block_hash,
+            result: casper_types::ExecutionResult::Failure {
+                effect: ExecutionEffect::default(),
+                transfers: vec![],
+                cost: U512::from(12_345),
+                error_message: "Out of gas error".to_string(),
+            },
+        };
+        let transport = GetDeployTransport::new(Timestamp::now(), vec![execution_result]);
+
+        let outcome = wait(&transport, None).await.unwrap();
+        assert!(!outcome.is_success());
+        assert_eq!(outcome.deploy_hash, transport.deploy_hash());
+        assert_eq!(outcome.block_hash, block_hash);
+        assert_eq!(outcome.cost, U512::from(12_345));
+        assert_eq!(outcome.error_message.as_deref(), Some("Out of gas error"));
+    }
+
+    #[tokio::test]
+    async fn should_keep_polling_while_deploy_unknown() {
+        let block_hash = BlockHash::new(Digest::hash([2; 32]));
+        let execution_result = ExecutionResult {
+            block_hash,
+            result: casper_types::ExecutionResult::Success {
+                effect: ExecutionEffect::default(),
+                transfers: vec![],
+                cost: U512::from(1),
+            },
+        };
+        let transport = GetDeployTransport::new(Timestamp::now(), vec![execution_result]);
+        *transport.unknown_responses.lock().unwrap() = 1;
+
+        let outcome = wait(&transport, None).await.unwrap();
+        assert!(outcome.is_success());
+        assert_eq!(*transport.unknown_responses.lock().unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn should_stop_waiting_at_timeout_or_expiry() {
+        let transport = GetDeployTransport::new(Timestamp::now(), vec![]);
+        let error = wait(&transport, Some(TimeDiff::from_millis(0)))
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::TimedOutWaitingForDeploy { .. }));
+
+        let long_ago = Timestamp::from_str("2020-01-01T00:00:00Z").unwrap();
+        let transport = GetDeployTransport::new(long_ago, vec![]);
+        let error = wait(&transport, None).await.unwrap_err();
+        assert!(matches!(error, Error::DeployExpired { .. }));
+    }
+}
@@ -0,0 +1,810 @@
+use std::ops::Range;
+
+use serde::Serialize;
+
+#[cfg(doc)]
+use crate::NetworkLimits;
+use crate::{
+    types::{Chainspec, MAX_SERIALIZED_SIZE_OF_DEPLOY},
+    Error,
+};
+
+const MAGIC: &[u8] = b"\0asm";
+const VERSION: &[u8] = &[1, 0, 0, 0];
+
+const CUSTOM_SECTION_ID: u8 = 0;
+const TYPE_SECTION_ID: u8 = 1;
+const IMPORT_SECTION_ID: u8 = 2;
+const MEMORY_SECTION_ID: u8 = 5;
+const GLOBAL_SECTION_ID: u8 = 6;
+const EXPORT_SECTION_ID: u8 = 7;
+const CODE_SECTION_ID: u8 = 10;
+
+const FUNCTION_TYPE: u8 = 0x60;
+const EMPTY_BLOCK_TYPE: u8 = 0x40;
+const F32_TYPE: u8 = 0x7d;
+const F64_TYPE: u8 = 0x7c;
+const END_OPCODE: u8 = 0x0b;
+
+/// The module from which the host functions provided by the network are imported.
+const HOST_MODULE: &str = "env";
+/// The function which the network calls to execute session or payment code.
+const CALL_EXPORT: &str = "call";
+/// The maximum number of 64 KiB memory pages on Mainnet.
+const DEFAULT_MAX_MEMORY_PAGES: u32 = 64;
+/// The prefix of the names of DWARF debug sections.
+const DEBUG_SECTION_PREFIX: &str = ".debug";
+/// The names of other custom sections holding debug info.
+const DEBUG_SECTION_NAMES: [&str; 3] = ["name", "sourceMappingURL", "external_debug_info"];
+
+/// The limits placed on the Wasm modules of deploys by a network.
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Debug)]
+pub struct WasmLimits {
+    /// The maximum size of a module in bytes, i.e. the maximum serialized size of a deploy.
+    pub max_module_size: u32,
+    /// The maximum number of 64 KiB pages of memory a module may use.
+    pub max_memory_pages: u32,
+}
+
+impl WasmLimits {
+    /// Returns the limits configured in `chainspec`, or the default limits for any section of the
+    /// chainspec which is missing.
+    pub fn from_chainspec(chainspec: &Chainspec) -> Self {
+        let default = WasmLimits::default();
+        WasmLimits {
+            max_module_size: chainspec
+                .deploys
+                .as_ref()
+                .map_or(default.max_module_size, |deploys| deploys.max_deploy_size),
+            max_memory_pages: chainspec
+                .wasm
+                .as_ref()
+                .map_or(default.max_memory_pages, |wasm| wasm.max_memory),
+        }
+    }
+}
+
+impl Default for WasmLimits {
+    /// Returns the limits of Mainnet.
+    fn default() -> Self {
+        WasmLimits {
+            max_module_size: MAX_SERIALIZED_SIZE_OF_DEPLOY,
+            max_memory_pages: DEFAULT_MAX_MEMORY_PAGES,
+        }
+    }
+}
+
+/// The kind of an item imported or exported by a Wasm module.
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum WasmExternalKind {
+    /// A function.
+    Function,
+    /// A table.
+    Table,
+    /// A linear memory.
+    Memory,
+    /// A global variable.
+    Global,
+}
+
+/// An item imported by a Wasm module.
+#[derive(Clone, PartialEq, Eq, Serialize, Debug)]
+pub struct WasmImport {
+    /// The name of the module from which the item is imported.
+    pub module: String,
+    /// The name of the item.
+    pub name: String,
+    /// The kind of the item.
+    pub kind: WasmExternalKind,
+}
+
+/// An item exported by a Wasm module.
+#[derive(Clone, PartialEq, Eq, Serialize, Debug)]
+pub struct WasmExport {
+    /// The name of the item.
+    pub name: String,
+    /// The kind of the item.
+    pub kind: WasmExternalKind,
+}
+
+/// A custom section of a Wasm module, e.g. one holding debug info.
+#[derive(Clone, PartialEq, Eq, Serialize, Debug)]
+pub struct WasmCustomSection {
+    /// The name of the section.
+    pub name: String,
+    /// The size of the section's contents in bytes.
+    pub size: usize,
+}
+
+/// The linear memory of a Wasm module.
+#[derive(Clone, PartialEq, Eq, Serialize, Debug)]
+pub struct WasmMemory {
+    /// The initial number of 64 KiB pages.
+    pub initial_pages: u32,
+    /// The maximum number of 64 KiB pages, if specified.
+    pub maximum_pages: Option<u32>,
+    /// Whether the memory is imported rather than defined by the module.
+    pub imported: bool,
+}
+
+/// A summary of the contents of a Wasm module.
+#[derive(Clone, PartialEq, Eq, Serialize, Debug)]
+pub struct WasmInspection {
+    /// The size of the module in bytes.
+    pub size: usize,
+    /// The items imported by the module.
+    pub imports: Vec<WasmImport>,
+    /// The items exported by the module.
+    pub exports: Vec<WasmExport>,
+    /// The custom sections of the module.
+    pub custom_sections: Vec<WasmCustomSection>,
+    /// The linear memory of the module, if any.
+    pub memory: Option<WasmMemory>,
+    /// Whether the module uses floating point types or instructions.
+    pub uses_floats: bool,
+    /// The opcodes of any instructions unknown to the client, e.g. from newer Wasm proposals.
+    ///
+    /// As the encoding of an unknown instruction can't be skipped, the rest of the function body
+    /// or global section in which it appears is not inspected.
+    pub unknown_instructions: Vec<String>,
+}
+
+impl WasmInspection {
+    /// Parses `module_bytes`, the contents of a Wasm file.
+    ///
+    /// Returns [`Error::InvalidWasm`] if the bytes are not a valid Wasm module.
+    pub fn new(module_bytes: &[u8]) -> Result<Self, Error> {
+        let mut inspection = WasmInspection {
+            size: module_bytes.len(),
+            imports: vec![],
+            exports: vec![],
+            custom_sections: vec![],
+            memory: None,
+            uses_floats: false,
+            unknown_instructions: vec![],
+        };
+        for section in sections(module_bytes)? {
+            let mut reader = Reader::new(section.contents);
+            match section.id {
+                CUSTOM_SECTION_ID => inspection.custom_sections.push(WasmCustomSection {
+                    name: reader.name()?,
+                    size: section.contents.len(),
+                }),
+                TYPE_SECTION_ID => inspection.uses_floats |= read_types(&mut reader)?,
+                IMPORT_SECTION_ID => read_imports(&mut reader, &mut inspection)?,
+                MEMORY_SECTION_ID => {
+                    for _ in 0..reader.u32()? {
+                        let (initial_pages, maximum_pages) = reader.limits()?;
+                        inspection.memory = Some(WasmMemory {
+                            initial_pages,
+                            maximum_pages,
+                            imported: false,
+                        });
+                    }
+                }
+                GLOBAL_SECTION_ID => read_globals(&mut reader, &mut inspection)?,
+                EXPORT_SECTION_ID => {
+                    for _ in 0..reader.u32()? {
+                        let name = reader.name()?;
+                        let kind = reader.external_kind()?;
+                        let _index = reader.u32()?;
+                        inspection.exports.push(WasmExport { name, kind });
+                    }
+                }
+                CODE_SECTION_ID => read_code(&mut reader, &mut inspection)?,
+                _ => (),
+            }
+        }
+        Ok(inspection)
+    }
+}
+
+/// Returns `Ok` if `module_bytes` is a Wasm module which would be accepted as the session or
+/// payment code of a deploy by a network with the given `limits`.
+///
+/// Returns [`Error::InvalidWasm`] if the bytes are not a valid Wasm module, or
+/// [`Error::DisallowedWasm`] if
+///   * the module is larger than `max_module_size`
+///   * the module doesn't export a function named "call"
+///   * the module imports anything other than the host functions from the "env" module
+///   * the module uses floating point types or instructions
+///   * the module's memory exceeds `max_memory_pages`
+///
+/// Instructions unknown to the client don't cause an error, as the network may support them, but
+/// a warning is logged since any floating point instructions after them are not detected.
+///
+/// When building a deploy for a network, this is checked by [`NetworkLimits::check`].
+pub fn validate_wasm(module_bytes: &[u8], limits: &WasmLimits) -> Result<(), Error> {
+    if module_bytes.len() > limits.max_module_size as usize {
+        return Err(Error::DisallowedWasm(format!(
+            "module size of {} bytes exceeds limit of {}",
+            module_bytes.len(),
+            limits.max_module_size
+        )));
+    }
+
+    let inspection = WasmInspection::new(module_bytes)?;
+    if !inspection
+        .exports
+        .iter()
+        .any(|export| export.name == CALL_EXPORT && export.kind == WasmExternalKind::Function)
+    {
+        return Err(Error::DisallowedWasm(format!(
+            "missing exported function '{}'",
+            CALL_EXPORT
+        )));
+    }
+    if let Some(import) = inspection
+        .imports
+        .iter()
+        .find(|import| import.module != HOST_MODULE)
+    {
+        return Err(Error::DisallowedWasm(format!(
+            "import of '{}' from module '{}'; only imports from '{}' are allowed",
+            import.name, import.module, HOST_MODULE
+        )));
+    }
+    if inspection.uses_floats {
+        return Err(Error::DisallowedWasm(
+            "floating point types and instructions are not allowed".to_string(),
+        ));
+    }
+    if !inspection.unknown_instructions.is_empty() {
+        tracing::warn!(
+            unknown_instructions = ?inspection.unknown_instructions,
+            "Wasm module only partially validated due to unknown instructions"
+        );
+    }
+    if let Some(memory) = inspection.memory {
+        let pages = memory
+            .initial_pages
+            .max(memory.maximum_pages.unwrap_or_default());
+        if pages > limits.max_memory_pages {
+            return Err(Error::DisallowedWasm(format!(
+                "memory of {} pages exceeds limit of {}",
+                pages, limits.max_memory_pages
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Returns a copy of the Wasm module `module_bytes` without its debug info, i.e. without the
+/// custom sections named "name", "sourceMappingURL", "external_debug_info" or starting with
+/// ".debug".
+pub fn strip_wasm_debug_sections(module_bytes: &[u8]) -> Result<Vec<u8>, Error> {
+    let sections = sections(module_bytes)?;
+    let mut stripped = module_bytes[..MAGIC.len() + VERSION.len()].to_vec();
+    for section in sections {
+        if section.id == CUSTOM_SECTION_ID {
+            let name = Reader::new(section.contents).name()?;
+            if name.starts_with(DEBUG_SECTION_PREFIX)
+                || DEBUG_SECTION_NAMES.contains(&name.as_str())
+            {
+                continue;
+            }
+        }
+        stripped.extend_from_slice(&module_bytes[section.range]);
+    }
+    Ok(stripped)
+}
+
+/// A section of a Wasm module.
+struct Section<'a> {
+    id: u8,
+    contents: &'a [u8],
+    /// The range of the whole section, including its ID and size, within the module.
+    range: Range<usize>,
+}
+
+/// Checks the header of `module_bytes` and splits the rest into sections.
+fn sections(module_bytes: &[u8]) -> Result<Vec<Section<'_>>, Error> {
+    let mut reader = Reader::new(module_bytes);
+    if reader.bytes(MAGIC.len()).ok() != Some(MAGIC) {
+        return Err(Error::InvalidWasm("missing magic number".to_string()));
+    }
+    if reader.bytes(VERSION.len())? != VERSION {
+        return Err(Error::InvalidWasm("unsupported version".to_string()));
+    }
+
+    let mut sections = vec![];
+    while !reader.is_empty() {
+        let start = reader.position;
+        let id = reader.byte()?;
+        let size = reader.u32()?;
+        let contents = reader.bytes(size as usize)?;
+        sections.push(Section {
+            id,
+            contents,
+            range: start..reader.position,
+        });
+    }
+    Ok(sections)
+}
+
+/// Reads the type section, returning whether any function type has a floating point parameter or
+/// result.
+fn read_types(reader: &mut Reader) -> Result<bool, Error> {
+    let mut uses_floats = false;
+    for _ in 0..reader.u32()? {
+        if reader.byte()? != FUNCTION_TYPE {
+            return Err(Error::InvalidWasm("malformed function type".to_string()));
+        }
+        // The parameters, then the results.
+        for _ in 0..2 {
+            for _ in 0..reader.u32()? {
+                uses_floats |= is_float_type(reader.byte()?);
+            }
+        }
+    }
+    Ok(uses_floats)
+}
+
+fn read_imports(reader: &mut Reader, inspection: &mut WasmInspection) -> Result<(), Error> {
+    for _ in 0..reader.u32()? {
+        let module = reader.name()?;
+        let name = reader.name()?;
+        let kind = reader.external_kind()?;
+        match kind {
+            WasmExternalKind::Function => {
+                let _type_index = reader.u32()?;
+            }
+            WasmExternalKind::Table => {
+                let _reference_type = reader.byte()?;
+                let _limits = reader.limits()?;
+            }
+            WasmExternalKind::Memory => {
+                let (initial_pages, maximum_pages) = reader.limits()?;
+                inspection.memory = Some(WasmMemory {
+                    initial_pages,
+                    maximum_pages,
+                    imported: true,
+                });
+            }
+            WasmExternalKind::Global => {
+                inspection.uses_floats |= is_float_type(reader.byte()?);
+                let _mutability = reader.byte()?;
+            }
+        }
+        inspection.imports.push(WasmImport { module, name, kind });
+    }
+    Ok(())
+}
+
+/// Reads the global section, recording whether any global has a floating point type or
+/// initializer.
+fn read_globals(reader: &mut Reader, inspection: &mut WasmInspection) -> Result<(), Error> {
+    for _ in 0..reader.u32()? {
+        inspection.uses_floats |= is_float_type(reader.byte()?);
+        let _mutability = reader.byte()?;
+        loop {
+            let opcode = reader.byte()?;
+            if opcode == END_OPCODE {
+                break;
+            }
+            match read_instruction(opcode, reader)? {
+                Instruction::Known { uses_floats } => inspection.uses_floats |= uses_floats,
+                // The globals are not length-prefixed, so the rest of the section is skipped.
+                Instruction::Unknown(name) => {
+                    inspection.unknown_instructions.push(name);
+                    return Ok(());
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Reads the code section, recording whether any function has a floating point local or
+/// instruction.
+fn read_code(reader: &mut Reader, inspection: &mut WasmInspection) -> Result<(), Error> {
+    for _ in 0..reader.u32()? {
+        let size = reader.u32()?;
+        let mut body = Reader::new(reader.bytes(size as usize)?);
+        for _ in 0..body.u32()? {
+            let _count = body.u32()?;
+            inspection.uses_floats |= is_float_type(body.byte()?);
+        }
+        while !body.is_empty() {
+            let opcode = body.byte()?;
+            match read_instruction(opcode, &mut body)? {
+                Instruction::Known { uses_floats } => inspection.uses_floats |= uses_floats,
+                // The rest of the body is skipped, continuing with the next function.
+                Instruction::Unknown(name) => {
+                    inspection.unknown_instructions.push(name);
+                    break;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// An instruction read by [`read_instruction`].
+enum Instruction {
+    /// A known instruction, whose immediates have been read.
+    Known { uses_floats: bool },
+    /// An unknown instruction, with its opcode formatted as hex, whose immediates can't be read.
+    Unknown(String),
+}
+
+/// Reads the immediates of the instruction with the given `opcode`.
+fn read_instruction(opcode: u8, reader: &mut Reader) -> Result<Instruction, Error> {
+    match opcode {
+        // block, loop, if: a block type, which is either empty, a value type or a type index.
+        0x02..=0x04 => {
+            let block_type = reader.byte()?;
+            if block_type != EMPTY_BLOCK_TYPE && block_type & 0x80 != 0 {
+                reader.skip_leb128(4)?;
+            }
+            return Ok(Instruction::Known {
+                uses_floats: is_float_type(block_type),
+            });
+        }
+        // br, br_if, call, local.*, global.*, table.get, table.set, ref.func: an index.
+        0x0c | 0x0d | 0x10 | 0x20..=0x26 | 0xd2 => {
+            let _index = reader.u32()?;
+        }
+        // br_table: a vector of labels and a default label.
+        0x0e => {
+            for _ in 0..reader.u32()? {
+                let _label = reader.u32()?;
+            }
+            let _default_label = reader.u32()?;
+        }
+        // call_indirect: a type index and a table index.
+        0x11 => {
+            let _type_index = reader.u32()?;
+            let _table_index = reader.u32()?;
+        }
+        // select with types.
+        0x1c => {
+            let mut uses_floats = false;
+            for _ in 0..reader.u32()? {
+                uses_floats |= is_float_type(reader.byte()?);
+            }
+            return Ok(Instruction::Known { uses_floats });
+        }
+        // Loads and stores: an alignment and an offset.
+        0x28..=0x3e => {
+            let _align = reader.u32()?;
+            let _offset = reader.u32()?;
+        }
+        // memory.size, memory.grow: a reserved byte.
+        0x3f | 0x40 => {
+            let _reserved = reader.byte()?;
+        }
+        // i32.const, i64.const: a signed integer.
+        0x41 => reader.skip_leb128(5)?,
+        0x42 => reader.skip_leb128(10)?,
+        // f32.const, f64.const: a float.
+        0x43 => {
+            let _value = reader.bytes(4)?;
+        }
+        0x44 => {
+            let _value = reader.bytes(8)?;
+        }
+        // ref.null: a reference type.
+        0xd0 => {
+            let _reference_type = reader.byte()?;
+        }
+        // Saturating truncations and bulk memory operations.
+        0xfc => {
+            let uses_floats = match reader.u32()? {
+                0..=7 => true,
+                8 => {
+                    let _data_index = reader.u32()?;
+                    let _reserved = reader.byte()?;
+                    false
+                }
+                9 | 13 | 15..=17 => {
+                    let _index = reader.u32()?;
+                    false
+                }
+                10 => {
+                    let _reserved = reader.bytes(2)?;
+                    false
+                }
+                11 => {
+                    let _reserved = reader.byte()?;
+                    false
+                }
+                12 | 14 => {
+                    let _index = reader.u32()?;
+                    let _other_index = reader.u32()?;
+                    false
+                }
+                sub_opcode => {
+                    return Ok(Instruction::Unknown(format!(
+                        "{:#04x} {}",
+                        opcode, sub_opcode
+                    )))
+                }
+            };
+            return Ok(Instruction::Known { uses_floats });
+        }
+        // Instructions without immediates.
+        0x00 | 0x01 | 0x05 | 0x0b | 0x0f | 0x1a | 0x1b | 0x45..=0xc4 | 0xd1 => (),
+        _ => return Ok(Instruction::Unknown(format!("{:#04x}", opcode))),
+    }
+    Ok(Instruction::Known {
+        uses_floats: is_float_opcode(opcode),
+    })
+}
+
+fn is_float_type(value_type: u8) -> bool {
+    value_type == F32_TYPE || value_type == F64_TYPE
+}
+
+/// Returns whether the instruction with the given `opcode` operates on floats.
+fn is_float_opcode(opcode: u8) -> bool {
+    matches!(
+        opcode,
+        // Loads, stores and constants.
+        0x2a | 0x2b | 0x38 | 0x39 | 0x43 | 0x44
+        // Comparisons and arithmetic.
+        | 0x5b..=0x66 | 0x8b..=0xa6
+        // Conversions to, from and between floats, other than integer wraps and extensions.
+        | 0xa8..=0xab | 0xae..=0xbf
+    )
+}
+
+/// A reader of the values encoded in a Wasm module.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Reader { bytes, position: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.position >= self.bytes.len()
+    }
+
+    fn bytes(&mut self, count: usize) -> Result<&'a [u8], Error> {
+        let end = self
+            .position
+            .checked_add(count)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or_else(|| Error::InvalidWasm("unexpected end of module".to_string()))?;
+        let bytes = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(bytes)
+    }
+
+    fn byte(&mut self) -> Result<u8, Error> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    /// Reads an unsigned LEB128-encoded `u32`.
+    fn u32(&mut self) -> Result<u32, Error> {
+        let mut value = 0_u64;
+        for shift in (0..35).step_by(7) {
+            let byte = self.byte()?;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return u32::try_from(value)
+                    .map_err(|_| Error::InvalidWasm("integer too large".to_string()));
+            }
+        }
+        Err(Error::InvalidWasm("integer too long".to_string()))
+    }
+
+    /// Skips a LEB128-encoded integer of at most `max_length` bytes.
+    fn skip_leb128(&mut self, max_length: usize) -> Result<(), Error> {
+        for _ in 0..max_length {
+            if self.byte()? & 0x80 == 0 {
+                return Ok(());
+            }
+        }
+        Err(Error::InvalidWasm("integer too long".to_string()))
+    }
+
+    fn name(&mut self) -> Result<String, Error> {
+        let length = self.u32()?;
+        let bytes = self.bytes(length as usize)?;
+        String::from_utf8(bytes.to_vec())
+            .map_err(|_| Error::InvalidWasm("name is not valid UTF-8".to_string()))
+    }
+
+    fn external_kind(&mut self) -> Result<WasmExternalKind, Error> {
+        match self.byte()? {
+            0 => Ok(WasmExternalKind::Function),
+            1 => Ok(WasmExternalKind::Table),
+            2 => Ok(WasmExternalKind::Memory),
+            3 => Ok(WasmExternalKind::Global),
+            kind => Err(Error::InvalidWasm(format!(
+                "unknown import or export kind {}",
+                kind
+            ))),
+        }
+    }
+
+    /// Reads the initial and optional maximum sizes of a memory or table.
+    fn limits(&mut self) -> Result<(u32, Option<u32>), Error> {
+        match self.byte()? {
+            0 => Ok((self.u32()?, None)),
+            1 => {
+                let initial = self.u32()?;
+                Ok((initial, Some(self.u32()?)))
+            }
+            _ => Err(Error::InvalidWasm(
+                "unsupported memory or table limits".to_string(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn section(id: u8, contents: &[u8]) -> Vec<u8> {
+        let mut section = vec![id, contents.len() as u8];
+        section.extend_from_slice(contents);
+        section
+    }
+
+    fn name(name: &str) -> Vec<u8> {
+        let mut bytes = vec![name.len() as u8];
+        bytes.extend_from_slice(name.as_bytes());
+        bytes
+    }
+
+    /// Returns a module importing `casper_revert` from `import_module`, with a memory of
+    /// `memory_pages`, the given custom section, and a function exported as `export_name` whose
+    /// body is `instructions`.
+    fn module(
+        import_module: &str,
+        memory_pages: u8,
+        custom_section: &str,
+        export_name: &str,
+        instructions: &[u8],
+    ) -> Vec<u8> {
+        let mut module = [MAGIC, VERSION].concat();
+        module.extend(section(TYPE_SECTION_ID, &[1, FUNCTION_TYPE, 0, 0]));
+
+        let mut imports = vec![1];
+        imports.extend(name(import_module));
+        imports.extend(name("casper_revert"));
+        imports.extend([0, 0]);
+        module.extend(section(IMPORT_SECTION_ID, &imports));
+
+        module.extend(section(3, &[1, 0]));
+        module.extend(section(MEMORY_SECTION_ID, &[1, 0, memory_pages]));
+
+        let mut exports = vec![1];
+        exports.extend(name(export_name));
+        exports.extend([0, 1]);
+        module.extend(section(EXPORT_SECTION_ID, &exports));
+
+        let mut body = vec![0];
+        body.extend_from_slice(instructions);
+        body.push(END_OPCODE);
+        let mut code = vec![1, body.len() as u8];
+        code.extend(body);
+        module.extend(section(CODE_SECTION_ID, &code));
+
+        let mut custom = name(custom_section);
+        custom.extend([1, 2, 3]);
+        module.extend(section(CUSTOM_SECTION_ID, &custom));
+        module
+    }
+
+    fn valid_module() -> Vec<u8> {
+        // i32.const 1, drop
+        module("env", 16, "name", "call", &[0x41, 1, 0x1a])
+    }
+
+    #[test]
+    fn should_inspect_wasm() {
+        let inspection = WasmInspection::new(&valid_module()).unwrap();
+        assert_eq!(
+            inspection.imports,
+            vec![WasmImport {
+                module: "env".to_string(),
+                name: "casper_revert".to_string(),
+                kind: WasmExternalKind::Function,
+            }]
+        );
+        assert_eq!(
+            inspection.exports,
+            vec![WasmExport {
+                name: "call".to_string(),
+                kind: WasmExternalKind::Function,
+            }]
+        );
+        assert_eq!(
+            inspection.custom_sections,
+            vec![WasmCustomSection {
+                name: "name".to_string(),
+                size: 8,
+            }]
+        );
+        assert_eq!(
+            inspection.memory,
+            Some(WasmMemory {
+                initial_pages: 16,
+                maximum_pages: None,
+                imported: false,
+            })
+        );
+        assert!(!inspection.uses_floats);
+        assert!(inspection.unknown_instructions.is_empty());
+    }
+
+    #[test]
+    fn should_skip_unknown_instructions() {
+        // A SIMD instruction, then i32.const 1, drop, which are skipped.
+        let module_bytes = module("env", 16, "name", "call", &[0xfd, 0x0c, 0x41, 1, 0x1a]);
+        let inspection = WasmInspection::new(&module_bytes).unwrap();
+        assert_eq!(inspection.unknown_instructions, vec!["0xfd".to_string()]);
+        validate_wasm(&module_bytes, &WasmLimits::default()).unwrap();
+
+        let module_bytes = module("env", 16, "name", "call", &[0xfc, 18, 0, 0x1a]);
+        let inspection = WasmInspection::new(&module_bytes).unwrap();
+        assert_eq!(inspection.unknown_instructions, vec!["0xfc 18".to_string()]);
+    }
+
+    #[test]
+    fn should_validate_wasm() {
+        let limits = WasmLimits::default();
+        validate_wasm(&valid_module(), &limits).unwrap();
+
+        let mut bad_magic = valid_module();
+        bad_magic[1] = b'x';
+        assert!(matches!(
+            validate_wasm(&bad_magic, &limits),
+            Err(Error::InvalidWasm(_))
+        ));
+        assert!(matches!(
+            validate_wasm(&valid_module()[..20], &limits),
+            Err(Error::InvalidWasm(_))
+        ));
+
+        let disallowed_modules = [
+            module("env", 16, "name", "main", &[]),
+            module("wasi_snapshot_preview1", 16, "name", "call", &[]),
+            module("env", 65, "name", "call", &[]),
+            // f32.const 0, drop
+            module("env", 16, "name", "call", &[0x43, 0, 0, 0, 0, 0x1a]),
+        ];
+        for module in &disallowed_modules {
+            assert!(matches!(
+                validate_wasm(module, &limits),
+                Err(Error::DisallowedWasm(_))
+            ));
+        }
+
+        let limits = WasmLimits {
+            max_module_size: 10,
+            ..limits
+        };
+        assert!(matches!(
+            validate_wasm(&valid_module(), &limits),
+            Err(Error::DisallowedWasm(_))
+        ));
+    }
+
+    #[test]
+    fn should_strip_debug_sections() {
+        let module_bytes = valid_module();
+        let stripped = strip_wasm_debug_sections(&module_bytes).unwrap();
+        assert_eq!(stripped.len(), module_bytes.len() - 10);
+        assert!(WasmInspection::new(&stripped)
+            .unwrap()
+            .custom_sections
+            .is_empty());
+        validate_wasm(&stripped, &WasmLimits::default()).unwrap();
+
+        let module_bytes = module("env", 16, "producers", "call", &[]);
+        assert_eq!(
+            strip_wasm_debug_sections(&module_bytes).unwrap(),
+            module_bytes
+        );
+    }
+}
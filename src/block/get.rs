@@ -41,10 +41,17 @@ impl ClientCommand for GetBlock {
         let maybe_rpc_id = common::rpc_id::get(matches);
         let node_address = common::node_address::get(matches);
         let verbosity_level = common::verbose::get(matches);
+        let transport = common::transport::get(matches)?;
         let maybe_block_id = common::block_identifier::get(matches);
 
-        casper_client::cli::get_block(maybe_rpc_id, node_address, verbosity_level, maybe_block_id)
-            .await
-            .map(Success::from)
+        casper_client::cli::get_block_with_transport(
+            transport.as_ref(),
+            maybe_rpc_id,
+            node_address,
+            verbosity_level,
+            maybe_block_id,
+        )
+        .await
+        .map(Success::from)
     }
 }
@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use clap::{Arg, ArgMatches, Command};
+
+use casper_client::cli::CliError;
+
+use crate::{command::ClientCommand, common, Success};
+
+pub struct ChainspecDiff;
+
+/// This enum defines the order in which the args are shown for this subcommand's help message.
+enum DisplayOrder {
+    Verbose,
+    NodeAddress,
+    OtherNodeAddress,
+    RpcId,
+}
+
+/// Handles providing the arg for and retrieval of the address of the node whose chainspec is
+/// compared.
+mod other_node_address {
+    use super::*;
+
+    const ARG_NAME: &str = "other-node-address";
+    const ARG_VALUE_NAME: &str = "HOST:PORT";
+    const ARG_HELP: &str =
+        "Hostname or IP and port of the node whose chainspec is compared against that of the node \
+        at --node-address";
+
+    pub(super) fn arg() -> Arg {
+        Arg::new(ARG_NAME)
+            .long(ARG_NAME)
+            .required(true)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::OtherNodeAddress as usize)
+    }
+
+    pub(super) fn get(matches: &ArgMatches) -> &str {
+        matches
+            .get_one::<String>(ARG_NAME)
+            .map(String::as_str)
+            .unwrap_or_else(|| panic!("should have {} arg", ARG_NAME))
+    }
+}
+
+#[async_trait]
+impl ClientCommand for ChainspecDiff {
+    const NAME: &'static str = "chainspec-diff";
+    const ABOUT: &'static str =
+        "Retrieve the chainspecs of two nodes and list the settings which differ between them";
+
+    fn build(display_order: usize) -> Command {
+        Command::new(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(common::verbose::arg(DisplayOrder::Verbose as usize))
+            .arg(common::node_address::arg(
+                DisplayOrder::NodeAddress as usize,
+            ))
+            .arg(other_node_address::arg())
+            .arg(common::rpc_id::arg(DisplayOrder::RpcId as usize))
+    }
+
+    async fn run(matches: &ArgMatches) -> Result<Success, CliError> {
+        if common::node_address::get_all(matches).len() > 1 {
+            return Err(CliError::InvalidArgument {
+                context: "chainspec-diff node-address",
+                error: "a single node address is required, as each request must be sent to a \
+                    specific node"
+                    .to_string(),
+            });
+        }
+        let maybe_rpc_id = common::rpc_id::get(matches);
+        let node_address = common::node_address::get(matches);
+        let other_node_address = other_node_address::get(matches);
+        let verbosity_level = common::verbose::get(matches);
+        let transport = common::transport::get(matches)?;
+
+        casper_client::cli::diff_chainspecs_with_transport(
+            transport.as_ref(),
+            maybe_rpc_id,
+            node_address,
+            other_node_address,
+            verbosity_level,
+        )
+        .await
+        .map(Success::from)
+    }
+}
@@ -11,6 +11,8 @@ pub enum Success {
     Response(Value),
     /// The output which should be presented to the user for non-RPC client commands.
     Output(String),
+    /// The command has already presented all its output to the user.
+    Done,
 }
 
 impl<T: Serialize> From<T> for Success {
@@ -46,13 +46,17 @@ pub mod node_address {
     const ARG_SHORT: char = 'n';
     const ARG_VALUE_NAME: &str = "HOST:PORT";
     const ARG_DEFAULT: &str = "http://localhost:7777";
-    const ARG_HELP: &str = "Hostname or IP and port of node on which HTTP service is running";
+    const ARG_HELP: &str =
+        "Hostname or IP and port of node on which HTTP service is running. May be repeated to \
+        spread requests across a pool of nodes, in which case each request is sent to a healthy, \
+        caught-up node from the pool, failing over to the next node if sending fails";
 
     pub fn arg(order: usize) -> Arg {
         Arg::new(ARG_NAME)
             .long(ARG_NAME)
             .short(ARG_SHORT)
             .required(false)
+            .action(ArgAction::Append)
             .default_value(ARG_DEFAULT)
             .value_name(ARG_VALUE_NAME)
             .help(ARG_HELP)
@@ -65,6 +69,15 @@ pub mod node_address {
             .map(String::as_str)
             .unwrap_or_else(|| panic!("should have {} arg", ARG_NAME))
     }
+
+    /// Returns all the given node addresses, in the order in which they were given.
+    pub fn get_all(matches: &ArgMatches) -> Vec<&str> {
+        matches
+            .get_many::<String>(ARG_NAME)
+            .unwrap_or_else(|| panic!("should have {} arg", ARG_NAME))
+            .map(String::as_str)
+            .collect()
+    }
 }
 
 /// Handles providing the arg for the RPC ID.
@@ -101,7 +114,11 @@ pub mod secret_key {
     pub(crate) const ARG_NAME: &str = "secret-key";
     const ARG_SHORT: char = 'k';
     const ARG_VALUE_NAME: &str = ARG_PATH;
-    const ARG_HELP: &str = "Path to secret key file";
+    const ARG_HELP: &str =
+        "Path to secret key file, either PEM-encoded or a password-encrypted keystore. The \
+        password of a keystore is read from the environment variable \
+        CASPER_CLIENT_KEYSTORE_PASSWORD if set, otherwise from the file at the path given by \
+        CASPER_CLIENT_KEYSTORE_PASSWORD_FILE if set, otherwise it is prompted for";
 
     pub fn arg(order: usize, extended_help: &str) -> Arg {
         Arg::new(ARG_NAME)
@@ -117,6 +134,38 @@ pub mod secret_key {
     }
 }
 
+/// Handles providing the arg for and retrieval of the command of an external signer.
+pub mod signer_command {
+    use super::*;
+
+    pub(crate) const ARG_NAME: &str = "signer-command";
+    const ARG_VALUE_NAME: &str = "COMMAND";
+    const ARG_HELP: &str =
+        "Command of an external process used to sign the deploy instead of a secret key, for \
+        example a client of a signing service. The command is split into words as by a POSIX \
+        shell, so quotes can be used for a path or argument containing spaces. The process is \
+        given the deploy hash on its stdin as JSON of the form \
+        {\"deploy_hash\":\"<HEX STRING>\"}, and must output JSON of the form \
+        {\"public_key\":\"<HEX STRING>\",\"signature\":\"<HEX STRING>\"} on its stdout";
+
+    pub fn arg(order: usize) -> Arg {
+        Arg::new(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .conflicts_with(secret_key::ARG_NAME)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(order)
+    }
+
+    pub fn get(matches: &ArgMatches) -> &str {
+        matches
+            .get_one::<String>(ARG_NAME)
+            .map(String::as_str)
+            .unwrap_or_default()
+    }
+}
+
 /// Handles the arg for whether to overwrite existing output file(s).
 pub mod force {
     use super::*;
@@ -374,3 +423,246 @@ pub(super) mod purse_uref {
         matches.get_one::<String>(ARG_NAME).map(String::as_str)
     }
 }
+
+/// Handles providing the global args for and construction of the transport used to send JSON-RPC
+/// requests to the node.
+pub mod transport {
+    use std::time::Duration;
+
+    use casper_client::{
+        Error, HttpConfig, HttpTransport, NodePool, RecordingTransport, ReplayTransport,
+        RetryPolicy, RetryingTransport, Transport,
+    };
+
+    use super::*;
+
+    const REQUEST_TIMEOUT_ARG_NAME: &str = "request-timeout";
+    const REQUEST_TIMEOUT_ARG_HELP: &str =
+        "Maximum duration of each request to the node, e.g. '30s' or '2min'. If not given, \
+        requests do not time out";
+
+    const CONNECT_TIMEOUT_ARG_NAME: &str = "connect-timeout";
+    const CONNECT_TIMEOUT_ARG_HELP: &str =
+        "Maximum duration allowed for connecting to the node, e.g. '5s'. If not given, \
+        connecting does not time out";
+
+    const TIMEOUT_ARG_VALUE_NAME: &str = "DURATION";
+
+    const HEADER_ARG_NAME: &str = "header";
+    const HEADER_ARG_VALUE_NAME: &str = "NAME:VALUE";
+    const HEADER_ARG_HELP: &str =
+        "Extra HTTP header to send with each request, e.g. 'Authorization: Bearer <TOKEN>'. May \
+        be repeated to send several headers";
+
+    const PROXY_ARG_NAME: &str = "proxy";
+    const PROXY_ARG_VALUE_NAME: &str = "URL";
+    const PROXY_ARG_HELP: &str = "URL of a proxy through which to send all requests to the node";
+
+    const CA_CERT_ARG_NAME: &str = "ca-cert";
+    const CA_CERT_ARG_HELP: &str =
+        "Path to a file of one or more PEM-encoded CA certificates to trust in addition to the \
+        built-in root certificates";
+
+    const INSECURE_ARG_NAME: &str = "insecure";
+    const INSECURE_ARG_HELP: &str =
+        "Disables verification of the node's TLS certificate. This should only be used against \
+        development networks";
+
+    const RETRIES_ARG_NAME: &str = "retries";
+    const RETRIES_ARG_HELP: &str =
+        "Maximum number of times to retry a request which fails due to a transient error, e.g. a \
+        connection failure or an HTTP 502 response. Only read-only requests are retried unless \
+        --retry-put-deploy is also passed";
+
+    const RETRY_BACKOFF_ARG_NAME: &str = "retry-backoff";
+    const RETRY_BACKOFF_ARG_HELP: &str =
+        "Delay before the first retry, e.g. '500ms'. The delay doubles for each subsequent retry, \
+        and is randomly reduced by up to half";
+
+    const RETRY_PUT_DEPLOY_ARG_NAME: &str = "retry-put-deploy";
+    const RETRY_PUT_DEPLOY_ARG_HELP: &str =
+        "Also retry sending a deploy. This is safe since the retried request carries the same \
+        deploy hash, and so cannot cause the deploy to be executed twice";
+
+    const RECORD_ARG_NAME: &str = "record";
+    const RECORD_ARG_HELP: &str =
+        "Path to a cassette file to which every request sent to the node and the node's response \
+        will be recorded, for later use with --replay. Any existing file will be overwritten";
+
+    const REPLAY_ARG_NAME: &str = "replay";
+    const REPLAY_ARG_HELP: &str =
+        "Path to a cassette file previously recorded via --record. Requests will be answered with \
+        the matching recorded responses rather than being sent to the node";
+
+    /// Returns the args for configuring the transport, all of which are global, i.e. usable with
+    /// every subcommand.
+    pub fn args() -> Vec<Arg> {
+        vec![
+            Arg::new(REQUEST_TIMEOUT_ARG_NAME)
+                .long(REQUEST_TIMEOUT_ARG_NAME)
+                .required(false)
+                .global(true)
+                .value_name(TIMEOUT_ARG_VALUE_NAME)
+                .help(REQUEST_TIMEOUT_ARG_HELP),
+            Arg::new(CONNECT_TIMEOUT_ARG_NAME)
+                .long(CONNECT_TIMEOUT_ARG_NAME)
+                .required(false)
+                .global(true)
+                .value_name(TIMEOUT_ARG_VALUE_NAME)
+                .help(CONNECT_TIMEOUT_ARG_HELP),
+            Arg::new(HEADER_ARG_NAME)
+                .long(HEADER_ARG_NAME)
+                .required(false)
+                .global(true)
+                .action(ArgAction::Append)
+                .value_name(HEADER_ARG_VALUE_NAME)
+                .help(HEADER_ARG_HELP),
+            Arg::new(PROXY_ARG_NAME)
+                .long(PROXY_ARG_NAME)
+                .required(false)
+                .global(true)
+                .value_name(PROXY_ARG_VALUE_NAME)
+                .help(PROXY_ARG_HELP),
+            Arg::new(CA_CERT_ARG_NAME)
+                .long(CA_CERT_ARG_NAME)
+                .required(false)
+                .global(true)
+                .value_name(ARG_PATH)
+                .help(CA_CERT_ARG_HELP),
+            Arg::new(INSECURE_ARG_NAME)
+                .long(INSECURE_ARG_NAME)
+                .required(false)
+                .global(true)
+                .action(ArgAction::SetTrue)
+                .help(INSECURE_ARG_HELP),
+            Arg::new(RETRIES_ARG_NAME)
+                .long(RETRIES_ARG_NAME)
+                .required(false)
+                .global(true)
+                .value_name(ARG_INTEGER)
+                .value_parser(clap::value_parser!(u32))
+                .help(RETRIES_ARG_HELP),
+            Arg::new(RETRY_BACKOFF_ARG_NAME)
+                .long(RETRY_BACKOFF_ARG_NAME)
+                .required(false)
+                .global(true)
+                .value_name(TIMEOUT_ARG_VALUE_NAME)
+                .help(RETRY_BACKOFF_ARG_HELP),
+            Arg::new(RETRY_PUT_DEPLOY_ARG_NAME)
+                .long(RETRY_PUT_DEPLOY_ARG_NAME)
+                .required(false)
+                .global(true)
+                .action(ArgAction::SetTrue)
+                .help(RETRY_PUT_DEPLOY_ARG_HELP),
+            Arg::new(RECORD_ARG_NAME)
+                .long(RECORD_ARG_NAME)
+                .required(false)
+                .global(true)
+                .value_name(ARG_PATH)
+                .conflicts_with(REPLAY_ARG_NAME)
+                .help(RECORD_ARG_HELP),
+            Arg::new(REPLAY_ARG_NAME)
+                .long(REPLAY_ARG_NAME)
+                .required(false)
+                .global(true)
+                .value_name(ARG_PATH)
+                .help(REPLAY_ARG_HELP),
+        ]
+    }
+
+    /// Returns the transport configured by the global args.
+    ///
+    /// If more than one node address was given, requests are sent via a pool of those nodes.  If
+    /// a cassette to replay was given, no requests are sent at all.
+    pub fn get(matches: &ArgMatches) -> Result<Box<dyn Transport>, CliError> {
+        if let Some(path) = matches.get_one::<String>(REPLAY_ARG_NAME) {
+            return Ok(Box::new(ReplayTransport::from_file(path)?));
+        }
+
+        let config = http_config(matches)?;
+        let http_transport = HttpTransport::with_config(&config)?;
+        let node_addresses = node_address::get_all(matches);
+        let mut transport: Box<dyn Transport> = if node_addresses.len() > 1 {
+            Box::new(NodePool::new(node_addresses, http_transport)?)
+        } else {
+            Box::new(http_transport)
+        };
+        if let Some(path) = matches.get_one::<String>(RECORD_ARG_NAME) {
+            transport = Box::new(RecordingTransport::new(transport, path));
+        }
+        match retry_policy(matches)? {
+            Some(policy) => Ok(Box::new(RetryingTransport::new(transport, policy))),
+            None => Ok(transport),
+        }
+    }
+
+    fn retry_policy(matches: &ArgMatches) -> Result<Option<RetryPolicy>, CliError> {
+        let max_retries = matches
+            .get_one::<u32>(RETRIES_ARG_NAME)
+            .copied()
+            .unwrap_or_default();
+        if max_retries == 0 {
+            return Ok(None);
+        }
+
+        let mut policy = RetryPolicy::new().with_max_retries(max_retries);
+        if let Some(value) = matches.get_one::<String>(RETRY_BACKOFF_ARG_NAME) {
+            policy = policy.with_initial_backoff(duration(value, RETRY_BACKOFF_ARG_NAME)?);
+        }
+        let retry_put_deploy = matches
+            .get_one::<bool>(RETRY_PUT_DEPLOY_ARG_NAME)
+            .copied()
+            .unwrap_or_default();
+        Ok(Some(policy.with_put_deploy_retries(retry_put_deploy)))
+    }
+
+    /// Returns the HTTP settings configured by the global args.
+    pub fn http_config(matches: &ArgMatches) -> Result<HttpConfig, CliError> {
+        let mut config = HttpConfig::new();
+
+        if let Some(value) = matches.get_one::<String>(REQUEST_TIMEOUT_ARG_NAME) {
+            config = config.with_request_timeout(duration(value, REQUEST_TIMEOUT_ARG_NAME)?);
+        }
+
+        if let Some(value) = matches.get_one::<String>(CONNECT_TIMEOUT_ARG_NAME) {
+            config = config.with_connect_timeout(duration(value, CONNECT_TIMEOUT_ARG_NAME)?);
+        }
+
+        for header in matches
+            .get_many::<String>(HEADER_ARG_NAME)
+            .unwrap_or_default()
+        {
+            let (name, value) =
+                header
+                    .split_once(':')
+                    .ok_or_else(|| CliError::InvalidArgument {
+                        context: HEADER_ARG_NAME,
+                        error: format!("'{}' should be of the form NAME:VALUE", header),
+                    })?;
+            config = config.with_header(name.trim(), value.trim());
+        }
+
+        if let Some(proxy_url) = matches.get_one::<String>(PROXY_ARG_NAME) {
+            config = config.with_proxy(proxy_url.as_str());
+        }
+
+        if let Some(path) = matches.get_one::<String>(CA_CERT_ARG_NAME) {
+            let ca_bundle_pem = fs::read(path).map_err(|error| Error::IoError {
+                context: format!("unable to read CA certificate file at '{}'", path),
+                error,
+            })?;
+            config = config.with_ca_bundle_pem(ca_bundle_pem);
+        }
+
+        let accept_invalid_certs = matches
+            .get_one::<bool>(INSECURE_ARG_NAME)
+            .copied()
+            .unwrap_or_default();
+        Ok(config.with_accept_invalid_certs(accept_invalid_certs))
+    }
+
+    fn duration(value: &str, context: &'static str) -> Result<Duration, CliError> {
+        humantime::parse_duration(value)
+            .map_err(|error| CliError::FailedToParseTimeDiff { context, error })
+    }
+}
@@ -1,18 +1,28 @@
+mod check_approvals;
 mod creation_common;
 mod get;
+mod inspect;
 mod list;
 mod make;
 mod make_transfer;
+mod merge;
 mod put;
+mod restamp;
 mod send;
 mod sign;
 mod transfer;
+mod verify;
 
+pub use check_approvals::CheckApprovals;
 pub use get::GetDeploy;
+pub use inspect::InspectDeploy;
 pub use list::ListDeploys;
 pub use make::MakeDeploy;
 pub use make_transfer::MakeTransfer;
+pub use merge::MergeDeploys;
 pub use put::PutDeploy;
+pub use restamp::RestampDeploy;
 pub use send::SendDeploy;
 pub use sign::SignDeploy;
 pub use transfer::Transfer;
+pub use verify::VerifyDeploy;
@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use clap::{ArgMatches, Command};
+
+use casper_client::cli::CliError;
+
+use super::creation_common;
+use crate::{command::ClientCommand, common, Success};
+
+pub struct CheckApprovals;
+
+/// This enum defines the order in which the args are shown for this subcommand's help message.
+enum DisplayOrder {
+    Verbose,
+    NodeAddress,
+    RpcId,
+    BlockIdentifier,
+    Input,
+}
+
+#[async_trait]
+impl ClientCommand for CheckApprovals {
+    const NAME: &'static str = "check-approvals";
+    const ABOUT: &'static str =
+        "Read a previously-saved deploy from a file and check whether its approvals meet the \
+        deployment threshold of its account, as retrieved from the network";
+
+    fn build(display_order: usize) -> Command {
+        Command::new(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(common::verbose::arg(DisplayOrder::Verbose as usize))
+            .arg(common::node_address::arg(
+                DisplayOrder::NodeAddress as usize,
+            ))
+            .arg(common::rpc_id::arg(DisplayOrder::RpcId as usize))
+            .arg(common::block_identifier::arg(
+                DisplayOrder::BlockIdentifier as usize,
+                true,
+            ))
+            .arg(creation_common::input::arg().display_order(DisplayOrder::Input as usize))
+    }
+
+    async fn run(matches: &ArgMatches) -> Result<Success, CliError> {
+        let maybe_rpc_id = common::rpc_id::get(matches);
+        let node_address = common::node_address::get(matches);
+        let verbosity_level = common::verbose::get(matches);
+        let transport = common::transport::get(matches)?;
+        let block_identifier = common::block_identifier::get(matches);
+        let input_path = creation_common::input::get(matches);
+
+        casper_client::cli::check_approvals_with_transport(
+            transport.as_ref(),
+            maybe_rpc_id,
+            node_address,
+            verbosity_level,
+            block_identifier,
+            input_path,
+        )
+        .await
+        .map(Success::from)
+    }
+}
@@ -1,13 +1,20 @@
 //! This module contains structs and helpers which are used by multiple subcommands related to
 //! creating deploys.
 
-use std::process;
+use std::{collections::BTreeMap, process};
 
-use clap::{Arg, ArgAction, ArgGroup, ArgMatches, Command};
+use clap::{parser::ValueSource, Arg, ArgAction, ArgGroup, ArgMatches, Command};
 
-use casper_client::cli::{json_args_help, simple_args_help, PaymentStrParams, SessionStrParams};
+use casper_client::{
+    cli::{
+        json_args_help, simple_args_help, CliError, DeployStrParams, DeployTemplate,
+        PaymentStrParams, SessionStrParams,
+    },
+    rpcs::results::PutDeployResult,
+    SuccessResponse, Transport,
+};
 
-use crate::common;
+use crate::{common, Success};
 
 const SESSION_ARG_GROUP: &str = "session-args";
 
@@ -19,16 +26,24 @@ pub(super) enum DisplayOrder {
     NodeAddress,
     RpcId,
     SpeculativeExec,
+    Wait,
     SecretKey,
+    SignerCommand,
     Input,
     Output,
+    Format,
     Force,
+    Template,
+    TemplateVar,
     TransferAmount,
     TransferTargetAccount,
     TransferId,
     Timestamp,
     Ttl,
+    GasPrice,
+    Dependency,
     ChainName,
+    DiscoverLimits,
     SessionCode,
     SessionArgSimple,
     SessionArgsJson,
@@ -118,6 +133,38 @@ pub(super) mod show_json_args_examples {
     }
 }
 
+/// Returns the deploy, session and payment params, taken from `maybe_template` if provided, with
+/// any deploy params explicitly given on the command line taking precedence.
+pub(super) fn str_params<'a>(
+    matches: &'a ArgMatches,
+    maybe_template: Option<&'a DeployTemplate>,
+    deploy_str_params: DeployStrParams<'a>,
+) -> (
+    DeployStrParams<'a>,
+    SessionStrParams<'a>,
+    PaymentStrParams<'a>,
+) {
+    match maybe_template {
+        Some(template) => (
+            template::deploy_str_params(template, matches, deploy_str_params),
+            template.session_params(),
+            template.payment_params(),
+        ),
+        None => (
+            deploy_str_params,
+            session_str_params(matches),
+            payment_str_params(matches),
+        ),
+    }
+}
+
+/// Returns whether the template arg has been added to `subcommand`.
+fn has_template_arg(subcommand: &Command) -> bool {
+    subcommand
+        .get_arguments()
+        .any(|arg| arg.get_id() == template::ARG_NAME)
+}
+
 pub(super) fn session_str_params(matches: &ArgMatches) -> SessionStrParams<'_> {
     let session_args_simple = arg_simple::session::get(matches);
     let session_args_json = args_json::session::get(matches);
@@ -242,7 +289,7 @@ pub(super) fn payment_str_params(matches: &ArgMatches) -> PaymentStrParams<'_> {
 pub(super) mod speculative_exec {
     use super::*;
 
-    const ARG_NAME: &str = "speculative-exec";
+    pub(in crate::deploy) const ARG_NAME: &str = "speculative-exec";
     const ARG_VALUE_NAME: &str = "HEX STRING OR INTEGER";
     const ARG_HELP: &str =
         "If the receiving node supports this, execution of the deploy will only be attempted on \
@@ -273,6 +320,61 @@ pub(super) mod speculative_exec {
     }
 }
 
+/// Handles providing the arg for and retrieval of the wait option.
+pub(super) mod wait {
+    use super::*;
+
+    const ARG_NAME: &str = "wait";
+    const ARG_VALUE_NAME: &str = "DURATION";
+    const ARG_HELP: &str =
+        "After sending the deploy, wait until it has been executed and output the outcome, \
+        including the cost of execution. Exits with an error if execution failed. Optionally \
+        provide the maximum time to wait, e.g. '5min'. If not provided, waiting stops once the \
+        deploy has expired";
+    const DEFAULT_MISSING_VALUE: &str = "";
+
+    pub(in crate::deploy) fn arg() -> Arg {
+        Arg::new(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .num_args(0..=1)
+            .default_missing_value(DEFAULT_MISSING_VALUE)
+            .conflicts_with(speculative_exec::ARG_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Wait as usize)
+    }
+
+    // None represents no --wait argument at all, Some("") represents --wait with no timeout, and
+    // Some(timeout) represents "--wait timeout".
+    pub(in crate::deploy) fn get(matches: &ArgMatches) -> Option<&str> {
+        matches.get_one::<String>(ARG_NAME).map(String::as_str)
+    }
+}
+
+/// Returns the response to sending a deploy as the successful output, or if `--wait` was given,
+/// waits for the deploy to be executed and returns the outcome instead.
+pub(super) async fn wait_if_required(
+    matches: &ArgMatches,
+    transport: &dyn Transport,
+    response: SuccessResponse<PutDeployResult>,
+) -> Result<Success, CliError> {
+    let maybe_timeout = match wait::get(matches) {
+        Some(maybe_timeout) => maybe_timeout,
+        None => return Ok(Success::from(response)),
+    };
+    casper_client::cli::wait_for_deploy_with_transport(
+        transport,
+        common::rpc_id::get(matches),
+        common::node_address::get(matches),
+        common::verbose::get(matches),
+        &response.result.deploy_hash.to_string(),
+        maybe_timeout,
+    )
+    .await
+    .map(Success::from)
+}
+
 /// Handles providing the arg for and retrieval of the timestamp.
 pub(super) mod timestamp {
     use super::*;
@@ -306,7 +408,7 @@ pub(super) mod timestamp {
 pub(super) mod ttl {
     use super::*;
 
-    const ARG_NAME: &str = "ttl";
+    pub(in crate::deploy) const ARG_NAME: &str = "ttl";
     const ARG_VALUE_NAME: &str = "DURATION";
     const ARG_DEFAULT: &str = "30min";
     const ARG_HELP: &str =
@@ -333,6 +435,62 @@ pub(super) mod ttl {
     }
 }
 
+/// Handles providing the arg for and retrieval of the gas price.
+pub(super) mod gas_price {
+    use super::*;
+
+    pub(in crate::deploy) const ARG_NAME: &str = "gas-price";
+    const ARG_VALUE_NAME: &str = common::ARG_INTEGER;
+    const ARG_DEFAULT: &str = "1";
+    const ARG_HELP: &str = "Price per gas unit, in motes, to be paid for execution of the deploy";
+
+    pub(in crate::deploy) fn arg() -> Arg {
+        Arg::new(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .default_value(ARG_DEFAULT)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::GasPrice as usize)
+    }
+
+    pub(in crate::deploy) fn get(matches: &ArgMatches) -> &str {
+        matches
+            .get_one::<String>(ARG_NAME)
+            .map(String::as_str)
+            .unwrap_or_default()
+    }
+}
+
+/// Handles providing the arg for and retrieval of the deploy's dependencies.
+pub(super) mod dependency {
+    use super::*;
+
+    const ARG_NAME: &str = "dependency";
+    const ARG_VALUE_NAME: &str = "DEPLOY_HASH";
+    const ARG_HELP: &str =
+        "Hex-encoded hash of a deploy which must be executed before this one. May be repeated to \
+        provide several dependencies";
+
+    pub(in crate::deploy) fn arg() -> Arg {
+        Arg::new(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .action(ArgAction::Append)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Dependency as usize)
+    }
+
+    pub(in crate::deploy) fn get(matches: &ArgMatches) -> Vec<&str> {
+        matches
+            .get_many::<String>(ARG_NAME)
+            .unwrap_or_default()
+            .map(String::as_str)
+            .collect()
+    }
+}
+
 /// Handles providing the arg for and retrieval of the chain name.
 pub(super) mod chain_name {
     use super::*;
@@ -342,22 +500,59 @@ pub(super) mod chain_name {
     const ARG_HELP: &str =
         "Name of the chain, to avoid the deploy from being accidentally or maliciously included in \
         a different chain";
-
-    pub(in crate::deploy) fn arg() -> Arg {
-        Arg::new(ARG_NAME)
+    const DISCOVERABLE_ARG_HELP: &str =
+        "Name of the chain, to avoid the deploy from being accidentally or maliciously included in \
+        a different chain. If not provided, the chain name is discovered from the node, along with \
+        the network's limits on deploys, and the deploy is checked against those limits before \
+        being signed";
+
+    /// Returns the arg, which is optional if `discoverable` is true, i.e. if the chain name can be
+    /// discovered from the node to which the deploy is sent.
+    pub(in crate::deploy) fn arg(discoverable: bool) -> Arg {
+        let arg = Arg::new(ARG_NAME)
             .long(ARG_NAME)
-            .required_unless_present(show_simple_arg_examples::ARG_NAME)
-            .required_unless_present(show_json_args_examples::ARG_NAME)
             .value_name(ARG_VALUE_NAME)
+            .display_order(DisplayOrder::ChainName as usize);
+        if discoverable {
+            return arg.required(false).help(DISCOVERABLE_ARG_HELP);
+        }
+        arg.required_unless_present(show_simple_arg_examples::ARG_NAME)
+            .required_unless_present(show_json_args_examples::ARG_NAME)
             .help(ARG_HELP)
-            .display_order(DisplayOrder::ChainName as usize)
     }
 
     pub(in crate::deploy) fn get(matches: &ArgMatches) -> &str {
         matches
             .get_one::<String>(ARG_NAME)
             .map(String::as_str)
-            .unwrap_or_else(|| panic!("should have {} arg", ARG_NAME))
+            .unwrap_or_default()
+    }
+}
+
+/// Handles providing the arg for and retrieval of the flag to discover the network's limits.
+pub(super) mod discover_limits {
+    use super::*;
+
+    const ARG_NAME: &str = "discover-limits";
+    const ARG_HELP: &str =
+        "If this flag is passed, the network's limits on deploys are discovered from the node even \
+        if '--chain-name' is provided, and the deploy is checked against them before being signed. \
+        The chain name must then match that of the node";
+
+    pub(in crate::deploy) fn arg() -> Arg {
+        Arg::new(ARG_NAME)
+            .long(ARG_NAME)
+            .action(ArgAction::SetTrue)
+            .required(false)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::DiscoverLimits as usize)
+    }
+
+    pub(in crate::deploy) fn get(matches: &ArgMatches) -> bool {
+        matches
+            .get_one::<bool>(ARG_NAME)
+            .copied()
+            .unwrap_or_default()
     }
 }
 
@@ -597,8 +792,12 @@ pub(super) mod standard_payment_amount {
     const ARG_SHORT: char = 'p';
     const ARG_HELP: &str =
         "If provided, uses the standard-payment system contract rather than custom payment Wasm. \
-        The value is the 'amount' arg of the standard-payment contract. This arg is incompatible \
-        with all other --payment-xxx args";
+        The value is the 'amount' arg of the standard-payment contract. For put-deploy and \
+        transfer, the value can instead be 'auto', 'auto:<MARGIN>%' or \
+        'auto:<MARGIN>%:<TRIAL_AMOUNT>', in which case the deploy is first speculatively executed \
+        on the node with a payment of the trial amount (100 CSPR by default), then the amount is \
+        set to the resulting cost plus the margin (10% by default). The deploy is not sent if the \
+        speculative execution fails. This arg is incompatible with all other --payment-xxx args";
 
     pub(in crate::deploy) fn arg(default_value: Option<&'static str>) -> Arg {
         Arg::new(ARG_NAME)
@@ -632,17 +831,21 @@ pub(super) fn apply_common_creation_options(
         );
 
     if include_node_address {
-        subcommand = subcommand.arg(
-            common::node_address::arg(DisplayOrder::NodeAddress as usize)
-                .required_unless_present(show_simple_arg_examples::ARG_NAME)
-                .required_unless_present(show_json_args_examples::ARG_NAME),
-        );
+        subcommand = subcommand
+            .arg(
+                common::node_address::arg(DisplayOrder::NodeAddress as usize)
+                    .required_unless_present(show_simple_arg_examples::ARG_NAME)
+                    .required_unless_present(show_json_args_examples::ARG_NAME),
+            )
+            .arg(discover_limits::arg());
     }
 
-    let secret_key_arg = if require_secret_key {
+    let has_template = has_template_arg(&subcommand);
+    let mut secret_key_arg = if require_secret_key {
         common::secret_key::arg(DisplayOrder::SecretKey as usize, "")
             .required_unless_present(show_simple_arg_examples::ARG_NAME)
             .required_unless_present(show_json_args_examples::ARG_NAME)
+            .required_unless_present(common::signer_command::ARG_NAME)
     } else {
         common::secret_key::arg(
             DisplayOrder::SecretKey as usize,
@@ -650,12 +853,27 @@ pub(super) fn apply_common_creation_options(
             signed, for example by running the `sign-deploy` subcommand.",
         )
     };
+    let mut chain_name_arg = chain_name::arg(include_node_address);
+    if has_template {
+        // The template may provide these instead.
+        if require_secret_key {
+            secret_key_arg = secret_key_arg.required_unless_present(template::ARG_NAME);
+        }
+        if !include_node_address {
+            chain_name_arg = chain_name_arg.required_unless_present(template::ARG_NAME);
+        }
+    }
 
     subcommand = subcommand
         .arg(secret_key_arg)
+        .arg(common::signer_command::arg(
+            DisplayOrder::SignerCommand as usize,
+        ))
         .arg(timestamp::arg())
         .arg(ttl::arg())
-        .arg(chain_name::arg())
+        .arg(gas_price::arg())
+        .arg(dependency::arg())
+        .arg(chain_name_arg)
         .arg(session_account::arg(DisplayOrder::SessionAccount as usize));
     subcommand
 }
@@ -673,6 +891,8 @@ pub(super) fn apply_common_session_options(subcommand: Command) -> Command {
                 .arg(args_complex::session::ARG_NAME)
                 .required(false),
         )
+        .arg(template::arg())
+        .arg(template_var::arg())
         .arg(is_session_transfer::arg())
         .arg(session_path::arg())
         .arg(session_entry_point::arg())
@@ -697,6 +917,7 @@ pub(super) fn apply_common_session_options(subcommand: Command) -> Command {
             // This group duplicates all the args in the "session" and "show-examples" groups, but
             // ensures at least one of them are provided.
             ArgGroup::new("session-and-show-examples")
+                .arg(template::ARG_NAME)
                 .arg(is_session_transfer::ARG_NAME)
                 .arg(session_path::ARG_NAME)
                 .arg(session_hash::ARG_NAME)
@@ -714,6 +935,23 @@ pub(crate) fn apply_common_payment_options(
     subcommand: Command,
     default_amount: Option<&'static str>,
 ) -> Command {
+    // This group duplicates all the args in the "payment" and "show-examples" groups, but ensures
+    // at least one of them, or a template, is provided.
+    let mut payment_and_show_examples = ArgGroup::new("payment-and-show-example")
+        .arg(standard_payment_amount::ARG_NAME)
+        .arg(payment_path::ARG_NAME)
+        .arg(payment_hash::ARG_NAME)
+        .arg(payment_name::ARG_NAME)
+        .arg(payment_package_hash::ARG_NAME)
+        .arg(payment_package_name::ARG_NAME)
+        .arg(show_simple_arg_examples::ARG_NAME)
+        .arg(show_json_args_examples::ARG_NAME)
+        .multiple(true)
+        .required(true);
+    if has_template_arg(&subcommand) {
+        payment_and_show_examples = payment_and_show_examples.arg(template::ARG_NAME);
+    }
+
     subcommand
         .arg(arg_simple::payment::arg())
         .arg(args_json::payment::arg())
@@ -746,21 +984,7 @@ pub(crate) fn apply_common_payment_options(
                 .arg(show_json_args_examples::ARG_NAME)
                 .required(false),
         )
-        .group(
-            // This group duplicates all the args in the "payment" and "show-examples" groups, but
-            // ensures at least one of them are provided.
-            ArgGroup::new("payment-and-show-example")
-                .arg(standard_payment_amount::ARG_NAME)
-                .arg(payment_path::ARG_NAME)
-                .arg(payment_hash::ARG_NAME)
-                .arg(payment_name::ARG_NAME)
-                .arg(payment_package_hash::ARG_NAME)
-                .arg(payment_package_name::ARG_NAME)
-                .arg(show_simple_arg_examples::ARG_NAME)
-                .arg(show_json_args_examples::ARG_NAME)
-                .multiple(true)
-                .required(true),
-        )
+        .group(payment_and_show_examples)
 }
 
 pub(super) fn show_simple_arg_examples_and_exit_if_required(matches: &ArgMatches) {
@@ -802,6 +1026,129 @@ pub(super) mod output {
     }
 }
 
+/// Handles providing the arg for and retrieval of the format of the output deploy file.
+pub(super) mod format {
+    use super::*;
+
+    const ARG_NAME: &str = "format";
+    const ARG_VALUE_NAME: &str = "FORMAT";
+    const ARG_HELP: &str =
+        "Format of the output deploy: 'json', or 'binary' for the much smaller encoding whose bytes \
+        are the ones hashed and signed. The format of an input deploy file is detected \
+        automatically";
+    const JSON: &str = "json";
+    const BINARY: &str = "binary";
+
+    pub fn arg() -> Arg {
+        Arg::new(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .default_value(JSON)
+            .value_name(ARG_VALUE_NAME)
+            .value_parser([JSON, BINARY])
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Format as usize)
+    }
+
+    pub fn get(matches: &ArgMatches) -> &str {
+        matches
+            .get_one::<String>(ARG_NAME)
+            .map(String::as_str)
+            .unwrap_or_default()
+    }
+}
+
+/// Handles providing the arg for and retrieval of the deploy template.
+pub(super) mod template {
+    use super::*;
+
+    pub(in crate::deploy) const ARG_NAME: &str = "template";
+    const ARG_VALUE_NAME: &str = common::ARG_PATH;
+    const ARG_HELP: &str =
+        "Path to a deploy template: a TOML file, or a JSON file if its extension is '.json', \
+        providing the session and payment code and any of the other options of the deploy. Options \
+        given on the command line take precedence over those in the template. Placeholders of the \
+        form ${NAME} in the template are filled from the --var args, or else from the environment \
+        variable NAME";
+
+    pub(in crate::deploy) fn arg() -> Arg {
+        Arg::new(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .conflicts_with_all(["session", SESSION_ARG_GROUP, "payment", "payment-args"])
+            .display_order(DisplayOrder::Template as usize)
+    }
+
+    /// Reads the template if provided, filling its placeholders from the --var args.
+    pub(in crate::deploy) fn get(matches: &ArgMatches) -> Result<Option<DeployTemplate>, CliError> {
+        let path = match matches.get_one::<String>(ARG_NAME) {
+            Some(path) => path,
+            None => return Ok(None),
+        };
+        let vars = template_var::get(matches)?;
+        DeployTemplate::from_file(path, &vars).map(Some)
+    }
+
+    /// Returns the `DeployStrParams` of `template`, where those explicitly given on the command
+    /// line take precedence.
+    pub(in crate::deploy) fn deploy_str_params<'a>(
+        template: &'a DeployTemplate,
+        matches: &ArgMatches,
+        mut overrides: DeployStrParams<'a>,
+    ) -> DeployStrParams<'a> {
+        // Args with default values are only overrides if provided explicitly.
+        if matches.value_source(ttl::ARG_NAME) != Some(ValueSource::CommandLine) {
+            overrides.ttl = "";
+        }
+        if matches.value_source(gas_price::ARG_NAME) != Some(ValueSource::CommandLine) {
+            overrides.gas_price = "";
+        }
+        template.deploy_params(overrides)
+    }
+}
+
+/// Handles providing the arg for and retrieval of the values of deploy template placeholders.
+pub(super) mod template_var {
+    use super::*;
+
+    const ARG_NAME: &str = "var";
+    const ARG_VALUE_NAME: &str = "NAME=VALUE";
+    const ARG_HELP: &str =
+        "Value of the placeholder ${NAME} in the deploy template. May be repeated to provide \
+        several values";
+
+    pub(in crate::deploy) fn arg() -> Arg {
+        Arg::new(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .requires(template::ARG_NAME)
+            .action(ArgAction::Append)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::TemplateVar as usize)
+    }
+
+    pub(in crate::deploy) fn get(
+        matches: &ArgMatches,
+    ) -> Result<BTreeMap<String, String>, CliError> {
+        matches
+            .get_many::<String>(ARG_NAME)
+            .unwrap_or_default()
+            .map(|var| {
+                let (name, value) =
+                    var.split_once('=')
+                        .ok_or_else(|| CliError::InvalidArgument {
+                            context: "var",
+                            error: format!("expected NAME=VALUE, got '{}'", var),
+                        })?;
+                Ok((name.to_string(), value.to_string()))
+            })
+            .collect()
+    }
+}
+
 pub(super) mod input {
     use super::*;
 
@@ -840,7 +1187,8 @@ pub(super) mod session_account {
         in from a file, in which case enter the path to the file as the --session-account \
         argument. The file should be one of the two public key files generated via the `keygen` \
         subcommand; \"public_key_hex\" or \"public_key.pem\".  If not provided, the public key of \
-        the account will be derived from the key passed via --secret-key";
+        the account will be derived from the key passed via --secret-key. Required if \
+        --signer-command is used";
 
     pub fn arg(order: usize) -> Arg {
         Arg::new(ARG_NAME)
@@ -93,10 +93,12 @@ impl ClientCommand for GetDeploy {
         let maybe_rpc_id = common::rpc_id::get(matches);
         let node_address = common::node_address::get(matches);
         let verbosity_level = common::verbose::get(matches);
+        let transport = common::transport::get(matches)?;
         let deploy_hash = deploy_hash::get(matches);
         let finalized_approvals = finalized_approvals::get(matches);
 
-        casper_client::cli::get_deploy(
+        casper_client::cli::get_deploy_with_transport(
+            transport.as_ref(),
             maybe_rpc_id,
             node_address,
             verbosity_level,
@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+use clap::{ArgMatches, Command};
+
+use casper_client::cli::CliError;
+
+use super::creation_common;
+use crate::{command::ClientCommand, Success};
+
+pub struct InspectDeploy;
+
+#[async_trait]
+impl ClientCommand for InspectDeploy {
+    const NAME: &'static str = "inspect-deploy";
+    const ABOUT: &'static str =
+        "Read a previously-saved deploy from a file and print it in human-readable form, with its \
+        runtime args decoded into JSON and the account hash of each of its signers";
+
+    fn build(display_order: usize) -> Command {
+        Command::new(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(creation_common::input::arg())
+    }
+
+    async fn run(matches: &ArgMatches) -> Result<Success, CliError> {
+        let input_path = creation_common::input::get(matches);
+        casper_client::cli::inspect_deploy_file(input_path).map(Success::from)
+    }
+}
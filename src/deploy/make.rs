@@ -20,6 +20,7 @@ impl ClientCommand for MakeDeploy {
         let subcommand = Command::new(Self::NAME)
             .about(Self::ABOUT)
             .arg(creation_common::output::arg())
+            .arg(creation_common::format::arg())
             .arg(common::force::arg(
                 creation_common::DisplayOrder::Force as usize,
                 true,
@@ -35,27 +36,41 @@ impl ClientCommand for MakeDeploy {
         creation_common::show_json_args_examples_and_exit_if_required(matches);
 
         let secret_key = common::secret_key::get(matches).unwrap_or_default();
+        let signer_command = common::signer_command::get(matches);
         let timestamp = creation_common::timestamp::get(matches);
         let ttl = creation_common::ttl::get(matches);
         let chain_name = creation_common::chain_name::get(matches);
-
-        let session_str_params = creation_common::session_str_params(matches);
-        let payment_str_params = creation_common::payment_str_params(matches);
+        let gas_price = creation_common::gas_price::get(matches);
+        let dependencies = creation_common::dependency::get(matches);
 
         let maybe_output_path = creation_common::output::get(matches).unwrap_or_default();
+        let format = creation_common::format::get(matches);
         let session_account = creation_common::session_account::get(matches).unwrap_or_default();
+        let maybe_template = creation_common::template::get(matches)?;
 
         let force = common::force::get(matches);
 
+        let (deploy_str_params, session_str_params, payment_str_params) =
+            creation_common::str_params(
+                matches,
+                maybe_template.as_ref(),
+                DeployStrParams {
+                    secret_key,
+                    signer_command,
+                    timestamp,
+                    ttl,
+                    chain_name,
+                    session_account: &session_account,
+                    gas_price,
+                    dependencies,
+                    discover_limits: false,
+                },
+            );
+
         casper_client::cli::make_deploy(
             maybe_output_path,
-            DeployStrParams {
-                secret_key,
-                timestamp,
-                ttl,
-                chain_name,
-                session_account: &session_account,
-            },
+            format,
+            deploy_str_params,
             session_str_params,
             payment_str_params,
             force,
@@ -21,6 +21,7 @@ impl ClientCommand for MakeTransfer {
             .about(Self::ABOUT)
             .display_order(display_order)
             .arg(creation_common::output::arg())
+            .arg(creation_common::format::arg())
             .arg(transfer::amount::arg())
             .arg(transfer::target_account::arg())
             .arg(transfer::transfer_id::arg())
@@ -44,27 +45,36 @@ impl ClientCommand for MakeTransfer {
         let transfer_id = transfer::transfer_id::get(matches);
 
         let secret_key = common::secret_key::get(matches).unwrap_or_default();
+        let signer_command = common::signer_command::get(matches);
         let timestamp = creation_common::timestamp::get(matches);
         let ttl = creation_common::ttl::get(matches);
         let chain_name = creation_common::chain_name::get(matches);
+        let gas_price = creation_common::gas_price::get(matches);
+        let dependencies = creation_common::dependency::get(matches);
 
         let payment_str_params = creation_common::payment_str_params(matches);
 
         let maybe_output_path = creation_common::output::get(matches).unwrap_or_default();
+        let format = creation_common::format::get(matches);
         let session_account = creation_common::session_account::get(matches)?;
         let force = common::force::get(matches);
 
         casper_client::cli::make_transfer(
             maybe_output_path,
+            format,
             amount,
             target_account,
             transfer_id,
             DeployStrParams {
                 secret_key,
+                signer_command,
                 timestamp,
                 ttl,
                 chain_name,
                 session_account: &session_account,
+                gas_price,
+                dependencies,
+                discover_limits: false,
             },
             payment_str_params,
             force,
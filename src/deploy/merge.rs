@@ -0,0 +1,83 @@
+use async_trait::async_trait;
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+use casper_client::cli::CliError;
+
+use super::creation_common;
+use crate::{command::ClientCommand, common, Success};
+
+pub struct MergeDeploys;
+
+/// Handles providing the arg for and retrieval of the paths of the deploy files to merge.
+mod input {
+    use super::*;
+
+    const ARG_NAME: &str = "input";
+    const ARG_SHORT: char = 'i';
+    const ARG_VALUE_NAME: &str = common::ARG_PATH;
+    const ARG_HELP: &str =
+        "Path to an input deploy file. Must be given at least twice, and every file must contain a \
+        copy of the same deploy";
+
+    pub fn arg() -> Arg {
+        Arg::new(ARG_NAME)
+            .required(true)
+            .long(ARG_NAME)
+            .short(ARG_SHORT)
+            .action(ArgAction::Append)
+            .num_args(1..)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(creation_common::DisplayOrder::Input as usize)
+    }
+
+    pub fn get(matches: &ArgMatches) -> Vec<&str> {
+        matches
+            .get_many::<String>(ARG_NAME)
+            .unwrap_or_else(|| panic!("should have {} arg", ARG_NAME))
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+#[async_trait]
+impl ClientCommand for MergeDeploys {
+    const NAME: &'static str = "merge-deploys";
+    const ABOUT: &'static str =
+        "Read several previously-saved copies of the same deploy from files, each signed by \
+        different keys, and output a single deploy holding all of their approvals to a file or \
+        stdout";
+
+    fn build(display_order: usize) -> Command {
+        Command::new(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(input::arg())
+            .arg(creation_common::output::arg())
+            .arg(creation_common::format::arg())
+            .arg(common::force::arg(
+                creation_common::DisplayOrder::Force as usize,
+                true,
+            ))
+    }
+
+    async fn run(matches: &ArgMatches) -> Result<Success, CliError> {
+        let input_paths = input::get(matches);
+        let maybe_output_path = creation_common::output::get(matches).unwrap_or_default();
+        let format = creation_common::format::get(matches);
+        let force = common::force::get(matches);
+        casper_client::cli::merge_deploy_files(&input_paths, maybe_output_path, format, force).map(
+            |_| {
+                Success::Output(if maybe_output_path.is_empty() {
+                    String::new()
+                } else {
+                    format!(
+                        "Merged the approvals of {} deploys and wrote to {}",
+                        input_paths.len(),
+                        maybe_output_path
+                    )
+                })
+            },
+        )
+    }
+}
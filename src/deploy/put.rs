@@ -19,7 +19,8 @@ impl ClientCommand for PutDeploy {
             .display_order(display_order)
             .arg(common::verbose::arg(DisplayOrder::Verbose as usize))
             .arg(common::rpc_id::arg(DisplayOrder::RpcId as usize))
-            .arg(creation_common::speculative_exec::arg());
+            .arg(creation_common::speculative_exec::arg())
+            .arg(creation_common::wait::arg());
         let subcommand = creation_common::apply_common_session_options(subcommand);
         let subcommand = creation_common::apply_common_payment_options(subcommand, None);
         creation_common::apply_common_creation_options(subcommand, true, true)
@@ -32,52 +33,63 @@ impl ClientCommand for PutDeploy {
         let maybe_rpc_id = common::rpc_id::get(matches);
         let node_address = common::node_address::get(matches);
         let verbosity_level = common::verbose::get(matches);
+        let transport = common::transport::get(matches)?;
 
         let secret_key = common::secret_key::get(matches).unwrap_or_default();
+        let signer_command = common::signer_command::get(matches);
         let maybe_speculative_exec = creation_common::speculative_exec::get(matches);
         let timestamp = creation_common::timestamp::get(matches);
         let ttl = creation_common::ttl::get(matches);
         let chain_name = creation_common::chain_name::get(matches);
+        let gas_price = creation_common::gas_price::get(matches);
+        let dependencies = creation_common::dependency::get(matches);
+        let discover_limits = creation_common::discover_limits::get(matches);
         let session_account = creation_common::session_account::get(matches)?;
 
-        let session_str_params = creation_common::session_str_params(matches);
-        let payment_str_params = creation_common::payment_str_params(matches);
+        let maybe_template = creation_common::template::get(matches)?;
 
-        if let Some(speculative_exec) = maybe_speculative_exec {
-            casper_client::cli::speculative_put_deploy(
-                speculative_exec,
-                maybe_rpc_id,
-                node_address,
-                verbosity_level,
+        let (deploy_str_params, session_str_params, payment_str_params) =
+            creation_common::str_params(
+                matches,
+                maybe_template.as_ref(),
                 DeployStrParams {
                     secret_key,
+                    signer_command,
                     timestamp,
                     ttl,
                     chain_name,
                     session_account: &session_account,
+                    gas_price,
+                    dependencies,
+                    discover_limits,
                 },
+            );
+
+        if let Some(speculative_exec) = maybe_speculative_exec {
+            casper_client::cli::speculative_put_deploy_with_transport(
+                transport.as_ref(),
+                speculative_exec,
+                maybe_rpc_id,
+                node_address,
+                verbosity_level,
+                deploy_str_params,
                 session_str_params,
                 payment_str_params,
             )
             .await
             .map(Success::from)
         } else {
-            casper_client::cli::put_deploy(
+            let response = casper_client::cli::put_deploy_with_transport(
+                transport.as_ref(),
                 maybe_rpc_id,
                 node_address,
                 verbosity_level,
-                DeployStrParams {
-                    secret_key,
-                    timestamp,
-                    ttl,
-                    chain_name,
-                    session_account: &session_account,
-                },
+                deploy_str_params,
                 session_str_params,
                 payment_str_params,
             )
-            .await
-            .map(Success::from)
+            .await?;
+            creation_common::wait_if_required(matches, transport.as_ref(), response).await
         }
     }
 }
@@ -0,0 +1,174 @@
+use async_trait::async_trait;
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+use casper_client::cli::CliError;
+
+use super::creation_common::{self, DisplayOrder};
+use crate::{command::ClientCommand, common, Success};
+
+pub struct RestampDeploy;
+
+/// Handles providing the arg for and retrieval of the secret keys with which to re-sign the deploy.
+mod secret_key {
+    use super::*;
+
+    const ARG_HELP: &str = ". May be repeated to re-sign the deploy with several keys";
+
+    pub(super) fn arg() -> Arg {
+        common::secret_key::arg(DisplayOrder::SecretKey as usize, ARG_HELP)
+            .required(false)
+            .action(ArgAction::Append)
+    }
+
+    pub(super) fn get(matches: &ArgMatches) -> Vec<&str> {
+        matches
+            .get_many::<String>(common::secret_key::ARG_NAME)
+            .unwrap_or_default()
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+/// Handles providing the arg for and retrieval of the new time to live.
+mod ttl {
+    use super::*;
+
+    const ARG_NAME: &str = "ttl";
+    const ARG_VALUE_NAME: &str = "DURATION";
+    const ARG_HELP: &str =
+        "New time that the deploy will remain valid for. If not provided, the deploy's existing \
+        TTL is kept. Input examples: '1hr 12min', '30min 50sec', '1day'";
+
+    pub(super) fn arg() -> Arg {
+        Arg::new(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Ttl as usize)
+    }
+
+    pub(super) fn get(matches: &ArgMatches) -> &str {
+        matches
+            .get_one::<String>(ARG_NAME)
+            .map(String::as_str)
+            .unwrap_or_default()
+    }
+}
+
+/// Handles providing the arg for and retrieval of the new gas price.
+mod gas_price {
+    use super::*;
+
+    const ARG_NAME: &str = "gas-price";
+    const ARG_VALUE_NAME: &str = common::ARG_INTEGER;
+    const ARG_HELP: &str =
+        "New price per gas unit, in motes, to be paid for execution of the deploy. If not \
+        provided, the deploy's existing gas price is kept";
+
+    pub(super) fn arg() -> Arg {
+        Arg::new(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::GasPrice as usize)
+    }
+
+    pub(super) fn get(matches: &ArgMatches) -> &str {
+        matches
+            .get_one::<String>(ARG_NAME)
+            .map(String::as_str)
+            .unwrap_or_default()
+    }
+}
+
+/// Handles providing the arg for and retrieval of the new chain name.
+mod chain_name {
+    use super::*;
+
+    const ARG_NAME: &str = "chain-name";
+    const ARG_VALUE_NAME: &str = "NAME";
+    const ARG_HELP: &str =
+        "New name of the chain on which the deploy is to be executed. If not provided, the \
+        deploy's existing chain name is kept";
+
+    pub(super) fn arg() -> Arg {
+        Arg::new(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::ChainName as usize)
+    }
+
+    pub(super) fn get(matches: &ArgMatches) -> &str {
+        matches
+            .get_one::<String>(ARG_NAME)
+            .map(String::as_str)
+            .unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl ClientCommand for RestampDeploy {
+    const NAME: &'static str = "restamp-deploy";
+    const ABOUT: &'static str =
+        "Read a previously-saved deploy from a file, give it a new timestamp and optionally a new \
+        TTL, gas price or chain name, drop its approvals, re-sign it, and output it to a file or \
+        stdout";
+
+    fn build(display_order: usize) -> Command {
+        Command::new(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(secret_key::arg())
+            .arg(common::signer_command::arg(
+                DisplayOrder::SignerCommand as usize,
+            ))
+            .arg(creation_common::input::arg())
+            .arg(creation_common::output::arg())
+            .arg(creation_common::format::arg())
+            .arg(common::force::arg(DisplayOrder::Force as usize, true))
+            .arg(creation_common::timestamp::arg())
+            .arg(ttl::arg())
+            .arg(gas_price::arg())
+            .arg(chain_name::arg())
+    }
+
+    async fn run(matches: &ArgMatches) -> Result<Success, CliError> {
+        let input_path = creation_common::input::get(matches);
+        let secret_key_paths = secret_key::get(matches);
+        let signer_command = common::signer_command::get(matches);
+        let maybe_output_path = creation_common::output::get(matches).unwrap_or_default();
+        let format = creation_common::format::get(matches);
+        let force = common::force::get(matches);
+        let timestamp = creation_common::timestamp::get(matches);
+        let ttl = ttl::get(matches);
+        let gas_price = gas_price::get(matches);
+        let chain_name = chain_name::get(matches);
+
+        casper_client::cli::restamp_deploy_file(
+            input_path,
+            timestamp,
+            ttl,
+            chain_name,
+            gas_price,
+            &secret_key_paths,
+            signer_command,
+            maybe_output_path,
+            format,
+            force,
+        )
+        .map(|_| {
+            Success::Output(if maybe_output_path.is_empty() {
+                String::new()
+            } else {
+                format!(
+                    "Re-stamped the deploy at {} and wrote to {}",
+                    input_path, maybe_output_path
+                )
+            })
+        })
+    }
+}
@@ -25,6 +25,7 @@ impl ClientCommand for SendDeploy {
             ))
             .arg(common::rpc_id::arg(DisplayOrder::RpcId as usize))
             .arg(creation_common::speculative_exec::arg())
+            .arg(creation_common::wait::arg())
             .arg(creation_common::input::arg())
     }
 
@@ -33,10 +34,12 @@ impl ClientCommand for SendDeploy {
         let maybe_rpc_id = common::rpc_id::get(matches);
         let node_address = common::node_address::get(matches);
         let verbosity_level = common::verbose::get(matches);
+        let transport = common::transport::get(matches)?;
         let input_path = creation_common::input::get(matches);
 
         if let Some(speculative_exec) = maybe_speculative_exec {
-            casper_client::cli::speculative_send_deploy_file(
+            casper_client::cli::speculative_send_deploy_file_with_transport(
+                transport.as_ref(),
                 speculative_exec,
                 maybe_rpc_id,
                 node_address,
@@ -46,14 +49,15 @@ impl ClientCommand for SendDeploy {
             .await
             .map(Success::from)
         } else {
-            casper_client::cli::send_deploy_file(
+            let response = casper_client::cli::send_deploy_file_with_transport(
+                transport.as_ref(),
                 maybe_rpc_id,
                 node_address,
                 verbosity_level,
                 input_path,
             )
-            .await
-            .map(Success::from)
+            .await?;
+            creation_common::wait_if_required(matches, transport.as_ref(), response).await
         }
     }
 }
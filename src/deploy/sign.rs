@@ -21,10 +21,14 @@ impl ClientCommand for SignDeploy {
             .display_order(display_order)
             .arg(
                 common::secret_key::arg(creation_common::DisplayOrder::SecretKey as usize, "")
-                    .required(true),
+                    .required_unless_present(common::signer_command::ARG_NAME),
             )
+            .arg(common::signer_command::arg(
+                creation_common::DisplayOrder::SignerCommand as usize,
+            ))
             .arg(creation_common::input::arg())
             .arg(creation_common::output::arg())
+            .arg(creation_common::format::arg())
             .arg(common::force::arg(
                 creation_common::DisplayOrder::Force as usize,
                 true,
@@ -34,19 +38,36 @@ impl ClientCommand for SignDeploy {
     async fn run(matches: &ArgMatches) -> Result<Success, CliError> {
         let input_path = creation_common::input::get(matches);
         let secret_key = common::secret_key::get(matches).unwrap_or_default();
+        let signer_command = common::signer_command::get(matches);
         let maybe_output_path = creation_common::output::get(matches).unwrap_or_default();
+        let format = creation_common::format::get(matches);
         let force = common::force::get(matches);
-        casper_client::cli::sign_deploy_file(input_path, secret_key, maybe_output_path, force).map(
-            |_| {
-                Success::Output(if maybe_output_path.is_empty() {
-                    String::new()
-                } else {
-                    format!(
-                        "Signed the deploy at {} and wrote to {}",
-                        input_path, maybe_output_path
-                    )
-                })
-            },
-        )
+        let result = if signer_command.is_empty() {
+            casper_client::cli::sign_deploy_file(
+                input_path,
+                secret_key,
+                maybe_output_path,
+                format,
+                force,
+            )
+        } else {
+            casper_client::cli::sign_deploy_file_with_signer_command(
+                input_path,
+                signer_command,
+                maybe_output_path,
+                format,
+                force,
+            )
+        };
+        result.map(|_| {
+            Success::Output(if maybe_output_path.is_empty() {
+                String::new()
+            } else {
+                format!(
+                    "Signed the deploy at {} and wrote to {}",
+                    input_path, maybe_output_path
+                )
+            })
+        })
     }
 }
@@ -108,6 +108,7 @@ impl ClientCommand for Transfer {
             .arg(common::verbose::arg(DisplayOrder::Verbose as usize))
             .arg(common::rpc_id::arg(DisplayOrder::RpcId as usize))
             .arg(creation_common::speculative_exec::arg())
+            .arg(creation_common::wait::arg())
             .arg(amount::arg())
             .arg(target_account::arg())
             .arg(transfer_id::arg());
@@ -129,18 +130,24 @@ impl ClientCommand for Transfer {
         let maybe_rpc_id = common::rpc_id::get(matches);
         let node_address = common::node_address::get(matches);
         let verbosity_level = common::verbose::get(matches);
+        let transport = common::transport::get(matches)?;
 
         let secret_key = common::secret_key::get(matches).unwrap_or_default();
+        let signer_command = common::signer_command::get(matches);
         let maybe_speculative_exec = creation_common::speculative_exec::get(matches);
         let timestamp = creation_common::timestamp::get(matches);
         let ttl = creation_common::ttl::get(matches);
         let chain_name = creation_common::chain_name::get(matches);
+        let gas_price = creation_common::gas_price::get(matches);
+        let dependencies = creation_common::dependency::get(matches);
+        let discover_limits = creation_common::discover_limits::get(matches);
         let session_account = creation_common::session_account::get(matches)?;
 
         let payment_str_params = creation_common::payment_str_params(matches);
 
         if let Some(speculative_exec) = maybe_speculative_exec {
-            casper_client::cli::speculative_transfer(
+            casper_client::cli::speculative_transfer_with_transport(
+                transport.as_ref(),
                 speculative_exec,
                 maybe_rpc_id,
                 node_address,
@@ -150,17 +157,22 @@ impl ClientCommand for Transfer {
                 transfer_id,
                 DeployStrParams {
                     secret_key,
+                    signer_command,
                     timestamp,
                     ttl,
                     chain_name,
                     session_account: &session_account,
+                    gas_price,
+                    dependencies,
+                    discover_limits,
                 },
                 payment_str_params,
             )
             .await
             .map(Success::from)
         } else {
-            casper_client::cli::transfer(
+            let response = casper_client::cli::transfer_with_transport(
+                transport.as_ref(),
                 maybe_rpc_id,
                 node_address,
                 verbosity_level,
@@ -169,15 +181,19 @@ impl ClientCommand for Transfer {
                 transfer_id,
                 DeployStrParams {
                     secret_key,
+                    signer_command,
                     timestamp,
                     ttl,
                     chain_name,
                     session_account: &session_account,
+                    gas_price,
+                    dependencies,
+                    discover_limits,
                 },
                 payment_str_params,
             )
-            .await
-            .map(Success::from)
+            .await?;
+            creation_common::wait_if_required(matches, transport.as_ref(), response).await
         }
     }
 }
@@ -0,0 +1,95 @@
+use async_trait::async_trait;
+use clap::{Arg, ArgMatches, Command};
+
+use casper_client::cli::CliError;
+
+use super::creation_common;
+use crate::{command::ClientCommand, Success};
+
+pub struct VerifyDeploy;
+
+/// This enum defines the order in which the args are shown for this subcommand's help message.
+enum DisplayOrder {
+    Input,
+    ChainName,
+    MaxTtl,
+}
+
+/// Handles providing the arg for and retrieval of the expected chain name.
+mod chain_name {
+    use super::*;
+
+    const ARG_NAME: &str = "chain-name";
+    const ARG_VALUE_NAME: &str = "NAME";
+    const ARG_HELP: &str = "Name of the chain on which the deploy is expected to be executed";
+
+    pub fn arg() -> Arg {
+        Arg::new(ARG_NAME)
+            .long(ARG_NAME)
+            .required(true)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::ChainName as usize)
+    }
+
+    pub fn get(matches: &ArgMatches) -> &str {
+        matches
+            .get_one::<String>(ARG_NAME)
+            .map(String::as_str)
+            .unwrap_or_else(|| panic!("should have {} arg", ARG_NAME))
+    }
+}
+
+/// Handles providing the arg for and retrieval of the maximum permitted TTL.
+mod max_ttl {
+    use super::*;
+
+    const ARG_NAME: &str = "max-ttl";
+    const ARG_VALUE_NAME: &str = "DURATION";
+    const ARG_DEFAULT: &str = "18hours";
+    const ARG_HELP: &str =
+        "Maximum time-to-live permitted for deploys on the chain, as given by `max_ttl` in the \
+        chainspec. Input examples: '1hr 12min', '30min 50sec', '1day'";
+
+    pub fn arg() -> Arg {
+        Arg::new(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .default_value(ARG_DEFAULT)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::MaxTtl as usize)
+    }
+
+    pub fn get(matches: &ArgMatches) -> &str {
+        matches
+            .get_one::<String>(ARG_NAME)
+            .map(String::as_str)
+            .unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl ClientCommand for VerifyDeploy {
+    const NAME: &'static str = "verify-deploy";
+    const ABOUT: &'static str =
+        "Read a previously-saved deploy from a file and verify its hashes and the signatures of \
+        its approvals, and that it is valid for the given chain";
+
+    fn build(display_order: usize) -> Command {
+        Command::new(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(creation_common::input::arg().display_order(DisplayOrder::Input as usize))
+            .arg(chain_name::arg())
+            .arg(max_ttl::arg())
+    }
+
+    async fn run(matches: &ArgMatches) -> Result<Success, CliError> {
+        let input_path = creation_common::input::get(matches);
+        let chain_name = chain_name::get(matches);
+        let max_ttl = max_ttl::get(matches);
+        casper_client::cli::verify_deploy_file(input_path, chain_name, max_ttl)
+            .map(|_| Success::Output(format!("Verified the deploy at {}", input_path)))
+    }
+}
@@ -57,10 +57,12 @@ impl ClientCommand for GetAccount {
         let maybe_rpc_id = common::rpc_id::get(matches);
         let node_address = common::node_address::get(matches);
         let verbosity_level = common::verbose::get(matches);
+        let transport = common::transport::get(matches)?;
         let block_identifier = common::block_identifier::get(matches);
         let account_idenfitier = common::account_identifier::get(matches)?;
 
-        casper_client::cli::get_account(
+        casper_client::cli::get_account_with_transport(
+            transport.as_ref(),
             maybe_rpc_id,
             node_address,
             verbosity_level,
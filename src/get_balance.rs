@@ -47,12 +47,14 @@ impl ClientCommand for GetBalance {
         let maybe_rpc_id = common::rpc_id::get(matches);
         let node_address = common::node_address::get(matches);
         let verbosity_level = common::verbose::get(matches);
+        let transport = common::transport::get(matches)?;
         let state_root_hash = common::state_root_hash::get(matches)
             .unwrap_or_else(|| panic!("should have {} arg", common::state_root_hash::ARG_NAME));
         let purse_uref = common::purse_uref::get(matches)
             .unwrap_or_else(|| panic!("should have {} arg", common::purse_uref::ARG_NAME));
 
-        casper_client::cli::get_balance(
+        casper_client::cli::get_balance_with_transport(
+            transport.as_ref(),
             maybe_rpc_id,
             node_address,
             verbosity_level,
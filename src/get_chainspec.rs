@@ -1,7 +1,7 @@
 use std::str;
 
 use async_trait::async_trait;
-use clap::{ArgMatches, Command};
+use clap::{Arg, ArgMatches, Command};
 
 use casper_client::cli::CliError;
 
@@ -14,6 +14,34 @@ enum DisplayOrder {
     Verbose,
     NodeAddress,
     RpcId,
+    SaveDir,
+    Force,
+}
+
+/// Handles providing the arg for and retrieval of the directory to which the chainspec files are
+/// written.
+mod save_dir {
+    use super::*;
+
+    pub(super) const ARG_NAME: &str = "save-dir";
+    const ARG_VALUE_NAME: &str = common::ARG_PATH;
+    const ARG_HELP: &str =
+        "Path to a directory to which the chainspec is written as \"chainspec.toml\", and the \
+        genesis accounts and global state, if provided by the node, as \"accounts.toml\" and \
+        \"global_state.toml\". If the path doesn't exist, it will be created";
+
+    pub(super) fn arg() -> Arg {
+        Arg::new(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::SaveDir as usize)
+    }
+
+    pub(super) fn get(matches: &ArgMatches) -> Option<&str> {
+        matches.get_one::<String>(ARG_NAME).map(String::as_str)
+    }
 }
 
 #[async_trait]
@@ -31,15 +59,41 @@ impl ClientCommand for GetChainspec {
                 DisplayOrder::NodeAddress as usize,
             ))
             .arg(common::rpc_id::arg(DisplayOrder::RpcId as usize))
+            .arg(save_dir::arg())
+            .arg(
+                common::force::arg(DisplayOrder::Force as usize, false)
+                    .requires(save_dir::ARG_NAME),
+            )
     }
 
     async fn run(matches: &ArgMatches) -> Result<Success, CliError> {
         let maybe_rpc_id = common::rpc_id::get(matches);
         let node_address = common::node_address::get(matches);
         let verbosity_level = common::verbose::get(matches);
+        let transport = common::transport::get(matches)?;
+        let maybe_save_dir = save_dir::get(matches);
+        let force = common::force::get(matches);
 
-        casper_client::cli::get_chainspec(maybe_rpc_id, node_address, verbosity_level)
-            .await
-            .map(Success::from)
+        let response = casper_client::cli::get_chainspec_with_transport(
+            transport.as_ref(),
+            maybe_rpc_id,
+            node_address,
+            verbosity_level,
+        )
+        .await?;
+        match maybe_save_dir {
+            Some(save_dir) => {
+                casper_client::cli::save_chainspec_files(
+                    &response.result.chainspec_bytes,
+                    save_dir,
+                    force,
+                )?;
+                Ok(Success::Output(format!(
+                    "Wrote chainspec files to {}",
+                    save_dir
+                )))
+            }
+            None => Ok(Success::from(response)),
+        }
     }
 }
@@ -245,6 +245,7 @@ impl ClientCommand for GetDictionaryItem {
         let maybe_rpc_id = common::rpc_id::get(matches);
         let node_address = common::node_address::get(matches);
         let verbosity_level = common::verbose::get(matches);
+        let transport = common::transport::get(matches)?;
         let state_root_hash = common::state_root_hash::get(matches)
             .unwrap_or_else(|| panic!("should have {} arg", common::state_root_hash::ARG_NAME));
 
@@ -282,7 +283,8 @@ impl ClientCommand for GetDictionaryItem {
             });
         };
 
-        casper_client::cli::get_dictionary_item(
+        casper_client::cli::get_dictionary_item_with_transport(
+            transport.as_ref(),
             maybe_rpc_id,
             node_address,
             verbosity_level,
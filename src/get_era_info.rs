@@ -46,10 +46,12 @@ impl ClientCommand for GetEraInfo {
         let maybe_rpc_id = common::rpc_id::get(matches);
         let node_address = common::node_address::get(matches);
         let verbosity_level = common::verbose::get(matches);
+        let transport = common::transport::get(matches)?;
         let maybe_block_id = common::block_identifier::get(matches);
 
         #[allow(deprecated)]
-        casper_client::cli::get_era_info(
+        casper_client::cli::get_era_info_with_transport(
+            transport.as_ref(),
             maybe_rpc_id,
             node_address,
             verbosity_level,
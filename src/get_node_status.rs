@@ -36,9 +36,15 @@ impl ClientCommand for GetNodeStatus {
         let maybe_rpc_id = common::rpc_id::get(matches);
         let node_address = common::node_address::get(matches);
         let verbosity_level = common::verbose::get(matches);
-
-        casper_client::cli::get_node_status(maybe_rpc_id, node_address, verbosity_level)
-            .await
-            .map(Success::from)
+        let transport = common::transport::get(matches)?;
+
+        casper_client::cli::get_node_status_with_transport(
+            transport.as_ref(),
+            maybe_rpc_id,
+            node_address,
+            verbosity_level,
+        )
+        .await
+        .map(Success::from)
     }
 }
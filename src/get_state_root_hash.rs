@@ -41,9 +41,11 @@ impl ClientCommand for GetStateRootHash {
         let maybe_rpc_id = common::rpc_id::get(matches);
         let node_address = common::node_address::get(matches);
         let verbosity_level = common::verbose::get(matches);
+        let transport = common::transport::get(matches)?;
         let maybe_block_id = common::block_identifier::get(matches);
 
-        casper_client::cli::get_state_root_hash(
+        casper_client::cli::get_state_root_hash_with_transport(
+            transport.as_ref(),
             maybe_rpc_id,
             node_address,
             verbosity_level,
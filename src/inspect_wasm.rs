@@ -0,0 +1,134 @@
+use async_trait::async_trait;
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+use casper_client::cli::CliError;
+
+use crate::{command::ClientCommand, common, Success};
+
+pub struct InspectWasm;
+
+/// This enum defines the order in which the args are shown for this subcommand's help message.
+enum DisplayOrder {
+    Input,
+    StripDebugSections,
+    Output,
+    Force,
+}
+
+/// Handles providing the arg for and retrieval of the path to the Wasm file.
+mod input {
+    use super::*;
+
+    const ARG_NAME: &str = "input";
+    const ARG_SHORT: char = 'i';
+    const ARG_VALUE_NAME: &str = common::ARG_PATH;
+    const ARG_HELP: &str = "Path to input Wasm file";
+
+    pub(super) fn arg() -> Arg {
+        Arg::new(ARG_NAME)
+            .required(true)
+            .long(ARG_NAME)
+            .short(ARG_SHORT)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Input as usize)
+    }
+
+    pub(super) fn get(matches: &ArgMatches) -> &str {
+        matches
+            .get_one::<String>(ARG_NAME)
+            .map(String::as_str)
+            .unwrap_or_else(|| panic!("should have {} arg", ARG_NAME))
+    }
+}
+
+/// Handles providing the arg for and retrieval of the flag to strip the module's debug sections.
+mod strip_debug_sections {
+    use super::*;
+
+    pub(super) const ARG_NAME: &str = "strip-debug-sections";
+    const ARG_HELP: &str =
+        "If this flag is passed, rather than being inspected, the module is written to '--output' \
+        without its debug info, i.e. without the custom sections named \"name\", \
+        \"sourceMappingURL\", \"external_debug_info\" or starting with \".debug\"";
+
+    pub(super) fn arg() -> Arg {
+        Arg::new(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .action(ArgAction::SetTrue)
+            .requires(output::ARG_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::StripDebugSections as usize)
+    }
+
+    pub(super) fn get(matches: &ArgMatches) -> bool {
+        matches
+            .get_one::<bool>(ARG_NAME)
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+/// Handles providing the arg for and retrieval of the path to the stripped Wasm file.
+mod output {
+    use super::*;
+
+    pub(super) const ARG_NAME: &str = "output";
+    const ARG_SHORT: char = 'o';
+    const ARG_VALUE_NAME: &str = common::ARG_PATH;
+    const ARG_HELP: &str =
+        "Path to output Wasm file, written when '--strip-debug-sections' is passed. If the file \
+        already exists, the command will fail unless '--force' is also specified";
+
+    pub(super) fn arg() -> Arg {
+        Arg::new(ARG_NAME)
+            .long(ARG_NAME)
+            .short(ARG_SHORT)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .requires(strip_debug_sections::ARG_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Output as usize)
+    }
+
+    pub(super) fn get(matches: &ArgMatches) -> &str {
+        matches
+            .get_one::<String>(ARG_NAME)
+            .map(String::as_str)
+            .unwrap_or_else(|| panic!("should have {} arg", ARG_NAME))
+    }
+}
+
+#[async_trait]
+impl ClientCommand for InspectWasm {
+    const NAME: &'static str = "inspect-wasm";
+    const ABOUT: &'static str =
+        "Read a Wasm module from a file and list its imports, exports, custom sections and memory, \
+        or write a copy of it without its debug sections";
+
+    fn build(display_order: usize) -> Command {
+        Command::new(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(input::arg())
+            .arg(strip_debug_sections::arg())
+            .arg(output::arg())
+            .arg(common::force::arg(DisplayOrder::Force as usize, true).requires(output::ARG_NAME))
+    }
+
+    async fn run(matches: &ArgMatches) -> Result<Success, CliError> {
+        let input_path = input::get(matches);
+        if !strip_debug_sections::get(matches) {
+            return casper_client::cli::inspect_wasm_file(input_path).map(Success::from);
+        }
+
+        let output_path = output::get(matches);
+        let force = common::force::get(matches);
+        casper_client::cli::strip_wasm_debug_sections_file(input_path, output_path, force)?;
+        Ok(Success::Output(format!(
+            "Wrote Wasm module without debug sections to {}",
+            output_path
+        )))
+    }
+}
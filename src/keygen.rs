@@ -1,10 +1,11 @@
 use async_trait::async_trait;
-use clap::{builder::PossibleValuesParser, Arg, ArgMatches, Command};
+use clap::{builder::PossibleValuesParser, Arg, ArgAction, ArgMatches, Command};
 use once_cell::sync::Lazy;
 
 use casper_client::{
     cli::CliError,
-    keygen::{self, FILES, PUBLIC_KEY_HEX},
+    keygen::{self, FILES, PUBLIC_KEY_HEX, SECRET_KEY_KEYSTORE, SECRET_KEY_PEM},
+    keystore,
 };
 
 use crate::{command::ClientCommand, common, Success};
@@ -12,10 +13,12 @@ use crate::{command::ClientCommand, common, Success};
 static MORE_ABOUT: Lazy<String> = Lazy::new(|| {
     format!(
         "{}. Creates {:?}. \"{}\" contains the hex-encoded key's bytes with the hex-encoded \
-        algorithm tag prefixed",
+        algorithm tag prefixed. If --encrypt is passed, \"{}\" is replaced by \"{}\"",
         Keygen::ABOUT,
         FILES,
-        PUBLIC_KEY_HEX
+        PUBLIC_KEY_HEX,
+        SECRET_KEY_PEM,
+        SECRET_KEY_KEYSTORE
     )
 });
 
@@ -24,6 +27,7 @@ enum DisplayOrder {
     OutputDir,
     Force,
     Algorithm,
+    Encrypt,
 }
 
 /// Handles providing the arg for and retrieval of the output directory.
@@ -84,6 +88,34 @@ mod algorithm {
     }
 }
 
+/// Handles the arg for whether to encrypt the secret key.
+mod encrypt {
+    use super::*;
+
+    const ARG_NAME: &str = "encrypt";
+    const ARG_HELP: &str =
+        "If this flag is passed, the secret key is written as a password-encrypted keystore rather \
+        than in plaintext. The password is read from the environment variable \
+        CASPER_CLIENT_KEYSTORE_PASSWORD if set, otherwise from the file at the path given by \
+        CASPER_CLIENT_KEYSTORE_PASSWORD_FILE if set, otherwise it is prompted for";
+
+    pub fn arg() -> Arg {
+        Arg::new(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .action(ArgAction::SetTrue)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Encrypt as usize)
+    }
+
+    pub fn get(matches: &ArgMatches) -> bool {
+        matches
+            .get_one::<bool>(ARG_NAME)
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
 pub struct Keygen {}
 
 #[async_trait]
@@ -99,6 +131,7 @@ impl ClientCommand for Keygen {
             .arg(output_dir::arg())
             .arg(common::force::arg(DisplayOrder::Force as usize, false))
             .arg(algorithm::arg())
+            .arg(encrypt::arg())
     }
 
     /// Asynchronously runs the keygen command based on the provided command-line arguments.
@@ -118,7 +151,12 @@ impl ClientCommand for Keygen {
         let algorithm = algorithm::get(matches);
         let force = common::force::get(matches);
 
-        keygen::generate_files(&output_dir, algorithm, force)?;
+        if encrypt::get(matches) {
+            let password = keystore::read_new_password()?;
+            keygen::generate_encrypted_files(&output_dir, algorithm, &password, force)?;
+        } else {
+            keygen::generate_files(&output_dir, algorithm, force)?;
+        }
         Ok(Success::Output(format!("Wrote files to {}", output_dir)))
     }
 }
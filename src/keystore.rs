@@ -0,0 +1,138 @@
+use async_trait::async_trait;
+use clap::{Arg, ArgMatches, Command};
+
+use casper_client::cli::{self, CliError};
+
+use crate::{command::ClientCommand, common, Success};
+
+const ENCRYPT: &str = "encrypt";
+const ENCRYPT_ABOUT: &str =
+    "Read a plaintext PEM-encoded secret key from a file, encrypt it under a password, and output \
+    the resulting keystore to a file or stdout";
+const DECRYPT: &str = "decrypt";
+const DECRYPT_ABOUT: &str =
+    "Read a keystore from a file, decrypt it, and output the PEM-encoded secret key to a file or \
+    stdout";
+
+/// This enum defines the order in which the args are shown for this subcommand's help message.
+enum DisplayOrder {
+    Input,
+    Output,
+    Force,
+}
+
+/// Handles providing the arg for and retrieval of the input file path.
+mod input {
+    use super::*;
+
+    const ARG_NAME: &str = "input";
+    const ARG_SHORT: char = 'i';
+    const ARG_VALUE_NAME: &str = common::ARG_PATH;
+    const ARG_HELP: &str = "Path to input secret key or keystore file";
+
+    pub fn arg() -> Arg {
+        Arg::new(ARG_NAME)
+            .required(true)
+            .long(ARG_NAME)
+            .short(ARG_SHORT)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Input as usize)
+    }
+
+    pub fn get(matches: &ArgMatches) -> &str {
+        matches
+            .get_one::<String>(ARG_NAME)
+            .map(String::as_str)
+            .unwrap_or_else(|| panic!("should have {} arg", ARG_NAME))
+    }
+}
+
+/// Handles providing the arg for and retrieval of the output file path.
+mod output {
+    use super::*;
+
+    const ARG_NAME: &str = "output";
+    const ARG_SHORT: char = 'o';
+    const ARG_VALUE_NAME: &str = common::ARG_PATH;
+    const ARG_HELP: &str =
+        "Path to output file. If omitted, defaults to stdout. If the file already exists, the \
+        command will fail unless '--force' is also specified";
+
+    pub fn arg() -> Arg {
+        Arg::new(ARG_NAME)
+            .required(false)
+            .long(ARG_NAME)
+            .short(ARG_SHORT)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Output as usize)
+    }
+
+    pub fn get(matches: &ArgMatches) -> &str {
+        matches
+            .get_one::<String>(ARG_NAME)
+            .map(String::as_str)
+            .unwrap_or_default()
+    }
+}
+
+fn subcommand(name: &'static str, about: &'static str) -> Command {
+    Command::new(name)
+        .about(about)
+        .arg(input::arg())
+        .arg(output::arg())
+        .arg(common::force::arg(DisplayOrder::Force as usize, true))
+}
+
+pub struct Keystore;
+
+#[async_trait]
+impl ClientCommand for Keystore {
+    const NAME: &'static str = "keystore";
+    const ABOUT: &'static str =
+        "Encrypt a secret key file into a password-protected keystore, or decrypt a keystore. The \
+        password is read from the environment variable CASPER_CLIENT_KEYSTORE_PASSWORD if set, \
+        otherwise from the file at the path given by CASPER_CLIENT_KEYSTORE_PASSWORD_FILE if set, \
+        otherwise it is prompted for";
+
+    fn build(display_order: usize) -> Command {
+        Command::new(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .subcommand_required(true)
+            .subcommand(subcommand(ENCRYPT, ENCRYPT_ABOUT))
+            .subcommand(subcommand(DECRYPT, DECRYPT_ABOUT))
+    }
+
+    async fn run(matches: &ArgMatches) -> Result<Success, CliError> {
+        let (subcommand_name, matches) = matches
+            .subcommand()
+            .unwrap_or_else(|| panic!("should have {} subcommand", Self::NAME));
+        let input_path = input::get(matches);
+        let maybe_output_path = output::get(matches);
+        let force = common::force::get(matches);
+
+        let (action, result) = match subcommand_name {
+            ENCRYPT => (
+                "Encrypted",
+                cli::encrypt_secret_key_file(input_path, maybe_output_path, force),
+            ),
+            DECRYPT => (
+                "Decrypted",
+                cli::decrypt_keystore_file(input_path, maybe_output_path, force),
+            ),
+            _ => unreachable!("unknown {} subcommand {}", Self::NAME, subcommand_name),
+        };
+        result.map(|_| {
+            Success::Output(if maybe_output_path.is_empty() {
+                String::new()
+            } else {
+                format!(
+                    "{} the key at {} and wrote to {}",
+                    action, input_path, maybe_output_path
+                )
+            })
+        })
+    }
+}
@@ -1,5 +1,6 @@
 mod account_address;
 mod block;
+mod chainspec_diff;
 mod command;
 mod common;
 mod deploy;
@@ -15,10 +16,14 @@ mod get_node_status;
 mod get_peers;
 mod get_state_root_hash;
 mod get_validator_changes;
+mod inspect_wasm;
 mod keygen;
+mod keystore;
 mod list_rpcs;
 mod query_balance;
 mod query_global_state;
+mod stream_events;
+mod trace_output;
 
 use std::process;
 
@@ -30,9 +35,11 @@ use casper_client::{cli, rpcs::results::GetChainspecResult, SuccessResponse};
 
 use account_address::AccountAddress;
 use block::{GetBlock, GetBlockTransfers};
+use chainspec_diff::ChainspecDiff;
 use command::{ClientCommand, Success};
 use deploy::{
-    GetDeploy, ListDeploys, MakeDeploy, MakeTransfer, PutDeploy, SendDeploy, SignDeploy, Transfer,
+    CheckApprovals, GetDeploy, InspectDeploy, ListDeploys, MakeDeploy, MakeTransfer, MergeDeploys,
+    PutDeploy, RestampDeploy, SendDeploy, SignDeploy, Transfer, VerifyDeploy,
 };
 use generate_completion::GenerateCompletion;
 use get_account::GetAccount;
@@ -45,10 +52,13 @@ use get_node_status::GetNodeStatus;
 use get_peers::GetPeers;
 use get_state_root_hash::GetStateRootHash;
 use get_validator_changes::GetValidatorChanges;
+use inspect_wasm::InspectWasm;
 use keygen::Keygen;
+use keystore::Keystore;
 use list_rpcs::ListRpcs;
 use query_balance::QueryBalance;
 use query_global_state::QueryGlobalState;
+use stream_events::StreamEvents;
 
 const APP_NAME: &str = "Casper client";
 
@@ -71,6 +81,12 @@ enum DisplayOrder {
     PutDeploy,
     MakeDeploy,
     SignDeploy,
+    RestampDeploy,
+    InspectDeploy,
+    InspectWasm,
+    VerifyDeploy,
+    MergeDeploys,
+    CheckApprovals,
     SendDeploy,
     Transfer,
     MakeTransfer,
@@ -91,8 +107,11 @@ enum DisplayOrder {
     GetPeers,
     GetNodeStatus,
     GetChainspec,
+    ChainspecDiff,
     ListRpcs,
+    StreamEvents,
     Keygen,
+    Keystore,
     AccountAddress,
     GenerateCompletion,
 }
@@ -101,9 +120,16 @@ fn cli() -> Command {
     Command::new(APP_NAME)
         .version(VERSION.as_str())
         .about("A client for interacting with the Casper network")
+        .args(common::transport::args())
         .subcommand(PutDeploy::build(DisplayOrder::PutDeploy as usize))
         .subcommand(MakeDeploy::build(DisplayOrder::MakeDeploy as usize))
         .subcommand(SignDeploy::build(DisplayOrder::SignDeploy as usize))
+        .subcommand(RestampDeploy::build(DisplayOrder::RestampDeploy as usize))
+        .subcommand(InspectDeploy::build(DisplayOrder::InspectDeploy as usize))
+        .subcommand(InspectWasm::build(DisplayOrder::InspectWasm as usize))
+        .subcommand(VerifyDeploy::build(DisplayOrder::VerifyDeploy as usize))
+        .subcommand(MergeDeploys::build(DisplayOrder::MergeDeploys as usize))
+        .subcommand(CheckApprovals::build(DisplayOrder::CheckApprovals as usize))
         .subcommand(SendDeploy::build(DisplayOrder::SendDeploy as usize))
         .subcommand(Transfer::build(DisplayOrder::Transfer as usize))
         .subcommand(MakeTransfer::build(DisplayOrder::MakeTransfer as usize))
@@ -134,8 +160,11 @@ fn cli() -> Command {
         .subcommand(GetPeers::build(DisplayOrder::GetPeers as usize))
         .subcommand(GetNodeStatus::build(DisplayOrder::GetNodeStatus as usize))
         .subcommand(GetChainspec::build(DisplayOrder::GetChainspec as usize))
+        .subcommand(ChainspecDiff::build(DisplayOrder::ChainspecDiff as usize))
         .subcommand(ListRpcs::build(DisplayOrder::ListRpcs as usize))
+        .subcommand(StreamEvents::build(DisplayOrder::StreamEvents as usize))
         .subcommand(Keygen::build(DisplayOrder::Keygen as usize))
+        .subcommand(Keystore::build(DisplayOrder::Keystore as usize))
         .subcommand(AccountAddress::build(DisplayOrder::AccountAddress as usize))
         .subcommand(GenerateCompletion::build(
             DisplayOrder::GenerateCompletion as usize,
@@ -151,10 +180,21 @@ async fn main() {
         process::exit(1);
     });
 
+    let mut verbosity_level = common::verbose::get(matches);
+    if verbosity_level > 0 {
+        trace_output::init();
+    }
+
     let result = match subcommand_name {
         PutDeploy::NAME => PutDeploy::run(matches).await,
         MakeDeploy::NAME => MakeDeploy::run(matches).await,
         SignDeploy::NAME => SignDeploy::run(matches).await,
+        RestampDeploy::NAME => RestampDeploy::run(matches).await,
+        InspectDeploy::NAME => InspectDeploy::run(matches).await,
+        InspectWasm::NAME => InspectWasm::run(matches).await,
+        VerifyDeploy::NAME => VerifyDeploy::run(matches).await,
+        MergeDeploys::NAME => MergeDeploys::run(matches).await,
+        CheckApprovals::NAME => CheckApprovals::run(matches).await,
         SendDeploy::NAME => SendDeploy::run(matches).await,
         Transfer::NAME => Transfer::run(matches).await,
         MakeTransfer::NAME => MakeTransfer::run(matches).await,
@@ -175,8 +215,11 @@ async fn main() {
         GetPeers::NAME => GetPeers::run(matches).await,
         GetNodeStatus::NAME => GetNodeStatus::run(matches).await,
         GetChainspec::NAME => GetChainspec::run(matches).await,
+        ChainspecDiff::NAME => ChainspecDiff::run(matches).await,
         ListRpcs::NAME => ListRpcs::run(matches).await,
+        StreamEvents::NAME => StreamEvents::run(matches).await,
         Keygen::NAME => Keygen::run(matches).await,
+        Keystore::NAME => Keystore::run(matches).await,
         AccountAddress::NAME => AccountAddress::run(matches).await,
         GenerateCompletion::NAME => GenerateCompletion::run(matches).await,
         _ => {
@@ -186,7 +229,6 @@ async fn main() {
         }
     };
 
-    let mut verbosity_level = common::verbose::get(matches);
     if verbosity_level == 0 {
         verbosity_level += 1
     }
@@ -201,6 +243,7 @@ async fn main() {
             }
         }
         Ok(Success::Output(output)) => println!("{}", output),
+        Ok(Success::Done) => (),
         Err(error) => {
             println!("{}", error);
             process::exit(1);
@@ -64,12 +64,14 @@ impl ClientCommand for QueryBalance {
         let maybe_rpc_id = common::rpc_id::get(matches);
         let node_address = common::node_address::get(matches);
         let verbosity_level = common::verbose::get(matches);
+        let transport = common::transport::get(matches)?;
 
         let maybe_block_id = common::block_identifier::get(matches);
         let maybe_state_root_hash = common::state_root_hash::get(matches).unwrap_or_default();
         let purse_id = common::purse_identifier::get(matches)?;
 
-        casper_client::cli::query_balance(
+        casper_client::cli::query_balance_with_transport(
+            transport.as_ref(),
             maybe_rpc_id,
             node_address,
             verbosity_level,
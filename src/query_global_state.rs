@@ -153,13 +153,15 @@ impl ClientCommand for QueryGlobalState {
         let maybe_rpc_id = common::rpc_id::get(matches);
         let node_address = common::node_address::get(matches);
         let verbosity_level = common::verbose::get(matches);
+        let transport = common::transport::get(matches)?;
 
         let maybe_block_id = common::block_identifier::get(matches);
         let maybe_state_root_hash = common::state_root_hash::get(matches).unwrap_or_default();
         let key = key::get(matches)?;
         let path = path::get(matches);
 
-        casper_client::cli::query_global_state(
+        casper_client::cli::query_global_state_with_transport(
+            transport.as_ref(),
             maybe_rpc_id,
             node_address,
             verbosity_level,
@@ -0,0 +1,220 @@
+use async_trait::async_trait;
+use clap::{builder::PossibleValuesParser, Arg, ArgAction, ArgMatches, Command};
+
+use casper_client::{
+    cli::CliError,
+    event_stream::{EventStream, EventStreamChannel, EventType},
+    Error,
+};
+
+use crate::{command::ClientCommand, common, Success};
+
+pub struct StreamEvents;
+
+/// This enum defines the order in which the args are shown for this subcommand's help message.
+enum DisplayOrder {
+    NodeAddress,
+    Channel,
+    EventType,
+    StartFrom,
+    Count,
+}
+
+/// Handles providing the arg for and retrieval of the address of the node's SSE server.
+mod node_address {
+    use super::*;
+
+    const ARG_NAME: &str = "node-address";
+    const ARG_SHORT: char = 'n';
+    const ARG_VALUE_NAME: &str = "HOST:PORT";
+    const ARG_DEFAULT: &str = "http://localhost:9999";
+    const ARG_HELP: &str = "Hostname or IP and port of node on which the event stream is served";
+
+    pub fn arg() -> Arg {
+        Arg::new(ARG_NAME)
+            .long(ARG_NAME)
+            .short(ARG_SHORT)
+            .required(false)
+            .default_value(ARG_DEFAULT)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::NodeAddress as usize)
+    }
+
+    pub fn get(matches: &ArgMatches) -> &str {
+        matches
+            .get_one::<String>(ARG_NAME)
+            .map(String::as_str)
+            .unwrap_or_else(|| panic!("should have {} arg", ARG_NAME))
+    }
+}
+
+/// Handles providing the arg for and retrieval of the event stream to connect to.
+mod channel {
+    use super::*;
+
+    const ARG_NAME: &str = "channel";
+    const ARG_VALUE_NAME: &str = "CHANNEL";
+    const ARG_HELP: &str =
+        "The event stream to connect to: 'main' provides all events other than DeployAccepted and \
+        FinalitySignature, which are provided by 'deploys' and 'sigs' respectively";
+    const MAIN: &str = "main";
+    const DEPLOYS: &str = "deploys";
+    const SIGS: &str = "sigs";
+
+    pub fn arg() -> Arg {
+        Arg::new(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .default_value(MAIN)
+            .value_name(ARG_VALUE_NAME)
+            .value_parser([MAIN, DEPLOYS, SIGS])
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Channel as usize)
+    }
+
+    pub fn get(matches: &ArgMatches) -> EventStreamChannel {
+        match matches.get_one::<String>(ARG_NAME).map(String::as_str) {
+            Some(DEPLOYS) => EventStreamChannel::Deploys,
+            Some(SIGS) => EventStreamChannel::Sigs,
+            _ => EventStreamChannel::Main,
+        }
+    }
+}
+
+/// Handles providing the arg for and retrieval of the types of event to output.
+mod event_type {
+    use super::*;
+
+    const ARG_NAME: &str = "event-type";
+    const ARG_VALUE_NAME: &str = "TYPE";
+    const ARG_HELP: &str =
+        "Only output events of the given type. May be repeated, or given as a comma-separated \
+        list, to output events of several types. If not given, all events are output";
+
+    pub fn arg() -> Arg {
+        Arg::new(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .action(ArgAction::Append)
+            .value_delimiter(',')
+            .value_name(ARG_VALUE_NAME)
+            .value_parser(PossibleValuesParser::new(
+                EventType::ALL.iter().map(EventType::name),
+            ))
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::EventType as usize)
+    }
+
+    pub fn get(matches: &ArgMatches) -> Vec<EventType> {
+        let names: Vec<&String> = matches
+            .get_many::<String>(ARG_NAME)
+            .unwrap_or_default()
+            .collect();
+        if names.is_empty() {
+            return EventType::ALL.to_vec();
+        }
+        EventType::ALL
+            .into_iter()
+            .filter(|event_type| names.iter().any(|name| *name == event_type.name()))
+            .collect()
+    }
+}
+
+/// Handles providing the arg for and retrieval of the ID of the first event to receive.
+mod start_from {
+    use super::*;
+
+    const ARG_NAME: &str = "start-from";
+    const ARG_VALUE_NAME: &str = common::ARG_INTEGER;
+    const ARG_HELP: &str =
+        "ID of the first event to receive, for resuming a previous session. If not given, only \
+        events emitted after connecting are received";
+
+    pub fn arg() -> Arg {
+        Arg::new(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .value_parser(clap::value_parser!(u32))
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::StartFrom as usize)
+    }
+
+    pub fn get(matches: &ArgMatches) -> Option<u32> {
+        matches.get_one::<u32>(ARG_NAME).copied()
+    }
+}
+
+/// Handles providing the arg for and retrieval of the number of events to output.
+mod count {
+    use super::*;
+
+    const ARG_NAME: &str = "count";
+    const ARG_VALUE_NAME: &str = common::ARG_INTEGER;
+    const ARG_HELP: &str =
+        "Number of events to output before exiting. If not given, events are output until the \
+        stream is interrupted and can't be resumed";
+
+    pub fn arg() -> Arg {
+        Arg::new(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .value_parser(clap::value_parser!(u64))
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Count as usize)
+    }
+
+    pub fn get(matches: &ArgMatches) -> Option<u64> {
+        matches.get_one::<u64>(ARG_NAME).copied()
+    }
+}
+
+#[async_trait]
+impl ClientCommand for StreamEvents {
+    const NAME: &'static str = "stream-events";
+    const ABOUT: &'static str =
+        "Connect to the node's event stream and output each event as a line of JSON, \
+        reconnecting and resuming from the last event received if the connection is lost";
+
+    fn build(display_order: usize) -> Command {
+        Command::new(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(node_address::arg())
+            .arg(channel::arg())
+            .arg(event_type::arg())
+            .arg(start_from::arg())
+            .arg(count::arg())
+    }
+
+    async fn run(matches: &ArgMatches) -> Result<Success, CliError> {
+        let node_address = node_address::get(matches);
+        let channel = channel::get(matches);
+        let event_types = event_type::get(matches);
+        let maybe_count = count::get(matches);
+        let client = common::transport::http_config(matches)?.build_client()?;
+
+        let mut stream = EventStream::new(node_address, channel).with_http_client(client);
+        if let Some(event_id) = start_from::get(matches) {
+            stream = stream.with_start_from(event_id);
+        }
+
+        let mut output_count = 0;
+        while maybe_count.map_or(true, |count| output_count < count) {
+            let event = stream.next().await?;
+            if !event_types.contains(&event.data.event_type()) {
+                continue;
+            }
+            let line =
+                serde_json::to_string(&event).map_err(|error| Error::FailedToEncodeToJson {
+                    context: "outputting event",
+                    error,
+                })?;
+            println!("{}", line);
+            output_count += 1;
+        }
+        Ok(Success::Done)
+    }
+}
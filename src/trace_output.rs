@@ -0,0 +1,60 @@
+use std::fmt::Debug;
+
+use tracing::{
+    field::{Field, Visit},
+    level_filters::LevelFilter,
+    Event, Subscriber,
+};
+use tracing_subscriber::{
+    filter::Targets,
+    layer::{Context, SubscriberExt},
+    util::SubscriberInitExt,
+    Layer,
+};
+
+/// The name of the field in which the library's debug-level events carry RPC requests and
+/// responses.
+const PAYLOAD_FIELD: &str = "payload";
+const LIBRARY_TARGET: &str = "casper_client";
+
+/// Installs a global `tracing` subscriber which prints the RPC requests and responses emitted by
+/// the library to stdout.
+///
+/// This preserves the output of the `--verbose` flag from before the library emitted `tracing`
+/// events in place of printing to stdout.  The degree of abbreviation of the payloads is governed
+/// by the `Verbosity` passed to the library.
+pub fn init() {
+    tracing_subscriber::registry()
+        .with(
+            PayloadPrinter
+                .with_filter(Targets::new().with_target(LIBRARY_TARGET, LevelFilter::DEBUG)),
+        )
+        .init();
+}
+
+/// A `Layer` which prints the payload of each event to stdout, ignoring events without one.
+struct PayloadPrinter;
+
+impl<S: Subscriber> Layer<S> for PayloadPrinter {
+    fn on_event(&self, event: &Event<'_>, _context: Context<'_, S>) {
+        let mut visitor = PayloadVisitor::default();
+        event.record(&mut visitor);
+        if let Some(payload) = visitor.payload {
+            println!("{}", payload);
+        }
+    }
+}
+
+#[derive(Default)]
+struct PayloadVisitor {
+    payload: Option<String>,
+}
+
+impl Visit for PayloadVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        // The payload is recorded as a `Display` value, whose `Debug` impl forwards to `Display`.
+        if field.name() == PAYLOAD_FIELD {
+            self.payload = Some(format!("{:?}", value));
+        }
+    }
+}